@@ -1,43 +1,67 @@
-mod tmp;
-
 use std::error::Error;
 
-use chartrs::{
-    prelude::{IntoDrawingArea, Stroke},
-    series::LineSeries,
-};
+use chartrs::prelude::{IntoDrawingArea, Stroke};
 use chartrs_backend::{colors::*, palette::rgba::*, FontStyle};
 use chartrs_bk::BkBackend;
-use chartrs_log_plot::{builder::LogPlotBuilder, plot_legend::PlotCurve};
-
-use tmp::{DEPTH, DTCO, GR_VALUE};
+use chartrs_log_plot::{
+    builder::LogPlotBuilder,
+    fill::{FillBaseline, FillStyle},
+    io::csv::CsvImporter,
+    pips::Pips,
+    plot::lithology_context::LithologyInterval,
+    plot_legend::PlotCurve,
+    tops::{FormationTop, TopsLabelTrack},
+    well::WellInfo,
+};
 
+const IN_FILE_NAME: &str = "data/well.csv";
 const OUT_FILE_NAME: &str = "output/graph.png";
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let root = BkBackend::new(OUT_FILE_NAME, (550, 5000)).into_drawing_area();
+    let curves = CsvImporter::new()
+        .unit_row(true)
+        .index_column("DEPT")
+        .open(IN_FILE_NAME)?;
+
+    let root = BkBackend::new(OUT_FILE_NAME, (680, 5000)).into_drawing_area();
 
     root.fill(&WHITE)?;
 
+    let mut well_info = WellInfo::default();
+    well_info
+        .well("EXAMPLE-1")
+        .company("EXAMPLE OIL CO.")
+        .field("EXAMPLE FIELD")
+        .elevations(Some(35.2), None, Some(30.0), "m");
+
     let mut builder = LogPlotBuilder::on(&root);
 
     let mut plot_context = builder
         .margin(10)
+        .well_header(well_info, 240)
+        .depth_unit("m")
         .head_text_style(("Microsoft YaHei UI", 18, FontStyle::Bold))
         .detail_text_style(("Microsoft YaHei UI", 12, FontStyle::Bold))
         .add_depth_area(1524.0..1850.0);
 
     plot_context
         .configure_style()
-        .title("DEPTH".to_string(), "DEPTH(m)".to_string())
+        .title("DEPTH".to_string(), "DEPTH".to_string())
+        .travel_time_pips(&Pips::travel_time(
+            curves.points("DTCO").ok_or("missing DTCO")?,
+            None,
+            3.28084,
+        ))
         .draw()?;
 
     let mut channel_context = plot_context.add_channel(1524.0..1850.0);
 
     let mut c1 = PlotCurve::default();
     c1.name("GR")
-        .range(30., 500.0, "gAPI")
-        .stroke(Stroke::new(1.25, RED.to_backend_color()));
+        .range(0., 150.0, "gAPI")
+        .stroke(Stroke::new(1.25, RED.to_backend_color()))
+        .backup_scale(Stroke::new(1.0, MAGENTA.to_backend_color()))
+        .gap_interpolation(0.5);
 
     let mut c2 = PlotCurve::default();
     c2.name("DTCO")
@@ -49,22 +73,16 @@ fn main() -> Result<(), Box<dyn Error>> {
         .title("CURVE".to_string())
         .draw(&[c1.clone(), c2.clone()])?;
 
-    channel_context.draw_series_with_range(
-        (c1.min as f64)..(c1.max as f64),
+    channel_context.draw_curve(
         1524.0..1850.0,
-        LineSeries::new(
-            GR_VALUE.iter().enumerate().map(|f| (*f.1, DEPTH[f.0])),
-            c1.stroke,
-        ),
+        &c1,
+        curves.points("GR").ok_or("missing GR")?,
     )?;
 
-    channel_context.draw_series_with_range(
-        (c2.min as f64)..(c2.max as f64),
+    channel_context.draw_curve(
         1524.0..1850.0,
-        LineSeries::new(
-            DTCO.iter().enumerate().map(|f| (*f.1, DEPTH[f.0])),
-            c2.stroke,
-        ),
+        &c2,
+        curves.points("DTCO").ok_or("missing DTCO")?,
     )?;
 
     let mut channel_context = plot_context.add_channel(1524.0..1850.0);
@@ -72,20 +90,46 @@ fn main() -> Result<(), Box<dyn Error>> {
     let mut c1 = PlotCurve::default();
     c1.name("GR")
         .range(30., 500., "gAPI")
-        .stroke(Stroke::new(1.25, RED.to_backend_color()));
+        .stroke(Stroke::new(1.25, RED.to_backend_color()))
+        .fill(
+            FillBaseline::LeftEdge,
+            FillStyle::Gradient(YELLOW.to_backend_color(), GREEN.to_backend_color()),
+        );
 
     channel_context
         .configure_style()
         .title("CURVE".to_string())
         .draw(&[c1.clone()])?;
 
-    channel_context.draw_series_with_range(
-        (c1.min as f64)..(c1.max as f64),
+    channel_context.draw_curve_fill(&c1, curves.points("GR").ok_or("missing GR")?)?;
+
+    channel_context.draw_curve(
         1524.0..1850.0,
-        LineSeries::new(
-            GR_VALUE.iter().enumerate().map(|f| (*f.1, DEPTH[f.0])),
-            c1.stroke,
-        ),
+        &c1,
+        curves.points("GR").ok_or("missing GR")?,
+    )?;
+
+    let mut lithology_context = plot_context.add_lithology_track(1524.0..1850.0, 120);
+
+    lithology_context.draw(
+        "LITH",
+        &[
+            LithologyInterval::new(1524.0, 1580.0, "shale"),
+            LithologyInterval::new(1580.0, 1640.0, "sandstone"),
+            LithologyInterval::new(1640.0, 1652.0, "coal"),
+            LithologyInterval::new(1652.0, 1730.0, "limestone"),
+            LithologyInterval::new(1730.0, 1790.0, "dolomite"),
+            LithologyInterval::new(1790.0, 1850.0, "anhydrite"),
+        ],
+    )?;
+
+    plot_context.draw_tops(
+        &[
+            FormationTop::new("SAND A", 1580.0, Stroke::new(2., RED.to_backend_color())),
+            FormationTop::new("COAL", 1640.0, Stroke::new(2., BLACK.to_backend_color())),
+            FormationTop::new("LIME B", 1652.0, Stroke::new(2., BLUE.to_backend_color())),
+        ],
+        TopsLabelTrack::Depth,
     )?;
 
     Ok(())
@@ -1,726 +0,0 @@
-pub const DEPTH: [f64; 2001] = [
-    1524.0, 1524.1524, 1524.3048, 1524.4572, 1524.6096, 1524.762, 1524.9144, 1525.0668, 1525.2192,
-    1525.3716, 1525.524, 1525.6764, 1525.8288, 1525.9812, 1526.1336, 1526.286, 1526.4384,
-    1526.5908, 1526.7432, 1526.8956, 1527.048, 1527.2004, 1527.3528, 1527.5052, 1527.6576, 1527.81,
-    1527.9624, 1528.1148, 1528.2672, 1528.4196, 1528.572, 1528.7244, 1528.8768, 1529.0292,
-    1529.1816, 1529.334, 1529.4864, 1529.6388, 1529.7912, 1529.9436, 1530.096, 1530.2484,
-    1530.4008, 1530.5532, 1530.7056, 1530.858, 1531.0104, 1531.1628, 1531.3152, 1531.4676, 1531.62,
-    1531.7724, 1531.9248, 1532.0772, 1532.2296, 1532.382, 1532.5344, 1532.6868, 1532.8392,
-    1532.9916, 1533.144, 1533.2964, 1533.4488, 1533.6012, 1533.7536, 1533.906, 1534.0584,
-    1534.2108, 1534.3632, 1534.5156, 1534.668, 1534.8204, 1534.9728, 1535.1252, 1535.2776, 1535.43,
-    1535.5824, 1535.7348, 1535.8872, 1536.0396, 1536.192, 1536.3444, 1536.4968, 1536.6492,
-    1536.8016, 1536.954, 1537.1064, 1537.2588, 1537.4112, 1537.5636, 1537.716, 1537.8684,
-    1538.0208, 1538.1732, 1538.3256, 1538.478, 1538.6304, 1538.7828, 1538.9352, 1539.0876, 1539.24,
-    1539.3924, 1539.5448, 1539.6972, 1539.8496, 1540.002, 1540.1544, 1540.3068, 1540.4592,
-    1540.6116, 1540.764, 1540.9164, 1541.0688, 1541.2212, 1541.3736, 1541.526, 1541.6784,
-    1541.8308, 1541.9832, 1542.1356, 1542.288, 1542.4404, 1542.5928, 1542.7452, 1542.8976, 1543.05,
-    1543.2024, 1543.3548, 1543.5072, 1543.6596, 1543.812, 1543.9644, 1544.1168, 1544.2692,
-    1544.4216, 1544.574, 1544.7264, 1544.8788, 1545.0312, 1545.1836, 1545.336, 1545.4884,
-    1545.6408, 1545.7932, 1545.9456, 1546.098, 1546.2504, 1546.4028, 1546.5552, 1546.7076, 1546.86,
-    1547.0124, 1547.1648, 1547.3172, 1547.4696, 1547.622, 1547.7744, 1547.9268, 1548.0792,
-    1548.2316, 1548.384, 1548.5364, 1548.6888, 1548.8412, 1548.9936, 1549.146, 1549.2984,
-    1549.4508, 1549.6032, 1549.7556, 1549.908, 1550.0604, 1550.2128, 1550.3652, 1550.5176, 1550.67,
-    1550.8224, 1550.9748, 1551.1272, 1551.2796, 1551.432, 1551.5844, 1551.7368, 1551.8892,
-    1552.0416, 1552.194, 1552.3464, 1552.4988, 1552.6512, 1552.8036, 1552.956, 1553.1084,
-    1553.2608, 1553.4132, 1553.5656, 1553.718, 1553.8704, 1554.0228, 1554.1752, 1554.3276, 1554.48,
-    1554.6324, 1554.7848, 1554.9372, 1555.0896, 1555.242, 1555.3944, 1555.5468, 1555.6992,
-    1555.8516, 1556.004, 1556.1564, 1556.3088, 1556.4612, 1556.6136, 1556.766, 1556.9184,
-    1557.0708, 1557.2232, 1557.3756, 1557.528, 1557.6804, 1557.8328, 1557.9852, 1558.1376, 1558.29,
-    1558.4424, 1558.5948, 1558.7472, 1558.8996, 1559.052, 1559.2044, 1559.3568, 1559.5092,
-    1559.6616, 1559.814, 1559.9664, 1560.1188, 1560.2712, 1560.4236, 1560.576, 1560.7284,
-    1560.8808, 1561.0332, 1561.1856, 1561.338, 1561.4904, 1561.6428, 1561.7952, 1561.9476, 1562.1,
-    1562.2524, 1562.4048, 1562.5572, 1562.7096, 1562.862, 1563.0144, 1563.1668, 1563.3192,
-    1563.4716, 1563.624, 1563.7764, 1563.9288, 1564.0812, 1564.2336, 1564.386, 1564.5384,
-    1564.6908, 1564.8432, 1564.9956, 1565.148, 1565.3004, 1565.4528, 1565.6052, 1565.7576, 1565.91,
-    1566.0624, 1566.2148, 1566.3672, 1566.5196, 1566.672, 1566.8244, 1566.9768, 1567.1292,
-    1567.2816, 1567.434, 1567.5864, 1567.7388, 1567.8912, 1568.0436, 1568.196, 1568.3484,
-    1568.5008, 1568.6532, 1568.8056, 1568.958, 1569.1104, 1569.2628, 1569.4152, 1569.5676, 1569.72,
-    1569.8724, 1570.0248, 1570.1772, 1570.3296, 1570.482, 1570.6344, 1570.7868, 1570.9392,
-    1571.0916, 1571.244, 1571.3964, 1571.5488, 1571.7012, 1571.8536, 1572.006, 1572.1584,
-    1572.3108, 1572.4632, 1572.6156, 1572.768, 1572.9204, 1573.0728, 1573.2252, 1573.3776, 1573.53,
-    1573.6824, 1573.8348, 1573.9872, 1574.1396, 1574.292, 1574.4444, 1574.5968, 1574.7492,
-    1574.9016, 1575.054, 1575.2064, 1575.3588, 1575.5112, 1575.6636, 1575.816, 1575.9684,
-    1576.1208, 1576.2732, 1576.4256, 1576.578, 1576.7304, 1576.8828, 1577.0352, 1577.1876, 1577.34,
-    1577.4924, 1577.6448, 1577.7972, 1577.9496, 1578.102, 1578.2544, 1578.4068, 1578.5592,
-    1578.7116, 1578.864, 1579.0164, 1579.1688, 1579.3212, 1579.4736, 1579.626, 1579.7784,
-    1579.9308, 1580.0832, 1580.2356, 1580.388, 1580.5404, 1580.6928, 1580.8452, 1580.9976, 1581.15,
-    1581.3024, 1581.4548, 1581.6072, 1581.7596, 1581.912, 1582.0644, 1582.2168, 1582.3692,
-    1582.5216, 1582.674, 1582.8264, 1582.9788, 1583.1312, 1583.2836, 1583.436, 1583.5884,
-    1583.7408, 1583.8932, 1584.0456, 1584.198, 1584.3504, 1584.5028, 1584.6552, 1584.8076, 1584.96,
-    1585.1124, 1585.2648, 1585.4172, 1585.5696, 1585.722, 1585.8744, 1586.0268, 1586.1792,
-    1586.3316, 1586.484, 1586.6364, 1586.7888, 1586.9412, 1587.0936, 1587.246, 1587.3984,
-    1587.5508, 1587.7032, 1587.8556, 1588.008, 1588.1604, 1588.3128, 1588.4652, 1588.6176, 1588.77,
-    1588.9224, 1589.0748, 1589.2272, 1589.3796, 1589.532, 1589.6844, 1589.8368, 1589.9892,
-    1590.1416, 1590.294, 1590.4464, 1590.5988, 1590.7512, 1590.9036, 1591.056, 1591.2084,
-    1591.3608, 1591.5132, 1591.6656, 1591.818, 1591.9704, 1592.1228, 1592.2752, 1592.4276, 1592.58,
-    1592.7324, 1592.8848, 1593.0372, 1593.1896, 1593.342, 1593.4944, 1593.6468, 1593.7992,
-    1593.9516, 1594.104, 1594.2564, 1594.4088, 1594.5612, 1594.7136, 1594.866, 1595.0184,
-    1595.1708, 1595.3232, 1595.4756, 1595.628, 1595.7804, 1595.9328, 1596.0852, 1596.2376, 1596.39,
-    1596.5424, 1596.6948, 1596.8472, 1596.9996, 1597.152, 1597.3044, 1597.4568, 1597.6092,
-    1597.7616, 1597.914, 1598.0664, 1598.2188, 1598.3712, 1598.5236, 1598.676, 1598.8284,
-    1598.9808, 1599.1332, 1599.2856, 1599.438, 1599.5904, 1599.7428, 1599.8952, 1600.0476, 1600.2,
-    1600.3524, 1600.5048, 1600.6572, 1600.8096, 1600.962, 1601.1144, 1601.2668, 1601.4192,
-    1601.5716, 1601.724, 1601.8764, 1602.0288, 1602.1812, 1602.3336, 1602.486, 1602.6384,
-    1602.7908, 1602.9432, 1603.0956, 1603.248, 1603.4004, 1603.5528, 1603.7052, 1603.8576, 1604.01,
-    1604.1624, 1604.3148, 1604.4672, 1604.6196, 1604.772, 1604.9244, 1605.0768, 1605.2292,
-    1605.3816, 1605.534, 1605.6864, 1605.8388, 1605.9912, 1606.1436, 1606.296, 1606.4484,
-    1606.6008, 1606.7532, 1606.9056, 1607.058, 1607.2104, 1607.3628, 1607.5152, 1607.6676, 1607.82,
-    1607.9724, 1608.1248, 1608.2772, 1608.4296, 1608.582, 1608.7344, 1608.8868, 1609.0392,
-    1609.1916, 1609.344, 1609.4964, 1609.6488, 1609.8012, 1609.9536, 1610.106, 1610.2584,
-    1610.4108, 1610.5632, 1610.7156, 1610.868, 1611.0204, 1611.1728, 1611.3252, 1611.4776, 1611.63,
-    1611.7824, 1611.9348, 1612.0872, 1612.2396, 1612.392, 1612.5444, 1612.6968, 1612.8492,
-    1613.0016, 1613.154, 1613.3064, 1613.4588, 1613.6112, 1613.7636, 1613.916, 1614.0684,
-    1614.2208, 1614.3732, 1614.5256, 1614.678, 1614.8304, 1614.9828, 1615.1352, 1615.2876, 1615.44,
-    1615.5924, 1615.7448, 1615.8972, 1616.0496, 1616.202, 1616.3544, 1616.5068, 1616.6592,
-    1616.8116, 1616.964, 1617.1164, 1617.2688, 1617.4212, 1617.5736, 1617.726, 1617.8784,
-    1618.0308, 1618.1832, 1618.3356, 1618.488, 1618.6404, 1618.7928, 1618.9452, 1619.0976, 1619.25,
-    1619.4024, 1619.5548, 1619.7072, 1619.8596, 1620.012, 1620.1644, 1620.3168, 1620.4692,
-    1620.6216, 1620.774, 1620.9264, 1621.0788, 1621.2312, 1621.3836, 1621.536, 1621.6884,
-    1621.8408, 1621.9932, 1622.1456, 1622.298, 1622.4504, 1622.6028, 1622.7552, 1622.9076, 1623.06,
-    1623.2124, 1623.3648, 1623.5172, 1623.6696, 1623.822, 1623.9744, 1624.1268, 1624.2792,
-    1624.4316, 1624.584, 1624.7364, 1624.8888, 1625.0412, 1625.1936, 1625.346, 1625.4984,
-    1625.6508, 1625.8032, 1625.9556, 1626.108, 1626.2604, 1626.4128, 1626.5652, 1626.7176, 1626.87,
-    1627.0224, 1627.1748, 1627.3272, 1627.4796, 1627.632, 1627.7844, 1627.9368, 1628.0892,
-    1628.2416, 1628.394, 1628.5464, 1628.6988, 1628.8512, 1629.0036, 1629.156, 1629.3084,
-    1629.4608, 1629.6132, 1629.7656, 1629.918, 1630.0704, 1630.2228, 1630.3752, 1630.5276, 1630.68,
-    1630.8324, 1630.9848, 1631.1372, 1631.2896, 1631.442, 1631.5944, 1631.7468, 1631.8992,
-    1632.0516, 1632.204, 1632.3564, 1632.5088, 1632.6612, 1632.8136, 1632.966, 1633.1184,
-    1633.2708, 1633.4232, 1633.5756, 1633.728, 1633.8804, 1634.0328, 1634.1852, 1634.3376, 1634.49,
-    1634.6424, 1634.7948, 1634.9472, 1635.0996, 1635.252, 1635.4044, 1635.5568, 1635.7092,
-    1635.8616, 1636.014, 1636.1664, 1636.3188, 1636.4712, 1636.6236, 1636.776, 1636.9284,
-    1637.0808, 1637.2332, 1637.3856, 1637.538, 1637.6904, 1637.8428, 1637.9952, 1638.1476, 1638.3,
-    1638.4524, 1638.6048, 1638.7572, 1638.9096, 1639.062, 1639.2144, 1639.3668, 1639.5192,
-    1639.6716, 1639.824, 1639.9764, 1640.1288, 1640.2812, 1640.4336, 1640.586, 1640.7384,
-    1640.8908, 1641.0432, 1641.1956, 1641.348, 1641.5004, 1641.6528, 1641.8052, 1641.9576, 1642.11,
-    1642.2624, 1642.4148, 1642.5672, 1642.7196, 1642.872, 1643.0244, 1643.1768, 1643.3292,
-    1643.4816, 1643.634, 1643.7864, 1643.9388, 1644.0912, 1644.2436, 1644.396, 1644.5484,
-    1644.7008, 1644.8532, 1645.0056, 1645.158, 1645.3104, 1645.4628, 1645.6152, 1645.7676, 1645.92,
-    1646.0724, 1646.2248, 1646.3772, 1646.5296, 1646.682, 1646.8344, 1646.9868, 1647.1392,
-    1647.2916, 1647.444, 1647.5964, 1647.7488, 1647.9012, 1648.0536, 1648.206, 1648.3584,
-    1648.5108, 1648.6632, 1648.8156, 1648.968, 1649.1204, 1649.2728, 1649.4252, 1649.5776, 1649.73,
-    1649.8824, 1650.0348, 1650.1872, 1650.3396, 1650.492, 1650.6444, 1650.7968, 1650.9492,
-    1651.1016, 1651.254, 1651.4064, 1651.5588, 1651.7112, 1651.8636, 1652.016, 1652.1684,
-    1652.3208, 1652.4732, 1652.6256, 1652.778, 1652.9304, 1653.0828, 1653.2352, 1653.3876, 1653.54,
-    1653.6924, 1653.8448, 1653.9972, 1654.1496, 1654.302, 1654.4544, 1654.6068, 1654.7592,
-    1654.9116, 1655.064, 1655.2164, 1655.3688, 1655.5212, 1655.6736, 1655.826, 1655.9784,
-    1656.1308, 1656.2832, 1656.4356, 1656.588, 1656.7404, 1656.8928, 1657.0452, 1657.1976, 1657.35,
-    1657.5024, 1657.6548, 1657.8072, 1657.9596, 1658.112, 1658.2644, 1658.4168, 1658.5692,
-    1658.7216, 1658.874, 1659.0264, 1659.1788, 1659.3312, 1659.4836, 1659.636, 1659.7884,
-    1659.9408, 1660.0932, 1660.2456, 1660.398, 1660.5504, 1660.7028, 1660.8552, 1661.0076, 1661.16,
-    1661.3124, 1661.4648, 1661.6172, 1661.7696, 1661.922, 1662.0744, 1662.2268, 1662.3792,
-    1662.5316, 1662.684, 1662.8364, 1662.9888, 1663.1412, 1663.2936, 1663.446, 1663.5984,
-    1663.7508, 1663.9032, 1664.0556, 1664.208, 1664.3604, 1664.5128, 1664.6652, 1664.8176, 1664.97,
-    1665.1224, 1665.2748, 1665.4272, 1665.5796, 1665.732, 1665.8844, 1666.0368, 1666.1892,
-    1666.3416, 1666.494, 1666.6464, 1666.7988, 1666.9512, 1667.1036, 1667.256, 1667.4084,
-    1667.5608, 1667.7132, 1667.8656, 1668.018, 1668.1704, 1668.3228, 1668.4752, 1668.6276, 1668.78,
-    1668.9324, 1669.0848, 1669.2372, 1669.3896, 1669.542, 1669.6944, 1669.8468, 1669.9992,
-    1670.1516, 1670.304, 1670.4564, 1670.6088, 1670.7612, 1670.9136, 1671.066, 1671.2184,
-    1671.3708, 1671.5232, 1671.6756, 1671.828, 1671.9804, 1672.1328, 1672.2852, 1672.4376, 1672.59,
-    1672.7424, 1672.8948, 1673.0472, 1673.1996, 1673.352, 1673.5044, 1673.6568, 1673.8092,
-    1673.9616, 1674.114, 1674.2664, 1674.4188, 1674.5712, 1674.7236, 1674.876, 1675.0284,
-    1675.1808, 1675.3332, 1675.4856, 1675.638, 1675.7904, 1675.9428, 1676.0952, 1676.2476, 1676.4,
-    1676.5524, 1676.7048, 1676.8572, 1677.0096, 1677.162, 1677.3144, 1677.4668, 1677.6192,
-    1677.7716, 1677.924, 1678.0764, 1678.2288, 1678.3812, 1678.5336, 1678.686, 1678.8384,
-    1678.9908, 1679.1432, 1679.2956, 1679.448, 1679.6004, 1679.7528, 1679.9052, 1680.0576, 1680.21,
-    1680.3624, 1680.5148, 1680.6672, 1680.8196, 1680.972, 1681.1244, 1681.2768, 1681.4292,
-    1681.5816, 1681.734, 1681.8864, 1682.0388, 1682.1912, 1682.3436, 1682.496, 1682.6484,
-    1682.8008, 1682.9532, 1683.1056, 1683.258, 1683.4104, 1683.5628, 1683.7152, 1683.8676, 1684.02,
-    1684.1724, 1684.3248, 1684.4772, 1684.6296, 1684.782, 1684.9344, 1685.0868, 1685.2392,
-    1685.3916, 1685.544, 1685.6964, 1685.8488, 1686.0012, 1686.1536, 1686.306, 1686.4584,
-    1686.6108, 1686.7632, 1686.9156, 1687.068, 1687.2204, 1687.3728, 1687.5252, 1687.6776, 1687.83,
-    1687.9824, 1688.1348, 1688.2872, 1688.4396, 1688.592, 1688.7444, 1688.8968, 1689.0492,
-    1689.2016, 1689.354, 1689.5064, 1689.6588, 1689.8112, 1689.9636, 1690.116, 1690.2684,
-    1690.4208, 1690.5732, 1690.7256, 1690.878, 1691.0304, 1691.1828, 1691.3352, 1691.4876, 1691.64,
-    1691.7924, 1691.9448, 1692.0972, 1692.2496, 1692.402, 1692.5544, 1692.7068, 1692.8592,
-    1693.0116, 1693.164, 1693.3164, 1693.4688, 1693.6212, 1693.7736, 1693.926, 1694.0784,
-    1694.2308, 1694.3832, 1694.5356, 1694.688, 1694.8404, 1694.9928, 1695.1452, 1695.2976, 1695.45,
-    1695.6024, 1695.7548, 1695.9072, 1696.0596, 1696.212, 1696.3644, 1696.5168, 1696.6692,
-    1696.8216, 1696.974, 1697.1264, 1697.2788, 1697.4312, 1697.5836, 1697.736, 1697.8884,
-    1698.0408, 1698.1932, 1698.3456, 1698.498, 1698.6504, 1698.8028, 1698.9552, 1699.1076, 1699.26,
-    1699.4124, 1699.5648, 1699.7172, 1699.8696, 1700.022, 1700.1744, 1700.3268, 1700.4792,
-    1700.6316, 1700.784, 1700.9364, 1701.0888, 1701.2412, 1701.3936, 1701.546, 1701.6984,
-    1701.8508, 1702.0032, 1702.1556, 1702.308, 1702.4604, 1702.6128, 1702.7652, 1702.9176, 1703.07,
-    1703.2224, 1703.3748, 1703.5272, 1703.6796, 1703.832, 1703.9844, 1704.1368, 1704.2892,
-    1704.4416, 1704.594, 1704.7464, 1704.8988, 1705.0512, 1705.2036, 1705.356, 1705.5084,
-    1705.6608, 1705.8132, 1705.9656, 1706.118, 1706.2704, 1706.4228, 1706.5752, 1706.7276, 1706.88,
-    1707.0324, 1707.1848, 1707.3372, 1707.4896, 1707.642, 1707.7944, 1707.9468, 1708.0992,
-    1708.2516, 1708.404, 1708.5564, 1708.7088, 1708.8612, 1709.0136, 1709.166, 1709.3184,
-    1709.4708, 1709.6232, 1709.7756, 1709.928, 1710.0804, 1710.2328, 1710.3852, 1710.5376, 1710.69,
-    1710.8424, 1710.9948, 1711.1472, 1711.2996, 1711.452, 1711.6044, 1711.7568, 1711.9092,
-    1712.0616, 1712.214, 1712.3664, 1712.5188, 1712.6712, 1712.8236, 1712.976, 1713.1284,
-    1713.2808, 1713.4332, 1713.5856, 1713.738, 1713.8904, 1714.0428, 1714.1952, 1714.3476, 1714.5,
-    1714.6524, 1714.8048, 1714.9572, 1715.1096, 1715.262, 1715.4144, 1715.5668, 1715.7192,
-    1715.8716, 1716.024, 1716.1764, 1716.3288, 1716.4812, 1716.6336, 1716.786, 1716.9384,
-    1717.0908, 1717.2432, 1717.3956, 1717.548, 1717.7004, 1717.8528, 1718.0052, 1718.1576, 1718.31,
-    1718.4624, 1718.6148, 1718.7672, 1718.9196, 1719.072, 1719.2244, 1719.3768, 1719.5292,
-    1719.6816, 1719.834, 1719.9864, 1720.1388, 1720.2912, 1720.4436, 1720.596, 1720.7484,
-    1720.9008, 1721.0532, 1721.2056, 1721.358, 1721.5104, 1721.6628, 1721.8152, 1721.9676, 1722.12,
-    1722.2724, 1722.4248, 1722.5772, 1722.7296, 1722.882, 1723.0344, 1723.1868, 1723.3392,
-    1723.4916, 1723.644, 1723.7964, 1723.9488, 1724.1012, 1724.2536, 1724.406, 1724.5584,
-    1724.7108, 1724.8632, 1725.0156, 1725.168, 1725.3204, 1725.4728, 1725.6252, 1725.7776, 1725.93,
-    1726.0824, 1726.2348, 1726.3872, 1726.5396, 1726.692, 1726.8444, 1726.9968, 1727.1492,
-    1727.3016, 1727.454, 1727.6064, 1727.7588, 1727.9112, 1728.0636, 1728.216, 1728.3684,
-    1728.5208, 1728.6732, 1728.8256, 1728.978, 1729.1304, 1729.2828, 1729.4352, 1729.5876, 1729.74,
-    1729.8924, 1730.0448, 1730.1972, 1730.3496, 1730.502, 1730.6544, 1730.8068, 1730.9592,
-    1731.1116, 1731.264, 1731.4164, 1731.5688, 1731.7212, 1731.8736, 1732.026, 1732.1784,
-    1732.3308, 1732.4832, 1732.6356, 1732.788, 1732.9404, 1733.0928, 1733.2452, 1733.3976, 1733.55,
-    1733.7024, 1733.8548, 1734.0072, 1734.1596, 1734.312, 1734.4644, 1734.6168, 1734.7692,
-    1734.9216, 1735.074, 1735.2264, 1735.3788, 1735.5312, 1735.6836, 1735.836, 1735.9884,
-    1736.1408, 1736.2932, 1736.4456, 1736.598, 1736.7504, 1736.9028, 1737.0552, 1737.2076, 1737.36,
-    1737.5124, 1737.6648, 1737.8172, 1737.9696, 1738.122, 1738.2744, 1738.4268, 1738.5792,
-    1738.7316, 1738.884, 1739.0364, 1739.1888, 1739.3412, 1739.4936, 1739.646, 1739.7984,
-    1739.9508, 1740.1032, 1740.2556, 1740.408, 1740.5604, 1740.7128, 1740.8652, 1741.0176, 1741.17,
-    1741.3224, 1741.4748, 1741.6272, 1741.7796, 1741.932, 1742.0844, 1742.2368, 1742.3892,
-    1742.5416, 1742.694, 1742.8464, 1742.9988, 1743.1512, 1743.3036, 1743.456, 1743.6084,
-    1743.7608, 1743.9132, 1744.0656, 1744.218, 1744.3704, 1744.5228, 1744.6752, 1744.8276, 1744.98,
-    1745.1324, 1745.2848, 1745.4372, 1745.5896, 1745.742, 1745.8944, 1746.0468, 1746.1992,
-    1746.3516, 1746.504, 1746.6564, 1746.8088, 1746.9612, 1747.1136, 1747.266, 1747.4184,
-    1747.5708, 1747.7232, 1747.8756, 1748.028, 1748.1804, 1748.3328, 1748.4852, 1748.6376, 1748.79,
-    1748.9424, 1749.0948, 1749.2472, 1749.3996, 1749.552, 1749.7044, 1749.8568, 1750.0092,
-    1750.1616, 1750.314, 1750.4664, 1750.6188, 1750.7712, 1750.9236, 1751.076, 1751.2284,
-    1751.3808, 1751.5332, 1751.6856, 1751.838, 1751.9904, 1752.1428, 1752.2952, 1752.4476, 1752.6,
-    1752.7524, 1752.9048, 1753.0572, 1753.2096, 1753.362, 1753.5144, 1753.6668, 1753.8192,
-    1753.9716, 1754.124, 1754.2764, 1754.4288, 1754.5812, 1754.7336, 1754.886, 1755.0384,
-    1755.1908, 1755.3432, 1755.4956, 1755.648, 1755.8004, 1755.9528, 1756.1052, 1756.2576, 1756.41,
-    1756.5624, 1756.7148, 1756.8672, 1757.0196, 1757.172, 1757.3244, 1757.4768, 1757.6292,
-    1757.7816, 1757.934, 1758.0864, 1758.2388, 1758.3912, 1758.5436, 1758.696, 1758.8484,
-    1759.0008, 1759.1532, 1759.3056, 1759.458, 1759.6104, 1759.7628, 1759.9152, 1760.0676, 1760.22,
-    1760.3724, 1760.5248, 1760.6772, 1760.8296, 1760.982, 1761.1344, 1761.2868, 1761.4392,
-    1761.5916, 1761.744, 1761.8964, 1762.0488, 1762.2012, 1762.3536, 1762.506, 1762.6584,
-    1762.8108, 1762.9632, 1763.1156, 1763.268, 1763.4204, 1763.5728, 1763.7252, 1763.8776, 1764.03,
-    1764.1824, 1764.3348, 1764.4872, 1764.6396, 1764.792, 1764.9444, 1765.0968, 1765.2492,
-    1765.4016, 1765.554, 1765.7064, 1765.8588, 1766.0112, 1766.1636, 1766.316, 1766.4684,
-    1766.6208, 1766.7732, 1766.9256, 1767.078, 1767.2304, 1767.3828, 1767.5352, 1767.6876, 1767.84,
-    1767.9924, 1768.1448, 1768.2972, 1768.4496, 1768.602, 1768.7544, 1768.9068, 1769.0592,
-    1769.2116, 1769.364, 1769.5164, 1769.6688, 1769.8212, 1769.9736, 1770.126, 1770.2784,
-    1770.4308, 1770.5832, 1770.7356, 1770.888, 1771.0404, 1771.1928, 1771.3452, 1771.4976, 1771.65,
-    1771.8024, 1771.9548, 1772.1072, 1772.2596, 1772.412, 1772.5644, 1772.7168, 1772.8692,
-    1773.0216, 1773.174, 1773.3264, 1773.4788, 1773.6312, 1773.7836, 1773.936, 1774.0884,
-    1774.2408, 1774.3932, 1774.5456, 1774.698, 1774.8504, 1775.0028, 1775.1552, 1775.3076, 1775.46,
-    1775.6124, 1775.7648, 1775.9172, 1776.0696, 1776.222, 1776.3744, 1776.5268, 1776.6792,
-    1776.8316, 1776.984, 1777.1364, 1777.2888, 1777.4412, 1777.5936, 1777.746, 1777.8984,
-    1778.0508, 1778.2032, 1778.3556, 1778.508, 1778.6604, 1778.8128, 1778.9652, 1779.1176, 1779.27,
-    1779.4224, 1779.5748, 1779.7272, 1779.8796, 1780.032, 1780.1844, 1780.3368, 1780.4892,
-    1780.6416, 1780.794, 1780.9464, 1781.0988, 1781.2512, 1781.4036, 1781.556, 1781.7084,
-    1781.8608, 1782.0132, 1782.1656, 1782.318, 1782.4704, 1782.6228, 1782.7752, 1782.9276, 1783.08,
-    1783.2324, 1783.3848, 1783.5372, 1783.6896, 1783.842, 1783.9944, 1784.1468, 1784.2992,
-    1784.4516, 1784.604, 1784.7564, 1784.9088, 1785.0612, 1785.2136, 1785.366, 1785.5184,
-    1785.6708, 1785.8232, 1785.9756, 1786.128, 1786.2804, 1786.4328, 1786.5852, 1786.7376, 1786.89,
-    1787.0424, 1787.1948, 1787.3472, 1787.4996, 1787.652, 1787.8044, 1787.9568, 1788.1092,
-    1788.2616, 1788.414, 1788.5664, 1788.7188, 1788.8712, 1789.0236, 1789.176, 1789.3284,
-    1789.4808, 1789.6332, 1789.7856, 1789.938, 1790.0904, 1790.2428, 1790.3952, 1790.5476, 1790.7,
-    1790.8524, 1791.0048, 1791.1572, 1791.3096, 1791.462, 1791.6144, 1791.7668, 1791.9192,
-    1792.0716, 1792.224, 1792.3764, 1792.5288, 1792.6812, 1792.8336, 1792.986, 1793.1384,
-    1793.2908, 1793.4432, 1793.5956, 1793.748, 1793.9004, 1794.0528, 1794.2052, 1794.3576, 1794.51,
-    1794.6624, 1794.8148, 1794.9672, 1795.1196, 1795.272, 1795.4244, 1795.5768, 1795.7292,
-    1795.8816, 1796.034, 1796.1864, 1796.3388, 1796.4912, 1796.6436, 1796.796, 1796.9484,
-    1797.1008, 1797.2532, 1797.4056, 1797.558, 1797.7104, 1797.8628, 1798.0152, 1798.1676, 1798.32,
-    1798.4724, 1798.6248, 1798.7772, 1798.9296, 1799.082, 1799.2344, 1799.3868, 1799.5392,
-    1799.6916, 1799.844, 1799.9964, 1800.1488, 1800.3012, 1800.4536, 1800.606, 1800.7584,
-    1800.9108, 1801.0632, 1801.2156, 1801.368, 1801.5204, 1801.6728, 1801.8252, 1801.9776, 1802.13,
-    1802.2824, 1802.4348, 1802.5872, 1802.7396, 1802.892, 1803.0444, 1803.1968, 1803.3492,
-    1803.5016, 1803.654, 1803.8064, 1803.9588, 1804.1112, 1804.2636, 1804.416, 1804.5684,
-    1804.7208, 1804.8732, 1805.0256, 1805.178, 1805.3304, 1805.4828, 1805.6352, 1805.7876, 1805.94,
-    1806.0924, 1806.2448, 1806.3972, 1806.5496, 1806.702, 1806.8544, 1807.0068, 1807.1592,
-    1807.3116, 1807.464, 1807.6164, 1807.7688, 1807.9212, 1808.0736, 1808.226, 1808.3784,
-    1808.5308, 1808.6832, 1808.8356, 1808.988, 1809.1404, 1809.2928, 1809.4452, 1809.5976, 1809.75,
-    1809.9024, 1810.0548, 1810.2072, 1810.3596, 1810.512, 1810.6644, 1810.8168, 1810.9692,
-    1811.1216, 1811.274, 1811.4264, 1811.5788, 1811.7312, 1811.8836, 1812.036, 1812.1884,
-    1812.3408, 1812.4932, 1812.6456, 1812.798, 1812.9504, 1813.1028, 1813.2552, 1813.4076, 1813.56,
-    1813.7124, 1813.8648, 1814.0172, 1814.1696, 1814.322, 1814.4744, 1814.6268, 1814.7792,
-    1814.9316, 1815.084, 1815.2364, 1815.3888, 1815.5412, 1815.6936, 1815.846, 1815.9984,
-    1816.1508, 1816.3032, 1816.4556, 1816.608, 1816.7604, 1816.9128, 1817.0652, 1817.2176, 1817.37,
-    1817.5224, 1817.6748, 1817.8272, 1817.9796, 1818.132, 1818.2844, 1818.4368, 1818.5892,
-    1818.7416, 1818.894, 1819.0464, 1819.1988, 1819.3512, 1819.5036, 1819.656, 1819.8084,
-    1819.9608, 1820.1132, 1820.2656, 1820.418, 1820.5704, 1820.7228, 1820.8752, 1821.0276, 1821.18,
-    1821.3324, 1821.4848, 1821.6372, 1821.7896, 1821.942, 1822.0944, 1822.2468, 1822.3992,
-    1822.5516, 1822.704, 1822.8564, 1823.0088, 1823.1612, 1823.3136, 1823.466, 1823.6184,
-    1823.7708, 1823.9232, 1824.0756, 1824.228, 1824.3804, 1824.5328, 1824.6852, 1824.8376, 1824.99,
-    1825.1424, 1825.2948, 1825.4472, 1825.5996, 1825.752, 1825.9044, 1826.0568, 1826.2092,
-    1826.3616, 1826.514, 1826.6664, 1826.8188, 1826.9712, 1827.1236, 1827.276, 1827.4284,
-    1827.5808, 1827.7332, 1827.8856, 1828.038, 1828.1904, 1828.3428, 1828.4952, 1828.6476, 1828.8,
-];
-
-pub const GR_VALUE: [f64; 2001] = [
-    109.119865, 111.97733, 107.61839, 103.16258, 95.316345, 78.75221, 71.022354, 57.24796,
-    56.230835, 68.145424, 97.64119, 82.41377, 67.41893, 100.54712, 123.40753, 132.97803, 121.32495,
-    135.12834, 98.45489, 109.2651, 122.28398, 120.01733, 117.014404, 115.07704, 125.974594,
-    111.008705, 99.09413, 91.538635, 95.54884, 89.891846, 89.795044, 99.287926, 102.17448,
-    123.40764, 119.29074, 118.128365, 111.15406, 106.24286, 113.527214, 111.78369, 113.91473,
-    104.22803, 95.73154, 102.09702, 99.733444, 103.2207, 115.51297, 107.42466, 116.0458, 109.1198,
-    104.034294, 103.84065, 86.88902, 83.69241, 94.44462, 97.87364, 112.1711, 108.68395, 111.20243,
-    101.32205, 98.41614, 113.527245, 116.53005, 104.22811, 107.4053, 108.24798, 113.04295,
-    111.938644, 104.03429, 112.75232, 126.70107, 125.5386, 110.9257, 125.7324, 130.07193,
-    116.23954, 114.49591, 99.19098, 84.854836, 81.270775, 101.32205, 99.384766, 100.15969,
-    103.453156, 113.13982, 106.03409, 95.66511, 105.58419, 103.88908, 111.00868, 113.33347,
-    97.93181, 106.691216, 129.99454, 115.07712, 107.52149, 93.13689, 97.14301, 104.61552, 90.94187,
-    121.43915, 121.12148, 120.404785, 143.55576, 134.37283, 131.2344, 123.21383, 119.14548,
-    105.293594, 115.46453, 112.75235, 110.86341, 118.70953, 120.88912, 112.17116, 106.213806,
-    94.50272, 91.24797, 93.86339, 82.82066, 87.954506, 79.042885, 75.700966, 81.60011, 107.17285,
-    118.12835, 130.18816, 128.25092, 111.174774, 108.93302, 113.33355, 111.00867, 115.46458,
-    113.79846, 116.67534, 104.42185, 108.97454, 96.18813, 104.90617, 110.13691, 126.701035,
-    130.04298, 122.05141, 107.5215, 120.65664, 131.23436, 146.07431, 128.79335, 126.70102,
-    132.65834, 133.21046, 140.456, 137.74382, 150.28107, 143.26517, 145.88062, 136.77507,
-    139.13866, 156.439, 159.97456, 138.32501, 118.91303, 104.71237, 125.9746, 129.02582, 139.19672,
-    137.97632, 129.89763, 129.607, 134.69257, 116.15643, 129.95578, 134.64409, 141.08562, 145.59,
-    131.35068, 139.58421, 154.48232, 157.85324, 151.4019, 141.32787, 143.55573, 155.17973,
-    141.81223, 137.59842, 135.27371, 145.64815, 146.1711, 161.34048, 150.06517, 153.43611,
-    144.13702, 139.63268, 156.67421, 139.77805, 148.90271, 166.22249, 152.85498, 151.30502,
-    145.29938, 136.00026, 135.22523, 157.11702, 139.07227, 150.14265, 148.20541, 150.33638,
-    144.83437, 145.15411, 130.38197, 127.04971, 119.43612, 130.76938, 137.45322, 138.17969,
-    128.19554, 133.67548, 133.09424, 134.25656, 136.19395, 124.04416, 149.36768, 136.23273,
-    160.84636, 199.93193, 167.09431, 145.10558, 141.92842, 125.42246, 127.51467, 111.73523,
-    124.725, 134.11125, 117.817055, 114.78643, 117.05315, 95.31642, 119.14546, 129.60698,
-    137.06578, 164.18825, 157.00635, 132.80365, 100.547104, 112.65546, 146.6555, 176.68394,
-    179.00888, 187.97585, 188.74387, 176.87784, 196.25102, 230.95331, 242.74689, 257.2766,
-    262.41058, 248.17142, 256.59857, 258.96503, 251.36789, 228.99167, 216.35085, 218.53023,
-    197.41331, 189.85791, 227.63565, 250.2636, 256.30814, 260.08575, 258.24542, 247.20274,
-    253.98323, 256.16278, 257.66415, 252.14285, 217.36786, 202.54727, 209.81236, 204.58148,
-    207.68118, 174.47554, 205.45323, 230.9679, 225.5047, 245.84642, 228.55594, 223.17984,
-    222.16267, 239.0175, 218.46106, 216.06602, 191.79523, 191.02014, 195.5148, 227.53891,
-    249.10117, 243.95761, 252.38509, 259.56265, 244.45169, 232.91492, 241.34216, 234.6101,
-    256.3081, 256.30795, 257.08307, 258.0516, 243.17297, 242.7469, 246.04028, 243.61852, 249.91501,
-    251.32634, 259.35944, 261.1515, 246.33078, 289.66888, 307.64722, 325.3251, 352.2057, 359.03488,
-    332.32855, 293.94064, 296.7595, 270.25665, 257.47052, 243.52174, 253.54732, 272.6979,
-    287.69272, 269.67557, 180.02599, 89.89187, 62.188118, 77.00869, 82.142586, 61.606903, 65.28787,
-    121.39759, 209.92857, 214.07443, 204.7267, 228.12006, 241.42947, 220.62245, 225.21411,
-    236.4021, 252.23962, 247.00902, 260.2312, 234.68745, 252.23975, 248.36499, 260.66702,
-    268.62958, 253.25667, 244.56792, 244.24832, 249.72107, 249.47894, 256.74408, 253.56805,
-    265.8011, 231.31668, 231.89774, 226.667, 234.22267, 238.00032, 234.22258, 238.29105, 229.137,
-    214.22939, 212.28241, 213.29936, 223.76105, 233.44771, 248.36493, 264.73544, 267.49615,
-    261.345, 247.2996, 259.33026, 258.7781, 251.56172, 267.73813, 249.18834, 244.9167, 259.35928,
-    252.96613, 278.7424, 266.04306, 278.03033, 284.2055, 299.8979, 286.2396, 304.257, 270.6055,
-    287.69275, 265.60724, 285.8037, 286.2398, 295.0159, 279.70117, 243.40555, 277.08588, 234.0288,
-    203.41919, 189.66408, 173.77803, 184.12338, 179.00873, 178.13698, 168.98323, 178.07889,
-    174.50449, 165.75757, 191.21385, 193.68413, 185.11142, 195.86345, 178.28232, 170.17468,
-    167.28793, 179.00888, 184.00713, 187.87198, 193.15135, 195.28238, 189.95464, 220.46765,
-    266.28528, 258.1969, 248.75261, 234.80371, 242.35931, 214.65567, 200.97806, 208.53362,
-    230.15428, 260.3763, 250.20544, 245.26541, 255.39471, 252.04599, 240.90643, 249.62424,
-    258.05173, 262.3139, 257.9547, 247.44485, 257.238, 248.51996, 223.17993, 231.60728, 230.00879,
-    247.00893, 261.82953, 247.10571, 234.51328, 239.01738, 219.69263, 161.39099, 160.04726,
-    167.38492, 152.56427, 144.13696, 153.43617, 178.86343, 193.24818, 192.84132, 201.38483,
-    167.7724, 187.00032, 197.72333, 202.54733, 216.20544, 213.92021, 235.67566, 224.53595,
-    225.50458, 228.7594, 218.23953, 223.52849, 217.22264, 206.159, 198.76958, 222.01747, 227.684,
-    246.23402, 206.2421, 167.8207, 147.15926, 136.2908, 158.23094, 168.69263, 190.19685, 196.44464,
-    194.26527, 174.24295, 182.6413, 211.99185, 227.05444, 229.76668, 227.48077, 231.8977,
-    221.55247, 215.76959, 232.28514, 233.35077, 230.88077, 253.40201, 262.89508, 252.62727,
-    227.94557, 227.9747, 227.3645, 208.79514, 206.80946, 213.99687, 199.7381, 179.00888, 165.496,
-    170.29074, 208.3178, 189.03445, 182.06004, 185.65112, 187.33928, 174.94041, 195.47615,
-    212.13702, 212.25328, 233.49617, 218.8208, 223.87723, 259.65005, 268.97812, 232.76958,
-    185.78947, 187.61047, 167.23964, 153.78482, 180.3165, 200.80379, 193.19, 185.78935, 186.41916,
-    156.82645, 134.11128, 145.29942, 152.07993, 162.92896, 164.86641, 153.00029, 143.55573,
-    153.72679, 148.78653, 144.48563, 144.71822, 133.3267, 112.51979, 109.38141, 106.35908,
-    159.73245, 228.60442, 240.80939, 254.95206, 251.368, 262.70114, 249.79868, 231.02603,
-    215.13986, 202.25679, 196.15413, 243.61856, 252.62726, 229.96051, 203.8065, 211.94336,
-    242.24312, 235.82077, 226.78334, 206.44131, 208.64984, 206.13142, 198.18825, 178.7182,
-    175.5217, 163.31645, 156.69081, 131.64128, 138.71245, 162.59, 182.88348, 181.13985, 210.58711,
-    174.72252, 153.24231, 159.39343, 162.00885, 152.73863, 157.21394, 194.70119, 209.34724,
-    211.78844, 206.90623, 213.88065, 188.59865, 172.80934, 143.701, 154.3661, 185.6926, 183.42587,
-    197.21971, 190.34213, 189.76096, 146.84927, 154.30792, 161.22412, 176.49037, 151.54721,
-    128.0959, 136.14555, 135.85487, 163.70395, 165.78659, 194.11989, 190.48749, 214.07448,
-    230.03784, 235.23972, 227.24823, 227.63557, 219.69269, 209.23111, 207.83609, 169.90347,
-    161.96042, 199.78659, 257.70306, 252.2396, 255.37817, 244.82953, 262.55585, 246.23396,
-    208.79526, 191.98886, 210.16103, 181.5274, 163.43265, 175.61855, 172.54295, 208.68562,
-    201.23962, 194.60425, 210.5098, 229.5729, 239.2595, 241.97195, 270.60547, 264.0573, 243.90929,
-    254.46762, 255.71124, 273.3954, 265.21973, 253.01454, 201.96616, 177.84648, 164.13011,
-    184.67554, 185.54736, 204.00026, 193.24823, 162.92906, 123.359146, 73.66678, 74.24801,
-    69.549934, 74.683876, 90.521515, 92.41036, 92.52666, 111.58996, 136.00014, 136.14551,
-    150.33647, 164.04294, 151.2567, 143.11987, 133.38475, 141.23105, 141.6185, 141.0856, 131.06,
-    127.42759, 134.06285, 132.51305, 128.00873, 115.852, 130.76944, 138.32504, 143.45883,
-    138.03445, 162.73532, 193.24805, 225.73714, 182.10861, 145.88052, 128.90958, 124.376274,
-    141.8121, 134.11137, 140.64978, 145.15399, 154.16269, 129.31645, 139.34203, 138.51874,
-    152.46753, 143.70108, 150.18138, 140.06863, 146.46172, 208.35936, 223.95485, 213.10565,
-    217.13536, 182.61232, 173.8942, 185.01454, 199.73827, 208.06866, 237.63713, 232.479, 226.81223,
-    231.89783, 209.37646, 164.91473, 182.68973, 195.13713, 212.57297, 192.86066, 175.23111,
-    155.76091, 156.09302, 183.51317, 199.7381, 198.38196, 176.91656, 192.18259, 203.41908,
-    214.46193, 237.8551, 218.87886, 190.77814, 205.01741, 240.61569, 241.77818, 245.70126,
-    250.96095, 245.99191, 226.81233, 217.36777, 231.02603, 228.75937, 238.87207, 226.5702,
-    222.98607, 244.29657, 231.08415, 214.4619, 215.62419, 210.00609, 190.7781, 167.966, 172.61569,
-    165.78659, 184.67543, 203.12854, 214.26814, 205.3079, 180.17125, 160.55586, 162.44463,
-    200.01498, 201.53023, 230.92902, 233.44766, 222.01752, 225.96951, 220.27385, 232.86655,
-    193.97458, 176.24811, 192.18268, 195.86346, 215.62415, 226.2312, 236.69263, 247.44485,
-    249.04315, 229.96042, 264.83215, 237.70985, 233.17645, 228.75926, 225.9406, 223.3735,
-    255.14563, 236.54745, 234.80368, 232.47894, 247.2996, 247.59004, 242.12686, 221.58163,
-    214.89766, 217.94904, 220.12862, 230.83218, 234.80379, 239.45343, 234.51321, 175.23097,
-    167.0943, 140.35919, 136.14546, 134.72162, 132.6583, 129.4617, 128.73529, 144.13698, 151.69247,
-    147.62418, 175.9866, 191.892, 188.59865, 167.57858, 163.70392, 141.66693, 153.872, 147.43044,
-    154.21101, 161.86342, 162.34789, 163.89764, 168.93471, 184.70462, 218.1427, 232.04303,
-    225.69844, 161.96031, 135.50203, 115.51302, 122.43889, 121.906166, 118.37058, 114.20525,
-    123.19728, 116.966034, 112.63602, 115.94891, 155.61565, 178.62132, 184.53015, 189.70291,
-    196.44464, 212.28244, 196.56099, 169.85489, 174.16557, 170.14563, 193.24808, 216.78668,
-    244.68414, 240.76096, 221.04886, 234.3678, 249.33368, 232.59532, 238.87204, 231.70395,
-    241.05173, 230.73544, 239.80197, 256.45346, 207.13875, 210.72562, 240.4705, 224.34212,
-    244.39352, 243.13435, 247.00894, 245.94331, 250.39929, 237.8259, 234.22263, 215.23674,
-    240.90623, 260.95776, 248.75256, 256.88916, 256.1144, 250.84482, 285.25168, 268.65863,
-    249.68237, 245.55594, 236.83806, 246.23387, 277.81232, 267.93213, 247.00887, 249.56613,
-    247.59021, 243.52168, 228.2653, 232.01407, 239.93762, 254.44833, 247.73535, 228.9917,
-    234.22269, 233.7577, 243.63786, 247.15425, 248.2682, 224.14845, 227.01578, 239.30794,
-    248.02606, 256.88937, 258.1969, 252.62714, 246.62155, 238.52338, 234.22258, 233.80745,
-    240.61569, 239.01755, 255.87218, 224.9233, 252.82095, 219.54738, 239.68571, 206.67389,
-    201.19113, 192.52165, 197.99467, 207.34213, 205.45325, 175.02351, 150.67537, 141.03726,
-    143.11993, 155.17969, 154.3079, 167.09433, 179.00876, 185.78947, 211.55585, 203.0316,
-    207.48753, 215.74046, 184.09425, 165.55829, 164.1882, 181.13979, 180.40376, 160.2652,
-    141.23094, 130.91478, 126.79786, 149.60027, 165.2054, 181.72096, 203.61281, 187.58154,
-    169.90337, 104.32493, 118.56432, 110.427444, 103.84062, 148.20532, 191.21387, 205.88928,
-    214.75249, 226.7638, 233.44774, 218.82083, 221.8236, 224.5748, 218.9661, 206.32507, 212.25339,
-    223.76094, 200.94904, 193.15137, 187.14555, 203.80647, 184.724, 178.81502, 203.53539,
-    213.73535, 213.8806, 208.50467, 204.00034, 201.53014, 214.17133, 203.61287, 219.11136,
-    212.7183, 225.50461, 231.0259, 236.35373, 229.68924, 245.5559, 245.12012, 221.82365, 222.71481,
-    238.1748, 237.85498, 219.98317, 222.59871, 208.06863, 223.64476, 253.1116, 244.91649,
-    243.15854, 245.49771, 229.3412, 229.13719, 226.95755, 234.80373, 228.556, 242.59178, 261.1901,
-    241.92352, 235.38486, 252.23964, 230.96796, 248.1712, 236.35353, 237.5438, 202.54724,
-    214.84929, 196.44482, 199.54448, 179.58994, 175.87039, 179.44472, 184.5301, 216.39928,
-    235.38504, 205.30792, 208.55307, 203.03166, 201.09422, 191.89206, 178.81511, 170.29077,
-    164.33356, 156.34218, 150.14261, 139.4874, 149.94894, 158.52155, 166.41628, 160.1199,
-    146.46176, 157.50458, 162.15408, 164.47884, 179.73528, 179.70633, 199.49594, 191.21394,
-    175.5217, 173.87486, 160.55582, 159.10287, 163.51015, 189.17973, 204.14568, 170.72673,
-    153.04866, 142.97464, 168.5472, 182.30229, 184.53027, 184.23958, 158.76375, 173.54562,
-    195.28229, 205.30792, 200.99753, 190.92331, 195.66971, 196.90976, 205.16266, 187.72668,
-    186.70975, 169.41907, 174.94035, 179.73535, 172.3251, 191.60135, 209.81235, 201.44307,
-    180.89763, 193.1029, 199.932, 207.85068, 174.35931, 191.9404, 191.79506, 195.08862, 188.30804,
-    195.42758, 167.82071, 184.14278, 176.10274, 162.44472, 173.77808, 179.12497, 166.51312,
-    176.29663, 179.8806, 173.77795, 194.41063, 178.54384, 194.9917, 197.026, 205.1626, 206.0344,
-    198.7696, 217.94908, 206.325, 201.96616, 213.29956, 193.8708, 191.64993, 187.87204, 180.55865,
-    162.59001, 176.53876, 181.76941, 198.91486, 188.40486, 208.06859, 207.48753, 202.15984,
-    219.40193, 209.52167, 211.7497, 194.04726, 187.72685, 170.29091, 182.01161, 177.70116,
-    185.20833, 173.48741, 170.17459, 177.07156, 179.88058, 163.60707, 188.23154, 188.30801,
-    218.53014, 230.15417, 244.53893, 217.94902, 182.01164, 192.23114, 193.9746, 195.0886, 232.6728,
-    221.04874, 209.08571, 191.60152, 191.94054, 186.56432, 187.8431, 178.86343, 163.70392,
-    181.33365, 168.8378, 165.25374, 174.74678, 196.1542, 182.84464, 178.28235, 185.59572,
-    197.95583, 185.28583, 174.64975, 174.3592, 184.04593, 201.09439, 182.49593, 167.15237,
-    159.92621, 185.40192, 181.76947, 177.94333, 166.80363, 164.04294, 161.13705, 156.19678,
-    151.6925, 162.88065, 192.26009, 194.36905, 177.65276, 170.58138, 188.5985, 176.21915, 193.9262,
-    182.49596, 186.79694, 178.50023, 167.06656, 172.26697, 168.63028, 156.92323, 163.70401,
-    158.27945, 160.84634, 176.49037, 161.96037, 152.07993, 166.80371, 146.89767, 151.11127,
-    168.3535, 172.51884, 172.8093, 177.74954, 149.10011, 154.0174, 126.99165, 108.82921,
-    119.377975, 136.43613, 147.62411, 176.1029, 156.48741, 156.53578, 169.3222, 173.7781,
-    156.05148, 171.8891, 148.64131, 176.9746, 180.46182, 175.52164, 174.94034, 185.40196,
-    179.29948, 182.93188, 187.14551, 194.26532, 198.30458, 197.80075, 197.7234, 167.5786,
-    185.78938, 181.47897, 168.40196, 162.92896, 162.29942, 143.90453, 152.4674, 143.11986,
-    142.97462, 138.90619, 151.40189, 147.39174, 158.66684, 145.29935, 155.42885, 169.12839,
-    154.98595, 151.6926, 171.45328, 167.3848, 160.41052, 165.6413, 168.54721, 154.88914, 148.49596,
-    136.19388, 139.09995, 133.53017, 130.9147, 130.76938, 126.99169, 146.75235, 145.88052,
-    135.41907, 134.06282, 161.57283, 145.59003, 151.25664, 154.30785, 148.9319, 161.71823,
-    151.78935, 154.01738, 149.17407, 135.41898, 129.99448, 117.20816, 139.77795, 132.51302,
-    135.22531, 117.40181, 92.60412, 101.709595, 116.23949, 137.74377, 153.58148, 155.7609,
-    155.76085, 147.13986, 154.98596, 154.211, 153.24242, 147.81795, 145.06686, 150.64636,
-    167.23962, 177.07146, 167.67549, 170.14563, 169.41898, 174.35915, 172.20055, 168.74089,
-    157.96944, 169.2738, 173.58426, 154.16254, 155.61568, 157.40764, 144.13692, 147.8179, 163.3165,
-    166.51303, 162.29944, 159.97455, 170.2908, 163.70401, 151.83789, 145.4446, 160.55579,
-    143.11993, 144.9506, 155.4703, 157.94046, 162.38654, 136.93013, 126.26517, 153.72668,
-    157.50456, 168.54733, 160.70102, 163.5102, 167.09433, 164.38199, 149.36768, 171.30801,
-    170.19398, 170.09706, 174.55304, 162.88062, 167.57852, 191.79515, 180.17128, 201.67542,
-    200.65845, 188.07553, 195.28226, 196.05724, 178.77641, 177.07141, 161.10799, 171.16264,
-    172.17969, 171.59863, 170.87212, 172.3665, 166.99748, 147.91481, 163.31645, 159.68391,
-    148.06012, 143.78822, 157.35918, 171.64703, 178.28224, 173.48743, 175.9576, 184.23956,
-    167.6754, 177.07156, 160.41046, 156.53577, 172.80945, 170.37383, 166.22919, 173.3422,
-    166.02869, 159.6355, 160.64304, 172.17973, 151.49878, 152.04129, 149.36766, 145.99683,
-    157.79512, 169.41896, 159.1319, 142.1997, 156.63263, 150.91762, 161.18546, 151.49878,
-    149.56146, 154.59859, 158.7637, 144.13702, 150.18146, 135.41895, 143.70103, 144.33076,
-    143.11978, 166.10623, 185.11142, 190.24524, 188.88925, 177.36208, 170.29077, 171.16269,
-    151.69257, 157.03947, 159.26343, 166.65843, 138.47028, 150.06517, 152.12848, 163.75232,
-    174.94038, 153.2701, 143.74948, 144.4276, 142.82925, 151.40186, 148.0601, 140.88226, 149.94888,
-    191.6015, 183.65848, 187.53296, 185.49886, 171.88918, 168.89594, 173.77808, 162.05722,
-    161.76656, 171.74393, 202.37296, 224.05157, 206.90637, 186.5643, 198.91481, 179.97755,
-    190.05153, 167.19106, 165.40883, 185.88632, 196.88058, 195.28241, 216.06009, 198.04298,
-    195.28242, 185.7507, 181.47888, 203.30295, 204.19394, 180.89778, 165.87376, 162.15402,
-    168.93478, 178.57298, 186.7096, 181.043, 192.5701, 191.79512, 203.855, 207.58438, 197.31644,
-    202.95416, 191.09776, 199.64127, 194.41061, 174.79514, 183.65834, 184.67557, 188.45322,
-    205.16267, 184.0459, 181.13983, 183.22244, 180.75249, 189.47038, 200.70676, 204.58159,
-    187.7268, 199.93184, 188.69545, 188.16267, 186.68057, 184.23965, 201.84155, 199.54442,
-    188.74394, 172.47034, 176.80022, 178.07896, 172.4703, 164.18828, 131.69936, 115.80354,
-    103.25945, 104.421844, 97.78645, 106.35913, 111.880554, 114.1084, 116.23952, 107.40532,
-    98.57106, 116.93696, 130.30446, 122.051414, 137.16266, 134.3535, 131.35056, 127.57285,
-    130.07199, 145.49303, 127.28225, 135.80652, 148.78651, 159.24814, 163.70398, 157.40761,
-    168.3536, 167.96613, 161.07466, 174.35931, 172.03448, 173.34207, 166.61, 162.44472, 164.894,
-    160.99176, 189.9062, 163.75235, 166.45502, 159.39337, 170.87198, 168.98322, 174.64983,
-    165.49593, 158.08575, 153.72668, 161.42758, 156.19687, 159.59677, 155.95462, 168.83794,
-    150.2396, 155.76086, 160.8949, 173.1968, 163.17117, 176.9747, 151.11128, 155.7609, 153.24245,
-    156.63263, 151.22754, 150.5302, 147.85663, 149.22246, 142.24805, 157.27202, 161.80505,
-    167.28795, 161.80544, 158.52156, 162.15402, 161.13705, 163.70396, 164.9147, 156.48743,
-    156.80711, 151.88623, 157.8533, 166.07712, 185.98312, 166.22255, 143.26514, 145.58992,
-    150.87892, 148.78648, 150.64638, 151.83789, 137.74374, 145.0669, 152.46753, 158.58383,
-    160.41055, 151.6925, 143.67198, 132.65837, 160.99164, 154.68153, 155.3735, 169.70963,
-    160.26512, 141.81224, 139.95238, 155.47026, 175.71544, 157.40765, 148.27045, 158.32622,
-    161.57294, 162.3478, 155.95457, 171.53632, 176.10278, 163.8976, 191.50464, 178.71822,
-    184.33643, 182.6898, 182.2054, 184.35577, 191.06874, 190.53587, 186.12848, 175.5217, 187.26184,
-    176.21902, 175.5217, 200.04816, 188.5017, 195.9604, 186.95177, 194.26532, 208.84369, 205.0173,
-    206.03448, 203.25308, 186.419, 181.47899, 184.53018, 173.48737, 183.7747, 191.02025, 187.58138,
-    193.68408, 180.42032, 145.73526, 154.16269, 140.4173, 149.6583, 154.7439, 163.89764, 176.45152,
-    179.0089, 187.29086, 167.53009, 185.75078, 164.04298, 140.50443, 116.1233, 123.79504,
-    142.16084, 139.25496, 151.11131, 160.70108, 154.30798, 156.10957, 170.19403, 164.86635,
-    169.41895, 178.71819, 172.6157, 163.46173, 171.45323, 182.64133, 164.28506, 185.54729,
-    177.70119, 186.75809, 200.80363, 191.98897, 180.83539, 179.88069, 179.97755, 187.61049,
-    189.12166, 201.09436, 180.6361, 204.8722, 177.41057, 176.97456, 178.89261, 179.29945,
-    170.87198, 173.61201, 187.29082, 171.0657, 189.37358, 194.86714, 199.0602, 176.97469,
-    187.92047, 180.17113, 181.52734, 204.1166, 188.69536, 183.07727, 189.17975, 188.69542,
-    164.76955, 177.84642, 197.60706, 199.84895, 212.36949, 185.86696, 190.3422, 190.48743,
-    187.53302, 176.1029, 186.3706, 196.63849, 187.33939, 185.1695, 197.72334, 207.58441, 203.80649,
-    201.28802, 194.31374, 206.90627, 198.57576, 190.28412, 199.20532, 199.64131, 194.26532,
-    177.26517, 182.64131, 162.88065, 154.36598, 155.6156, 155.61568, 149.94888, 141.95752,
-    132.51308, 121.470215, 110.87958, 105.34198, 117.40186, 136.00024, 153.72679, 163.3164,
-    170.2909, 163.8008, 142.04462, 131.23444, 136.00014, 151.54727, 124.93687, 122.167755,
-    122.96474, 129.25836, 129.60703, 122.05142, 109.071434, 132.12561, 136.00015, 172.47034,
-    178.2824, 141.71526, 135.65154, 145.88052, 148.98024, 157.89203, 147.23674, 127.42752,
-    118.85489, 120.42411, 120.16256, 120.59851, 136.29079, 113.13971, 119.5814, 137.55013,
-    115.73091, 136.87206, 115.0771, 121.32491, 126.11988, 123.21385, 124.95743, 121.90621,
-    122.34204, 126.9335, 125.34497, 114.61212, 110.62119, 118.709625, 128.05716, 119.29076,
-    119.61049, 133.53009, 133.6142, 132.07715, 128.83215, 112.02579, 126.119804, 117.98313,
-    119.26166, 116.40557, 119.14546, 129.21948, 121.27658, 111.20249, 128.34773, 138.71248,
-    139.77805, 120.30783, 112.46175, 120.65658, 118.75795, 129.0259, 115.46457, 118.75796,
-    124.957504, 119.1455, 124.957405, 126.55577, 119.2285, 120.01727, 123.213905, 134.40189,
-    136.1455, 111.00876, 116.82071, 104.1796, 104.848045, 88.269356, 65.239426, 57.926033,
-    61.752197, 68.58127, 62.76935, 74.16079, 74.82913, 68.43603, 56.143673, 55.446224, 58.8947,
-    56.884693, 57.15109, 59.475895, 62.420586, 61.80065, 59.766495, 56.72485, 59.57275, 60.44456,
-    53.179554, 53.663883, 59.282166, 67.2736, 70.13115, 65.84967, 75.26504, 64.62916, 69.937454,
-    68.96876, 67.70947, 71.23823, 62.478695, 59.13682, 58.119766, 72.06847, 64.222305, 65.53004,
-    63.699234, 63.93171, 62.105118, 65.28781, 66.83771, 69.74372, 61.60692, 63.49583, 84.85484,
-    86.59839, 88.14829, 86.985916, 84.66105, 79.43033, 79.18813, 72.35905, 71.68103, 68.290726,
-    63.059895, 65.82062, 66.644, 77.58982, 85.31982, 88.632614, 79.62401, 76.911804, 68.43603,
-    70.760765, 72.79497, 66.25653, 66.48897, 81.48388, 100.98308, 102.87191, 105.003044, 97.253746,
-    82.14255, 88.82637, 82.384735, 103.4531, 87.32493, 89.271935, 88.72946, 75.55565, 73.8121,
-    79.62401, 71.48731, 70.71239, 66.02401, 51.726578, 45.769318, 64.07701, 63.93173, 71.37105,
-    68.435974, 64.22234, 60.153946, 57.53852, 58.584698, 68.96878, 67.806335, 57.771027, 52.54026,
-    57.30604, 56.569904, 62.769295, 53.470154, 63.35054, 54.748814, 55.960995, 59.766487, 62.59038,
-    64.31917, 68.581314, 54.63256, 61.606907, 64.28046, 71.681046, 55.678703, 53.08271, 69.04629,
-    66.11118, 59.28214, 66.45024, 73.579575, 76.1369, 79.33345, 73.81204, 70.6155, 71.92321,
-    71.196655, 75.99156, 72.141136, 91.53859, 109.26516,
-];
-
-pub const DTCO: [f64; 2001] = [
-    108.4851, 108.4851, 108.4851, 108.485054, 109.73854, 111.10998, 112.18079, 112.81093,
-    113.420006, 114.26825, 115.72838, 117.369835, 118.23398, 118.416275, 117.87266, 116.27648,
-    114.57611, 113.625946, 113.34184, 113.791115, 114.51168, 114.559425, 113.58621, 111.9254,
-    110.51038, 109.95278, 109.71726, 109.04306, 107.61819, 105.79031, 104.070145, 102.398445,
-    101.05353, 100.58406, 100.70991, 100.87712, 100.90352, 100.85812, 100.903305, 101.05799,
-    101.250435, 101.30122, 101.15141, 101.01454, 100.9323, 100.63409, 100.08641, 99.69034,
-    99.60634, 99.663956, 99.741806, 99.776115, 99.60245, 99.245705, 98.683266, 97.78752, 96.73869,
-    95.92648, 95.71699, 96.35547, 97.68486, 99.06926, 99.98852, 100.751175, 101.61618, 102.56407,
-    103.44187, 103.96214, 104.41056, 104.497444, 103.8322, 103.17491, 103.49083, 104.4066,
-    105.41018, 106.199196, 106.16563, 105.52573, 104.393265, 102.65586, 101.21822, 100.4014,
-    99.57905, 98.94402, 98.971344, 99.81231, 101.32397, 102.90115, 103.7426, 103.86305, 103.70704,
-    103.52258, 103.3122, 103.14641, 103.36573, 103.89637, 104.24862, 104.298065, 104.115166,
-    103.54921, 102.16528, 100.24435, 99.266426, 98.674866, 97.34114, 96.04827, 95.871704,
-    97.582924, 100.58632, 103.52198, 105.14673, 105.661514, 106.17925, 106.79245, 107.344086,
-    108.31073, 109.830666, 110.992645, 111.117905, 110.318985, 108.66674, 106.6654, 105.128235,
-    104.661865, 106.283745, 110.10048, 114.217636, 117.504524, 119.74039, 121.08768, 121.78057,
-    121.87488, 121.39852, 120.7159, 120.2936, 119.87103, 119.19489, 117.936646, 115.9223,
-    113.13124, 110.03648, 107.70272, 106.028694, 104.63142, 103.71674, 103.04072, 102.13305,
-    101.26469, 100.824165, 100.507126, 99.87681, 99.093025, 98.860954, 99.48492, 100.30502,
-    100.69905, 100.762146, 100.6916, 100.51273, 100.1941, 99.6902, 99.21871, 99.01577, 98.922,
-    98.88117, 99.0889, 99.56736, 100.132866, 100.538185, 100.71924, 100.73354, 100.54288,
-    100.11224, 99.67806, 99.43989, 99.204384, 98.78602, 98.40978, 98.370155, 98.53569, 98.44389,
-    97.89856, 97.153824, 96.55097, 96.151215, 95.842354, 95.63927, 95.62732, 95.78933, 95.966255,
-    96.0422, 96.06724, 96.09217, 96.10682, 96.110054, 96.11003, 96.1071, 96.099976, 96.1187,
-    96.200035, 96.32074, 96.55659, 97.05162, 97.604355, 97.88182, 97.93898, 97.943436, 97.943436,
-    97.943436, 97.943436, 97.943436, 97.943436, 97.943436, 97.943436, 97.943436, 97.943436,
-    97.940636, 97.90378, 97.717026, 97.30979, 96.86917, 96.50316, 96.090294, 95.704, 95.45665,
-    95.20459, 94.78811, 94.36218, 94.08688, 93.92886, 93.903366, 93.876625, 93.48028, 92.69966,
-    92.205376, 92.21381, 92.39056, 92.51578, 92.50776, 92.59502, 93.22294, 94.76128, 97.443016,
-    101.404465, 106.51625, 112.07403, 116.056946, 116.78597, 114.44905, 110.54478, 107.857605,
-    108.32939, 110.15375, 110.6579, 109.20978, 106.55147, 104.2301, 104.137184, 107.46327,
-    112.35394, 115.614494, 116.56943, 115.98192, 114.20004, 111.38096, 107.66192, 103.82396,
-    100.43705, 97.73662, 95.75458, 94.39306, 93.568535, 93.18062, 93.21079, 93.540215, 93.52514,
-    93.994835, 96.445305, 99.23839, 101.382805, 103.0423, 103.72757, 103.66525, 104.453606,
-    106.54567, 108.46234, 109.22027, 108.35777, 105.31689, 100.85924, 97.08457, 94.60423, 93.09939,
-    92.60448, 92.79314, 93.19135, 93.634186, 94.10503, 94.54852, 94.8746, 95.10976, 95.29587,
-    95.31308, 95.0261, 94.447655, 93.68318, 92.965454, 92.481834, 92.21847, 92.23566, 92.64931,
-    93.29434, 94.19393, 95.37118, 96.1598, 96.21967, 95.6566, 94.75885, 94.00568, 93.59634,
-    93.326065, 93.12446, 93.294716, 94.08656, 95.56627, 97.11429, 98.03612, 98.66469, 98.76238,
-    98.10696, 96.881454, 95.3004, 93.58205, 91.92815, 90.40079, 89.26243, 89.024216, 89.78298,
-    91.43807, 93.645676, 95.50512, 96.45244, 96.73693, 96.78615, 96.82172, 96.836494, 96.719376,
-    96.444, 96.0621, 95.73912, 95.959206, 97.1351, 99.133965, 101.89253, 105.38396, 108.54335,
-    110.446594, 110.70549, 109.0486, 106.44236, 104.94311, 105.23983, 105.88818, 105.867546,
-    105.58019, 105.24326, 104.523705, 103.5733, 102.67645, 101.60607, 100.574844, 100.1614,
-    100.35184, 100.84094, 101.397934, 101.76349, 101.78345, 101.51422, 101.06416, 100.70561,
-    100.71177, 100.774536, 100.437416, 99.984566, 99.9453, 100.59625, 101.88531, 103.10643,
-    103.69654, 103.73576, 103.38157, 102.775185, 102.08979, 101.50042, 101.22899, 101.37136,
-    101.70502, 101.75084, 101.156204, 100.198326, 99.54405, 99.62098, 100.51373, 102.1031,
-    104.12081, 105.84991, 106.65815, 106.771, 106.52236, 105.78763, 104.61073, 103.37323,
-    102.37261, 101.84711, 101.933235, 102.32008, 102.5145, 102.51899, 102.59807, 102.74408,
-    102.69708, 102.43153, 102.18504, 102.140564, 102.35127, 102.60867, 102.38037, 101.34125,
-    99.79052, 98.587715, 98.40018, 98.99557, 99.50468, 99.332375, 98.9573, 99.024254, 99.32493,
-    99.46844, 99.30132, 99.02482, 99.62451, 101.79898, 104.709076, 107.29003, 109.481415,
-    111.15071, 111.7285, 111.2793, 110.36577, 109.22918, 107.94321, 106.74914, 106.14823,
-    106.46545, 107.363556, 108.55898, 110.27676, 112.42727, 114.511246, 116.04979, 116.48088,
-    115.359795, 112.855606, 109.97229, 107.67353, 105.93329, 104.502975, 103.54434, 103.11842,
-    102.89641, 102.66553, 102.95186, 103.85657, 104.59491, 104.72228, 104.36343, 103.79637,
-    103.09413, 102.30228, 101.709145, 101.712425, 102.40494, 103.322105, 103.664925, 103.327866,
-    103.38724, 104.61255, 106.2508, 107.29221, 107.70511, 107.74309, 107.23795, 106.164734,
-    105.23062, 105.02446, 105.12906, 105.05369, 105.1936, 105.84079, 106.359215, 106.113716,
-    105.39069, 104.755264, 104.19659, 103.655235, 103.29549, 102.96133, 102.14187, 100.75312,
-    99.68717, 99.99172, 101.91032, 104.68569, 107.18975, 108.39977, 108.11038, 106.98668,
-    105.559105, 104.48934, 104.60711, 105.96645, 107.748375, 109.28177, 110.53235, 111.89138,
-    113.11208, 112.68135, 110.45469, 107.47176, 105.04386, 104.3054, 105.41238, 107.87736,
-    111.6399, 116.113396, 119.47427, 120.81865, 121.150986, 120.89837, 119.99641, 119.49014,
-    119.69977, 119.82193, 119.43081, 118.703354, 117.83768, 116.780045, 115.775505, 115.2993,
-    115.26158, 114.81456, 113.2467, 111.02667, 109.321396, 108.40255, 107.596016, 106.7395,
-    106.16839, 105.85511, 105.6221, 105.67532, 106.355255, 107.369484, 107.92733, 107.83407,
-    107.54503, 107.252975, 106.708755, 105.76209, 104.74367, 104.19239, 104.27287, 104.65951,
-    104.912735, 104.768394, 104.209564, 103.50236, 103.04452, 102.990746, 103.44872, 104.53673,
-    106.00662, 107.35954, 108.16106, 108.3653, 108.18347, 107.583725, 106.55045, 105.531555,
-    104.68512, 103.6918, 102.88569, 102.93702, 103.64006, 104.29017, 104.663284, 104.854324,
-    104.931625, 104.728806, 103.841415, 102.245705, 100.80944, 100.34242, 100.48971, 100.48348,
-    99.92185, 98.94804, 98.124596, 98.13789, 99.25698, 100.64371, 101.43833, 101.85616, 102.10101,
-    101.96846, 101.460754, 100.667, 99.77809, 99.340164, 99.63972, 100.24611, 100.53556, 100.19403,
-    99.03014, 97.14378, 95.45561, 94.70225, 94.456635, 93.93815, 93.006004, 92.25481, 92.03347,
-    92.130005, 92.58617, 93.503555, 94.542656, 95.55289, 97.19737, 99.830666, 102.35809,
-    103.818245, 104.60381, 105.142685, 105.18511, 104.53203, 103.45052, 102.17903, 100.78741,
-    99.54082, 98.53785, 97.67629, 96.93994, 96.382034, 96.263855, 96.67858, 97.3624, 98.2313,
-    99.308495, 100.421074, 101.226494, 101.63352, 102.04098, 102.59791, 103.0998, 103.56629,
-    104.16369, 104.96938, 105.71242, 105.86598, 105.3809, 104.57967, 103.660194, 103.10745,
-    103.54358, 104.64425, 105.38197, 105.42771, 104.98992, 103.9658, 102.27943, 100.339325,
-    98.88627, 98.27468, 98.180664, 98.22701, 98.3457, 98.64271, 99.10884, 99.414925, 99.31746,
-    99.142815, 99.26274, 99.69278, 100.381584, 101.00148, 101.21694, 101.1969, 101.189026,
-    101.21489, 101.26227, 101.23136, 100.874344, 100.292435, 99.97032, 100.042465, 100.20005,
-    100.22932, 100.16151, 100.161964, 100.34678, 100.704315, 101.425, 102.597, 103.60831,
-    103.83519, 103.294525, 102.22968, 100.7092, 98.9499, 97.63003, 97.08076, 96.87386, 96.70654,
-    96.65675, 96.61587, 96.51998, 96.45408, 96.54924, 96.83043, 97.25884, 97.9858, 98.96866,
-    99.91471, 100.69387, 101.17806, 101.19141, 100.45517, 98.99773, 97.73313, 97.01937, 96.437325,
-    96.02425, 95.89889, 95.93446, 95.97121, 95.91874, 95.67423, 95.2395, 95.086815, 95.584114,
-    96.23399, 96.47056, 96.45196, 96.64883, 97.09562, 97.39292, 97.324715, 97.04542, 96.90777,
-    97.11544, 97.339966, 97.25408, 97.0718, 96.91559, 96.6085, 96.15996, 95.77221, 95.55682,
-    95.5676, 95.86631, 96.36631, 96.915504, 97.4672, 97.90113, 98.01727, 97.71596, 97.063774,
-    96.46816, 96.37012, 96.77777, 97.42924, 98.04011, 98.50683, 98.87205, 99.01866, 98.69425,
-    97.93167, 97.43735, 97.77949, 98.62234, 99.39363, 99.76609, 99.737915, 99.49165, 99.19918,
-    99.177414, 99.436646, 99.467926, 99.02209, 98.44342, 98.16015, 98.144684, 98.08699, 97.84402,
-    97.564804, 97.4445, 97.423996, 97.41245, 97.45244, 97.62426, 98.05726, 98.74739, 99.33597,
-    99.48976, 99.395096, 99.22207, 98.72234, 97.98644, 97.562584, 97.59301, 97.824844, 98.181496,
-    98.57321, 98.704765, 98.49131, 98.20924, 98.15009, 98.38678, 98.90053, 99.63392, 100.455635,
-    101.26368, 101.89372, 102.192535, 102.31893, 102.37148, 102.14489, 101.67995, 101.308105,
-    101.153175, 101.04828, 100.60143, 99.65207, 98.815056, 98.61893, 98.97221, 99.771675,
-    100.83672, 101.88636, 102.85269, 103.65654, 104.12939, 104.537056, 105.20937, 105.791306,
-    105.8856, 105.3922, 104.02354, 101.88067, 99.91745, 98.85536, 98.75112, 99.76277, 101.70136,
-    103.497665, 104.59322, 105.28472, 105.991104, 106.48321, 106.20037, 105.343094, 104.366005,
-    103.495865, 102.91034, 102.59615, 102.48939, 102.42934, 102.199875, 101.71732, 100.90293,
-    99.93021, 99.12412, 98.585266, 98.521675, 98.86208, 99.23335, 99.56497, 99.904686, 100.19062,
-    100.41124, 100.48729, 100.13798, 99.36276, 98.54183, 97.846344, 97.33659, 97.101105, 97.111916,
-    97.1129, 96.836205, 96.46943, 96.35805, 96.46575, 96.655785, 97.04893, 97.824646, 98.72831,
-    99.088585, 98.649345, 97.61053, 96.24793, 95.1406, 94.574265, 94.097855, 93.68507, 93.76804,
-    94.29391, 94.88744, 95.37156, 95.83506, 96.32216, 97.10225, 98.38167, 99.40191, 99.99769,
-    101.0546, 102.728546, 103.833664, 103.75229, 103.42706, 103.2453, 102.968735, 102.25746,
-    101.18985, 100.40445, 100.00154, 99.75635, 99.6243, 99.62469, 99.81407, 100.0428, 100.06421,
-    99.838425, 99.529594, 99.39926, 99.38641, 99.40423, 99.46353, 99.51947, 99.479996, 99.29536,
-    99.10047, 99.04322, 99.05936, 99.08899, 99.19237, 99.44412, 99.77547, 100.09174, 100.232155,
-    100.110596, 100.27886, 101.11053, 101.95042, 101.904816, 101.78752, 102.255356, 103.41129,
-    104.92997, 106.595375, 108.27366, 109.84998, 110.74889, 110.15549, 108.45649, 106.44685,
-    104.03053, 101.43872, 99.69632, 99.34907, 100.07063, 101.48346, 103.35061, 105.58963,
-    108.08439, 109.73714, 109.453674, 107.71268, 105.9436, 104.861565, 104.15976, 103.752754,
-    103.96934, 104.79104, 105.87828, 106.89373, 107.82604, 108.70574, 108.87621, 108.114655,
-    107.036125, 105.85047, 104.72465, 104.11763, 104.14231, 104.470535, 104.68472, 104.56151,
-    103.823074, 102.22055, 100.347374, 99.07884, 98.41193, 97.94641, 97.587105, 97.463425,
-    97.643265, 98.10014, 98.83193, 99.764404, 100.6079, 101.17175, 101.51186, 101.54915, 101.08173,
-    100.14362, 99.07694, 98.15827, 97.607796, 97.722824, 98.262955, 98.62131, 98.33485, 97.26329,
-    96.00742, 94.9839, 93.66295, 91.47643, 89.24515, 88.81855, 90.136406, 92.16388, 95.23932,
-    98.927826, 101.84727, 103.31414, 103.2613, 101.95006, 99.92532, 97.70225, 95.953636, 94.95322,
-    94.64795, 94.99017, 95.94195, 97.18448, 98.1071, 98.45094, 98.44011, 98.49398, 98.65641,
-    98.829254, 98.88765, 98.92217, 99.5082, 100.75358, 102.09092, 103.28596, 103.940056, 103.8738,
-    103.55432, 103.482834, 103.74884, 103.461075, 102.40743, 101.50326, 101.01985, 100.74512,
-    100.60531, 100.5417, 100.396416, 100.17403, 99.9191, 99.53655, 99.03267, 98.44803, 97.84086,
-    97.27396, 96.84946, 96.7271, 96.92978, 97.21535, 97.179596, 96.7816, 96.42567, 96.32643,
-    96.11332, 95.45748, 94.529366, 93.65925, 93.14223, 93.21005, 93.88169, 94.83521, 95.5315,
-    96.15465, 96.92861, 97.54594, 97.781944, 97.6418, 97.21736, 96.64935, 96.10474, 95.59562,
-    95.22484, 95.05159, 94.99235, 95.13866, 95.38979, 95.51354, 95.56577, 95.745926, 95.91349,
-    95.74263, 95.340004, 95.19132, 95.36491, 95.530365, 95.54896, 95.46245, 95.26397, 94.957306,
-    94.6795, 94.41737, 94.2778, 94.399506, 94.59808, 94.74131, 94.83892, 94.97012, 95.212166,
-    95.42616, 95.51969, 95.66772, 96.007576, 96.34843, 96.40089, 96.30312, 96.33615, 96.45635,
-    96.528465, 96.56146, 96.69007, 96.96228, 97.18227, 97.19657, 97.098465, 97.14816, 97.47857,
-    97.82384, 97.94623, 97.94858, 97.944016, 97.943436, 97.938385, 97.87033, 97.5124, 96.67171,
-    95.67821, 95.01834, 94.681305, 94.51832, 94.597496, 94.70505, 94.582596, 94.44494, 94.394196,
-    94.39012, 94.48734, 94.662834, 94.852036, 94.98889, 95.079285, 95.22824, 95.36802, 95.35812,
-    95.36357, 95.57798, 95.970345, 96.45703, 96.95363, 97.34463, 97.72888, 98.160385, 98.49946,
-    98.95999, 99.60578, 100.229965, 100.7466, 101.30292, 101.965866, 102.57345, 102.8205,
-    102.47757, 101.811264, 101.43581, 101.412224, 101.05162, 100.11197, 99.34906, 99.100044,
-    98.67155, 97.85645, 97.29585, 97.45958, 98.418755, 99.61225, 100.17024, 100.23669, 100.400345,
-    100.25814, 99.49433, 99.158516, 100.01111, 101.127884, 101.683014, 101.980545, 102.38773,
-    103.01178, 103.763824, 104.20268, 103.96684, 103.15823, 101.88146, 100.19864, 98.63881,
-    97.652374, 97.03871, 96.799126, 96.90551, 97.16356, 97.460434, 97.698586, 97.71139, 97.644516,
-    97.90067, 98.33746, 98.55725, 98.69458, 99.06628, 99.46287, 99.500374, 99.39998, 99.6638,
-    100.19232, 100.79994, 101.49916, 101.91115, 101.819664, 101.22047, 100.273415, 99.143364,
-    98.265755, 97.725266, 97.35606, 97.19121, 97.11844, 96.8703, 96.54594, 96.363335, 96.25648,
-    96.07888, 95.79991, 95.52712, 95.32957, 95.22435, 95.26449, 95.4351, 95.62697, 95.82858,
-    96.0358, 96.234634, 96.39665, 96.49041, 96.45114, 96.253334, 95.96065, 95.65123, 95.466095,
-    95.59014, 96.15724, 97.09857, 98.14432, 98.75949, 98.73296, 98.39903, 97.99315, 97.513535,
-    97.070984, 96.821045, 96.47358, 95.80675, 95.07753, 94.51579, 94.31933, 94.597694, 95.076935,
-    95.39103, 95.54408, 95.53018, 95.21677, 94.82566, 94.644226, 94.54217, 94.16737, 93.63303,
-    93.24039, 92.78399, 92.17238, 91.696625, 91.50012, 91.49021, 91.66829, 92.149185, 92.825325,
-    93.3527, 93.55865, 93.5735, 93.563545, 93.738144, 94.12974, 94.35096, 94.40847, 94.5743,
-    94.872284, 95.387535, 95.97911, 96.35492, 96.51702, 96.634, 96.79057, 96.914246, 96.98724,
-    97.047005, 97.014694, 96.865524, 96.68313, 96.49265, 96.351616, 96.32484, 96.27145, 95.960846,
-    95.36297, 94.78693, 94.43855, 94.1785, 93.948944, 93.797775, 93.722374, 93.72621, 93.84306,
-    94.209465, 94.586044, 94.452354, 94.09669, 93.95861, 94.04758, 94.42402, 95.27639, 96.41002,
-    97.30728, 98.02555, 98.66076, 98.92102, 98.923546, 99.230255, 99.913124, 100.42501, 100.434555,
-    100.13686, 99.97207, 99.85231, 99.48717, 99.03886, 98.875595, 98.9317, 98.8561, 98.414154,
-    97.84418, 97.463394, 96.90345, 96.368996, 96.02398, 95.742424, 95.770905, 96.02285, 96.19997,
-    96.31695, 96.40682, 96.2543, 95.91871, 95.84803, 96.01998, 96.0116, 95.862526, 95.72283,
-    95.58951, 95.55219, 95.619484, 95.717155, 95.76873, 95.76504, 95.88658, 96.283714, 96.99315,
-    97.903336, 98.38366, 98.20241, 97.55623, 96.84765, 96.39935, 96.24035, 96.34566, 96.73748,
-    97.41173, 98.260765, 98.82549, 99.09583, 99.32838, 99.699104, 100.0964, 99.8764, 98.85658,
-    97.72086, 96.964325, 96.70675, 96.60797, 96.17682, 95.72419, 95.47125, 95.29047, 95.172676,
-    95.15348, 95.34488, 95.768745, 96.40306, 97.28065, 97.6873, 97.218346, 97.22281, 97.879715,
-    98.543205, 99.12197, 99.37065, 99.28516, 99.19647, 99.10501, 98.9991, 98.81711, 98.09397,
-    97.05567, 96.27779, 95.907486, 95.903786, 96.5832, 97.86605, 99.02657, 99.62203, 99.732956,
-    99.851654, 100.11247, 100.14616, 99.54126, 98.401436, 97.23584, 96.439026, 95.98279, 95.74573,
-    95.825676, 96.15872, 96.575676, 96.75028, 96.59059, 96.234, 95.85235, 95.757195, 96.03702,
-    96.35563, 96.46891, 96.505745, 96.76073, 97.18365, 97.51361, 97.727554, 98.09277, 98.59096,
-    98.802086, 98.79225, 98.9655, 99.24422, 99.36083, 99.31504, 99.16187, 99.01319, 98.753204,
-    98.20755, 97.443504, 96.67408, 96.10521, 95.78165, 95.738815, 96.08642, 96.86439, 97.931694,
-    98.83349, 98.88678, 98.283394, 97.86153, 97.7223, 97.605576, 97.502075, 97.60106, 97.91216,
-    98.1137, 98.12647, 98.301, 98.7074, 99.16608, 99.589714, 99.97318, 100.444824, 100.92134,
-    101.29924, 101.94685, 103.04955, 104.442375, 106.164955, 107.89216, 109.37075, 110.620224,
-    111.60335, 112.07136, 112.05803, 111.79328, 111.48064, 111.050674, 110.20155, 108.84135,
-    107.29277, 106.27974, 105.886536, 105.458984, 104.92815, 104.3479, 103.884865, 103.52326,
-    102.82751, 101.95509, 101.196915, 100.495445, 100.15354, 100.365906, 100.4569, 100.28488,
-    100.42077, 100.93706, 101.37337, 101.60084, 101.66698, 101.605225, 101.60496, 101.6368,
-    101.50101, 101.13173, 100.53144, 99.92301, 99.371284, 98.82501, 98.2687, 97.84393, 97.72809,
-    97.74033, 97.66142, 97.54957, 97.54443, 97.669846, 97.87424, 98.159546, 98.46066, 98.60133,
-    98.491776, 98.18757, 97.90918, 97.71537, 97.49456, 97.38836, 97.36834, 97.33648, 97.47429,
-    97.79019, 98.15801, 98.43399, 98.56034, 98.54112, 98.41856, 98.33144, 98.29825, 98.311005,
-    98.37049, 98.38268, 98.34088, 98.326096, 98.41996, 98.6729, 98.93429, 99.16134, 99.50969,
-    99.76293, 99.39941, 98.830635, 98.53163, 98.37735, 98.22988, 98.13425, 98.02175, 97.89248,
-    97.82894, 97.90343, 97.96852, 97.92206, 97.80355, 97.59097, 97.39968, 97.3834, 97.44591,
-    97.42432, 97.196205, 96.77688, 96.40435, 96.176056, 96.024376, 96.03251, 96.3068, 96.7487,
-    97.180595, 97.470345, 97.85477, 98.49581, 99.16643, 99.76566, 100.223175, 100.454544,
-    100.49201, 100.419876, 100.22381, 99.916756, 99.570946, 99.20586, 98.792786, 98.384544,
-    98.0312, 97.80278, 97.66581, 97.58529, 97.55693, 97.50912, 97.45716, 97.40063, 97.44159,
-    97.679085, 97.99214, 98.36426, 98.84858, 99.31527, 99.772255, 100.517876, 101.643814,
-    102.760475, 103.71446, 104.43798, 104.8362, 104.97153, 104.83791, 104.19924, 103.26045,
-    102.525375, 101.77143, 101.04186, 100.8992, 101.208145, 101.68642, 102.365746, 103.2782,
-    103.96934, 104.36744, 104.703865, 104.760284, 104.52171, 104.19577, 103.790375, 103.306305,
-    102.65085, 101.84014, 101.26495, 101.04574, 100.791504, 100.39517, 100.09327, 99.618935,
-    98.85756, 98.144554, 97.55803, 97.08415, 96.91349, 97.29445, 98.1148, 98.721054, 98.841,
-    98.80598, 98.68179, 98.06648, 97.14746, 97.18433, 98.34432, 99.6025, 100.6928, 101.78029,
-    102.64636, 102.809814, 102.09644, 100.79203, 99.197, 97.51305, 96.61074, 96.52437, 96.49856,
-    96.30293, 96.23798, 96.64469, 97.189125, 97.45745, 97.52502, 97.479485, 97.46303, 97.47178,
-    97.31728, 97.07936, 96.95382, 96.9235, 97.14016, 97.55409, 97.77749, 98.01763, 98.49648,
-    98.713394, 98.55126, 98.39047, 98.39456, 98.22749, 97.63548, 97.09516, 96.85405, 96.87946,
-    97.24086, 97.88866, 98.531746, 98.95533, 99.136284, 99.2054, 99.26991, 99.494194, 99.90665,
-    100.25445, 100.40079, 100.327675, 100.00702, 99.536156, 99.17301, 99.11652, 99.28609,
-    99.502975, 99.817085, 100.34396, 101.10535, 101.86088, 102.33564, 102.60122, 102.73342,
-    102.71781, 102.2842, 101.400856, 100.62168, 100.25347, 100.32357, 100.579605, 100.790184,
-    100.846344, 100.59385, 100.250565, 100.06806, 100.15216, 100.704124, 101.5458, 102.57129,
-    103.63103, 104.043396, 103.79962, 103.6271, 103.53265, 103.07199, 102.15133, 100.961395,
-    99.89494, 99.363075, 99.620964, 100.773766, 102.32142, 103.61585, 104.23447, 104.005325,
-    103.225716, 102.519745, 102.27714, 102.39644, 102.703575, 103.06191, 102.99427, 102.40603,
-    102.11293, 102.386925, 102.77759, 102.80142, 102.72934, 102.67637, 102.20614, 101.29192,
-    100.71951, 100.525246, 100.34571, 100.236664, 100.21587, 100.31233, 100.543175, 100.64197,
-    100.47984, 100.471725, 100.68488, 101.0808, 102.02705, 103.23827, 103.81399, 103.859604,
-    103.77512, 103.69857, 103.67372, 103.30097, 102.4848, 101.866425, 101.51362, 101.104225,
-    100.773415, 100.72333, 100.515755, 100.35426, 100.438156, 100.456856, 100.25353, 100.05795,
-    100.04455, 100.057045, 100.01257, 99.937294, 99.63129, 99.05248, 98.24499, 97.57208, 97.327484,
-    97.07438, 96.41039, 95.85783, 95.86219, 96.21364, 96.90303, 98.421196, 100.12839, 101.08906,
-    101.547745, 101.85951, 102.22161, 102.76605, 102.84268, 102.39271, 102.23991, 101.82953,
-    100.913345, 100.07126, 99.81269, 100.50665, 101.69052, 102.416954, 102.617676, 102.928566,
-    103.4679, 103.97148, 104.21125, 104.12164, 104.19317, 104.5147, 104.562164, 104.28199,
-    104.01119, 103.62666, 102.78028, 101.6845, 100.58589, 99.34305, 98.42802, 98.15727, 98.23637,
-    98.3415, 98.71643, 98.59179, 97.76607, 97.276146, 97.22924, 97.84045, 99.16444, 100.28381,
-    101.061714, 101.96865, 102.95818, 103.29159, 102.79673, 101.77501, 100.7398, 100.03388,
-    99.59374, 99.6967, 100.06202, 100.04999, 99.56392, 99.02127, 98.78649, 98.77727, 98.684784,
-    97.76416, 96.089455, 95.626335, 97.09232, 99.15364, 100.64923, 101.57784, 101.74755, 101.83667,
-    102.053116, 101.71563, 101.13791, 101.35997, 102.61012, 103.37986, 102.92321, 102.69957,
-    103.10741, 102.99937, 102.4462, 102.39654, 102.28539, 101.81413, 101.09681, 100.430466,
-    99.93187, 99.74416, 100.03603, 100.29239, 100.26612, 101.24218, 103.252754, 104.53266,
-    105.10781, 105.19137, 104.47025, 104.341644, 105.18512, 105.68979, 106.01435, 106.466125,
-    106.71773, 106.77113, 106.77532, 106.76973, 106.69599, 106.333664, 105.66109, 105.46298,
-    106.090836, 106.55972, 106.385025, 105.89273, 105.58176, 105.44548, 105.134514,
-];
@@ -0,0 +1,163 @@
+//! Free text comments on depth intervals, drawn in an annotation track, see
+//! [`crate::plot::log_plot_context::LogPlotContext::add_annotation_track`].
+//!
+//! ```ignore
+//! let mut annotation_context = plot_context.add_annotation_track(1524.0..1850.0, 120);
+//! annotation_context.draw(
+//!     "COMMENTS",
+//!     &[Annotation::new(1612.0, 1618.5, "oil show, fluorescence")],
+//! )?;
+//! ```
+
+/// A comment on the interval from `top` to `base`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub top: f64,
+    pub base: f64,
+    pub text: String,
+}
+
+impl Annotation {
+    pub fn new<T>(top: f64, base: f64, text: T) -> Self
+    where
+        T: Into<String>,
+    {
+        Self {
+            top,
+            base,
+            text: text.into(),
+        }
+    }
+}
+
+/// Break `text` into lines no wider than `max_width` as measured by
+/// `measure`, at white space. A word wider than `max_width` gets a line of its own.
+pub(crate) fn wrap_text<F, E>(text: &str, max_width: i32, mut measure: F) -> Result<Vec<String>, E>
+where
+    F: FnMut(&str) -> Result<i32, E>,
+{
+    let mut lines = vec![];
+    let mut line = String::new();
+
+    for word in text.split_whitespace() {
+        if line.is_empty() {
+            line.push_str(word);
+            continue;
+        }
+
+        let candidate = format!("{line} {word}");
+        if measure(&candidate)? <= max_width {
+            line = candidate;
+        } else {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        }
+    }
+
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    Ok(lines)
+}
+
+/// Shift labels given as `(wanted top, height)` in pixels so that they are
+/// at least `gap` apart, moving them as little as possible and keeping them
+/// within `min..=max` as long as they fit; labels that do not fit run past
+/// `max`. Returns the tops in the order of `labels`.
+pub(crate) fn stack_labels(labels: &[(i32, i32)], gap: i32, min: i32, max: i32) -> Vec<i32> {
+    let mut order: Vec<usize> = (0..labels.len()).collect();
+    order.sort_by_key(|i| labels[*i].0);
+
+    let mut tops: Vec<i32> = labels.iter().map(|(top, _)| *top).collect();
+    // tops of the labels stacked from the top edge, the highest they can go
+    let mut floors = vec![min; labels.len()];
+
+    // push down past the label above
+    let (mut free, mut floor) = (min, min);
+    for i in order.iter().copied() {
+        tops[i] = tops[i].max(free);
+        free = tops[i] + labels[i].1 + gap;
+        floors[i] = floor;
+        floor += labels[i].1 + gap;
+    }
+
+    // pull back up from the bottom edge, without leaving the top edge
+    let mut limit = max;
+    for i in order.iter().rev().copied() {
+        tops[i] = tops[i].min(limit - labels[i].1).max(floors[i]);
+        limit = tops[i] - gap;
+    }
+
+    tops
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use super::*;
+
+    /// Ten pixels a character
+    fn wrap(text: &str, max_width: i32) -> Vec<String> {
+        wrap_text(text, max_width, |line| {
+            Ok::<_, Infallible>(line.chars().count() as i32 * 10)
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn wraps_at_white_space() {
+        assert_eq!(
+            wrap("oil show,  good\nfluorescence cut", 120),
+            vec!["oil show,", "good", "fluorescence", "cut"]
+        );
+        assert_eq!(wrap("oil show", 80), vec!["oil show"]);
+        assert!(wrap("  ", 80).is_empty());
+    }
+
+    #[test]
+    fn a_word_wider_than_the_line_gets_a_line_of_its_own() {
+        assert_eq!(
+            wrap("a microfractured sandstone", 60),
+            vec!["a", "microfractured", "sandstone"]
+        );
+        assert_eq!(wrap("microfractured", 60), vec!["microfractured"]);
+    }
+
+    #[test]
+    fn leaves_labels_apart_where_they_are() {
+        assert_eq!(stack_labels(&[(0, 10), (50, 10)], 4, 0, 100), vec![0, 50]);
+        assert!(stack_labels(&[], 4, 0, 100).is_empty());
+    }
+
+    #[test]
+    fn pushes_overlapping_labels_down() {
+        assert_eq!(
+            stack_labels(&[(10, 10), (12, 10), (14, 10)], 2, 0, 100),
+            vec![10, 22, 34]
+        );
+        // the order of the labels is kept, not the order of their tops
+        assert_eq!(stack_labels(&[(20, 10), (15, 10)], 2, 0, 100), vec![27, 15]);
+        // and a label above the top edge is brought in
+        assert_eq!(stack_labels(&[(-5, 10)], 2, 0, 100), vec![0]);
+    }
+
+    #[test]
+    fn pulls_labels_back_up_from_the_bottom_edge() {
+        assert_eq!(
+            stack_labels(&[(80, 10), (85, 10), (90, 10)], 2, 0, 100),
+            vec![66, 78, 90]
+        );
+        assert_eq!(stack_labels(&[(90, 10), (80, 10)], 2, 0, 100), vec![90, 78]);
+    }
+
+    #[test]
+    fn labels_that_do_not_fit_run_past_the_bottom_edge() {
+        // 3 × 30 + 2 × 5 > 60: stacked from the top edge, still `gap` apart
+        assert_eq!(
+            stack_labels(&[(0, 30), (10, 30), (20, 30)], 5, 0, 60),
+            vec![0, 35, 70]
+        );
+        assert_eq!(stack_labels(&[(50, 30), (55, 30)], 5, 10, 60), vec![10, 45]);
+    }
+}
@@ -4,7 +4,11 @@ use chartrs::{
 };
 use chartrs_backend::{DrawingBackend, IntoFont, SizeDesc, TextStyle};
 
-use crate::plot::log_plot_context::LogPlotContext;
+use crate::{
+    plot::log_plot_context::LogPlotContext,
+    unit::{UnitRegistry, UnitSystem},
+    well::WellInfo,
+};
 
 pub struct LogPlotBuilder<'a, DB>
 where
@@ -18,6 +22,13 @@ where
 
     pub(crate) head_text_style: TextStyle<'a>,
     pub(crate) detail_text_style: TextStyle<'a>,
+
+    pub(crate) well_info: Option<WellInfo>,
+    pub(crate) well_header_height: i32,
+
+    pub(crate) units: UnitRegistry,
+    pub(crate) unit_system: Option<UnitSystem>,
+    pub(crate) depth_unit: Option<String>,
 }
 
 impl<'a, DB> LogPlotBuilder<'a, DB>
@@ -34,6 +45,13 @@ where
 
             head_text_style: TextStyle::from(("sans-serif", 16).into_font()).hv_center(),
             detail_text_style: TextStyle::from(("sans-serif", 14).into_font()).hv_center(),
+
+            well_info: None,
+            well_header_height: 0,
+
+            units: UnitRegistry::default(),
+            unit_system: None,
+            depth_unit: None,
         }
     }
 
@@ -53,6 +71,36 @@ where
         self
     }
 
+    /// Reserve `height` pixels above the depth and channel heads for the log
+    /// heading of `info`
+    pub fn well_header(&mut self, info: WellInfo, height: i32) -> &mut Self {
+        self.well_info = Some(info);
+        self.well_header_height = height;
+        self
+    }
+
+    /// Render curve values and depth labels in the units of `unit_system`.
+    /// Curves whose unit is unknown to the unit registry are drawn as they are.
+    pub fn unit_system(&mut self, unit_system: UnitSystem) -> &mut Self {
+        self.unit_system = Some(unit_system);
+        self
+    }
+
+    /// Unit of the depth ranges and of the index of the drawn curves
+    pub fn depth_unit<I>(&mut self, depth_unit: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.depth_unit = Some(depth_unit.into());
+        self
+    }
+
+    /// Replace the default unit registry, e.g. to add company specific units
+    pub fn unit_registry(&mut self, units: UnitRegistry) -> &mut Self {
+        self.units = units;
+        self
+    }
+
     pub fn margin<S: SizeDesc>(&mut self, size: S) -> &mut Self {
         let size = size.in_pixels(self.root_area).max(0) as u32;
         self.margin = [size, size, size, size];
@@ -94,6 +142,8 @@ where
     {
         // Now the root drawing area is to be split into
         //
+        // +-----------------------------------------+
+        // |        0 (Well Header, optional)        |
         // +----------+------------------------------+
         // |   Depth  |    1 (Head Legend Area)      |
         // +----------+------------------------------+
@@ -103,10 +153,16 @@ where
         // |          |                              |
         // |          |                              |
         // +----------+------------------------------+
-        let (left, right) = self
-            .root_area
-            .apply_margin(self.margin)
-            .split_horizontally(self.depth_area_width);
+        let area = self.root_area.apply_margin(self.margin);
+
+        let (well_header, area) = if self.well_info.is_some() {
+            let (header, rest) = area.split_vertically(self.well_header_height);
+            (Some(header), rest)
+        } else {
+            (None, area)
+        };
+
+        let (left, right) = area.split_horizontally(self.depth_area_width);
 
         let (left_head, left_body) = left.split_vertically(self.head_height);
 
@@ -117,6 +173,8 @@ where
                 left_body.get_pixel_range(),
             )),
             drawing_area: right,
+            track_spans: vec![],
+            well_header,
             builder: self,
         }
     }
@@ -3,20 +3,9 @@ use chartrs::{
         cartesian::{Cartesian2dY, HorizontalLine},
         ranged1d::KeyPointHint,
     },
-    prelude::{DrawingArea, DrawingAreaErrorKind, Ranged},
+    prelude::Ranged,
 };
-use chartrs_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
-
-pub(crate) trait LogPlotDrawingAreaFunc<Y, DB: DrawingBackend> {
-    fn draw_plot_horizontal<DrawFunc, YH>(
-        &self,
-        y_keypoints: YH,
-        draw_func: DrawFunc,
-    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
-    where
-        YH: KeyPointHint,
-        DrawFunc: FnMut(&mut DB, HorizontalLine<Y>, bool) -> Result<(), DrawingErrorKind<DB::ErrorType>>;
-}
+use chartrs_backend::BackendCoord;
 
 pub(crate) trait LogPlotCatesian2dYFunc<Y> {
     fn draw_plot_horizontal<E, YH, DrawFunc>(&self, y_keypoints: YH, draw_hori: DrawFunc) -> Result<(), E>
@@ -25,27 +14,6 @@ pub(crate) trait LogPlotCatesian2dYFunc<Y> {
         DrawFunc: FnMut(HorizontalLine<Y>, bool) -> Result<(), E>;
 }
 
-impl<DB, Y> LogPlotDrawingAreaFunc<Y, DB> for DrawingArea<DB, Cartesian2dY<Y>>
-where
-    DB: DrawingBackend,
-    Y: Ranged,
-{
-    fn draw_plot_horizontal<DrawFunc, YH>(
-        &self,
-        y_keypoints: YH,
-        mut draw_func: DrawFunc,
-    ) -> Result<(), DrawingAreaErrorKind<<DB as DrawingBackend>::ErrorType>>
-    where
-        YH: KeyPointHint,
-        DrawFunc: FnMut(&mut DB, HorizontalLine<Y>, bool) -> Result<(), DrawingErrorKind<<DB as DrawingBackend>::ErrorType>>,
-    {
-        self.backend_ops(move |drawing_backend| {
-            self.coord()
-                .draw_plot_horizontal(y_keypoints, |line, is_dark| draw_func(drawing_backend, line, is_dark))
-        })
-    }
-}
-
 impl<Y> LogPlotCatesian2dYFunc<Y> for Cartesian2dY<Y>
 where
     Y: Ranged,
@@ -0,0 +1,211 @@
+//! Wellbore completion descriptions drawn as a schematic in a completion
+//! track, see [`crate::plot::log_plot_context::LogPlotContext::add_completion_track`].
+//!
+//! ```ignore
+//! let mut completion = Completion::default();
+//! completion
+//!     .add(Component::new(ComponentKind::Cement, 1524.0, 1850.0, 12.25, 9.625))
+//!     .add(Component::new(ComponentKind::Casing, 1524.0, 1850.0, 9.625, 8.681))
+//!     .add(Component::new(ComponentKind::Tubing, 1524.0, 1780.0, 3.5, 2.992))
+//!     .add(Component::new(ComponentKind::Packer, 1770.0, 1775.0, 8.681, 3.5))
+//!     .add(Component::new(ComponentKind::Perforation, 1790.0, 1805.0, 9.625, 0.0));
+//!
+//! plot_context
+//!     .add_completion_track(1524.0..1850.0, 100)
+//!     .draw("COMPLETION", &completion)?;
+//! ```
+
+use chartrs_backend::BackendColor;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComponentKind {
+    Casing,
+    /// A casing string hung from inside the previous one, its top marked by a hanger
+    Liner,
+    Tubing,
+    Packer,
+    /// Shots through the casing; only the outer diameter is used, the one the shots start at
+    Perforation,
+    /// Cement in the annulus between the hole, as outer diameter, and the casing, as inner diameter
+    Cement,
+}
+
+impl ComponentKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ComponentKind::Casing => "Casing",
+            ComponentKind::Liner => "Liner",
+            ComponentKind::Tubing => "Tubing",
+            ComponentKind::Packer => "Packer",
+            ComponentKind::Perforation => "Perforation",
+            ComponentKind::Cement => "Cement",
+        }
+    }
+
+    pub fn color(&self) -> BackendColor {
+        let rgb = match self {
+            ComponentKind::Casing | ComponentKind::Liner => (0, 0, 0),
+            ComponentKind::Tubing => (0, 0, 160),
+            ComponentKind::Packer => (160, 0, 0),
+            ComponentKind::Perforation => (255, 0, 0),
+            ComponentKind::Cement => (180, 180, 180),
+        };
+
+        BackendColor { alpha: 1.0, rgb }
+    }
+
+    /// Order the kinds are drawn in, the annulus fill below the walls and the perforations on top
+    pub(crate) fn layer(&self) -> u8 {
+        match self {
+            ComponentKind::Cement => 0,
+            ComponentKind::Casing | ComponentKind::Liner => 1,
+            ComponentKind::Tubing => 2,
+            ComponentKind::Packer => 3,
+            ComponentKind::Perforation => 4,
+        }
+    }
+}
+
+/// A part of the completion from `top` to `base`, with its outer and inner
+/// diameters in any unit, the same for all components.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Component {
+    pub kind: ComponentKind,
+    pub top: f64,
+    pub base: f64,
+    pub outer_diameter: f64,
+    pub inner_diameter: f64,
+}
+
+impl Component {
+    pub fn new(
+        kind: ComponentKind,
+        top: f64,
+        base: f64,
+        outer_diameter: f64,
+        inner_diameter: f64,
+    ) -> Self {
+        Self {
+            kind,
+            top,
+            base,
+            outer_diameter,
+            inner_diameter,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Completion {
+    pub components: Vec<Component>,
+}
+
+impl Completion {
+    pub fn add(&mut self, component: Component) -> &mut Self {
+        self.components.push(component);
+        self
+    }
+
+    /// The largest outer diameter, the one drawn across most of the track
+    pub fn max_diameter(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|component| component.outer_diameter)
+            .filter(|diameter| diameter.is_finite())
+            .fold(0.0, f64::max)
+    }
+
+    /// Pixels from the center line of the track to the wall of a diameter,
+    /// the largest diameter reaching `half_width` pixels. `None` when no
+    /// component has a diameter.
+    pub(crate) fn radius_mapper(&self, half_width: i32) -> Option<impl Fn(f64) -> i32> {
+        let max_diameter = self.max_diameter();
+        if max_diameter <= 0.0 {
+            return None;
+        }
+
+        let scale = half_width.max(1) as f64 / (max_diameter / 2.0);
+        Some(move |diameter: f64| (diameter / 2.0 * scale).round() as i32)
+    }
+
+    /// The kinds of the components in the order they first appear
+    pub fn kinds(&self) -> Vec<ComponentKind> {
+        let mut kinds = vec![];
+        for component in self.components.iter() {
+            if !kinds.contains(&component.kind) {
+                kinds.push(component.kind);
+            }
+        }
+        kinds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn component(kind: ComponentKind, outer_diameter: f64, inner_diameter: f64) -> Component {
+        Component::new(kind, 1524.0, 1850.0, outer_diameter, inner_diameter)
+    }
+
+    fn completion() -> Completion {
+        let mut completion = Completion::default();
+        completion
+            .add(component(ComponentKind::Perforation, 9.625, 0.0))
+            .add(component(ComponentKind::Casing, 9.625, 8.681))
+            .add(component(ComponentKind::Cement, 12.25, 9.625))
+            .add(component(ComponentKind::Casing, f64::NAN, 6.0));
+        completion
+    }
+
+    #[test]
+    fn largest_diameter_reaches_the_half_width() {
+        let completion = completion();
+        assert_eq!(completion.max_diameter(), 12.25);
+
+        let radius = completion.radius_mapper(49).unwrap();
+        assert_eq!(radius(12.25), 49);
+        assert_eq!(radius(9.625), 39);
+        assert_eq!(radius(0.0), 0);
+    }
+
+    #[test]
+    fn no_radius_without_diameters() {
+        assert!(Completion::default().radius_mapper(49).is_none());
+
+        let mut completion = Completion::default();
+        completion.add(component(ComponentKind::Tubing, f64::NAN, 0.0));
+        assert!(completion.radius_mapper(49).is_none());
+    }
+
+    #[test]
+    fn kinds_keep_their_first_appearance_and_layers_put_cement_below() {
+        let mut completion = completion();
+        assert_eq!(
+            completion.kinds(),
+            vec![
+                ComponentKind::Perforation,
+                ComponentKind::Casing,
+                ComponentKind::Cement
+            ]
+        );
+
+        completion
+            .components
+            .sort_by_key(|component| component.kind.layer());
+        let kinds: Vec<ComponentKind> = completion
+            .components
+            .iter()
+            .map(|component| component.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ComponentKind::Cement,
+                ComponentKind::Casing,
+                ComponentKind::Casing,
+                ComponentKind::Perforation
+            ]
+        );
+    }
+}
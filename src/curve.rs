@@ -0,0 +1,402 @@
+/// A single sampled curve, e.g. `GR` or `DTCO`.
+///
+/// Values are kept exactly as read; missing samples keep their `null_value`
+/// sentinel (or are `NaN`) so that drawing code can decide how to treat them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Curve {
+    pub mnemonic: String,
+    pub unit: String,
+    pub description: String,
+    pub values: Vec<f64>,
+    pub null_value: Option<f64>,
+}
+
+impl Curve {
+    pub fn new<M, U>(mnemonic: M, unit: U) -> Self
+    where
+        M: Into<String>,
+        U: Into<String>,
+    {
+        Self {
+            mnemonic: mnemonic.into(),
+            unit: unit.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn description<I>(&mut self, description: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.description = description.into();
+        self
+    }
+
+    pub fn null_value(&mut self, null_value: Option<f64>) -> &mut Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// Whether `value` is a missing sample of this curve (`NaN` or the null sentinel).
+    pub fn is_null(&self, value: f64) -> bool {
+        is_null(value, self.null_value)
+    }
+
+    /// Value at `at`, linearly interpolated between the two surrounding
+    /// samples of the monotonic `index`. `None` outside of the index range or
+    /// when a surrounding sample is null.
+    pub fn interpolate(&self, index: &[f64], at: f64) -> Option<f64> {
+        locate(index, at)
+            .and_then(|position| interpolate(&self.values, position, |v| self.is_null(v)))
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// A curve with several values per index sample, e.g. an NMR `T2` distribution
+/// or an image log. `values` holds one row per index sample.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ArrayCurve {
+    pub mnemonic: String,
+    pub unit: String,
+    pub description: String,
+    pub values: Vec<Vec<f64>>,
+    pub null_value: Option<f64>,
+}
+
+impl ArrayCurve {
+    /// Number of elements per sample
+    pub fn width(&self) -> usize {
+        self.values.first().map(Vec::len).unwrap_or(0)
+    }
+
+    /// Extract one element of the array as a scalar curve
+    pub fn element(&self, i: usize) -> Option<Curve> {
+        if i >= self.width() {
+            return None;
+        }
+
+        Some(Curve {
+            mnemonic: format!("{}[{}]", self.mnemonic, i + 1),
+            unit: self.unit.clone(),
+            description: self.description.clone(),
+            values: self
+                .values
+                .iter()
+                .map(|row| row.get(i).copied().unwrap_or(f64::NAN))
+                .collect(),
+            null_value: self.null_value,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// A curve of free text values, e.g. a lithology description column.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextCurve {
+    pub mnemonic: String,
+    pub unit: String,
+    pub description: String,
+    pub values: Vec<String>,
+}
+
+/// A set of curves sharing one index curve (depth or time).
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveSet {
+    pub index: Curve,
+    pub curves: Vec<Curve>,
+    pub arrays: Vec<ArrayCurve>,
+    pub texts: Vec<TextCurve>,
+}
+
+impl CurveSet {
+    /// Find a curve by mnemonic, ignoring case. The index curve is searched as well.
+    pub fn curve(&self, mnemonic: &str) -> Option<&Curve> {
+        std::iter::once(&self.index)
+            .chain(self.curves.iter())
+            .find(|curve| curve.mnemonic.eq_ignore_ascii_case(mnemonic))
+    }
+
+    pub fn curve_mut(&mut self, mnemonic: &str) -> Option<&mut Curve> {
+        std::iter::once(&mut self.index)
+            .chain(self.curves.iter_mut())
+            .find(|curve| curve.mnemonic.eq_ignore_ascii_case(mnemonic))
+    }
+
+    pub fn array(&self, mnemonic: &str) -> Option<&ArrayCurve> {
+        self.arrays
+            .iter()
+            .find(|curve| curve.mnemonic.eq_ignore_ascii_case(mnemonic))
+    }
+
+    pub fn text(&self, mnemonic: &str) -> Option<&TextCurve> {
+        self.texts
+            .iter()
+            .find(|curve| curve.mnemonic.eq_ignore_ascii_case(mnemonic))
+    }
+
+    /// Get the `(value, index)` pairs of a curve, in the order expected by
+    /// [`crate::plot::channel_context::ChannelContext::draw_series_with_range`].
+    pub fn points<'s>(&'s self, mnemonic: &str) -> Option<impl Iterator<Item = (f64, f64)> + 's> {
+        self.curve(mnemonic).map(|curve| {
+            curve
+                .values
+                .iter()
+                .zip(self.index.values.iter())
+                .map(|(value, index)| (*value, *index))
+        })
+    }
+
+    /// Resample every curve onto a regular index from the first to the last
+    /// index value, `step` apart. Values are linearly interpolated, a sample
+    /// next to a null one becomes `NaN`, text curves take the nearest value.
+    pub fn resample(&self, step: f64) -> CurveSet {
+        let index = &self.index.values;
+        let (first, last) = match (index.first(), index.last()) {
+            (Some(first), Some(last)) if step != 0.0 && step.is_finite() => (*first, *last),
+            _ => return self.clone(),
+        };
+
+        let step = if last < first {
+            -step.abs()
+        } else {
+            step.abs()
+        };
+        let count = ((last - first) / step + 1e-6).floor() as usize + 1;
+        let depths: Vec<f64> = (0..count).map(|i| first + i as f64 * step).collect();
+        let positions: Vec<_> = depths.iter().map(|at| locate(index, *at)).collect();
+
+        let resample_values = |values: &[f64], is_null: &dyn Fn(f64) -> bool| -> Vec<f64> {
+            positions
+                .iter()
+                .map(|position| {
+                    position
+                        .and_then(|position| interpolate(values, position, is_null))
+                        .unwrap_or(f64::NAN)
+                })
+                .collect()
+        };
+
+        CurveSet {
+            index: Curve {
+                values: depths.clone(),
+                ..self.index.clone()
+            },
+            curves: self
+                .curves
+                .iter()
+                .map(|curve| Curve {
+                    values: resample_values(&curve.values, &|v| curve.is_null(v)),
+                    ..curve.clone()
+                })
+                .collect(),
+            arrays: self
+                .arrays
+                .iter()
+                .map(|array| {
+                    let columns: Vec<Vec<f64>> = (0..array.width())
+                        .map(|i| {
+                            let column: Vec<f64> = array
+                                .values
+                                .iter()
+                                .map(|row| row.get(i).copied().unwrap_or(f64::NAN))
+                                .collect();
+                            resample_values(&column, &|v| is_null(v, array.null_value))
+                        })
+                        .collect();
+
+                    ArrayCurve {
+                        values: (0..count)
+                            .map(|row| columns.iter().map(|column| column[row]).collect())
+                            .collect(),
+                        ..array.clone()
+                    }
+                })
+                .collect(),
+            texts: self
+                .texts
+                .iter()
+                .map(|text| TextCurve {
+                    values: positions
+                        .iter()
+                        .map(|position| match position {
+                            Some((i, t)) => {
+                                let i = if *t < 0.5 { *i } else { *i + 1 };
+                                text.values.get(i).cloned().unwrap_or_default()
+                            }
+                            None => String::new(),
+                        })
+                        .collect(),
+                    ..text.clone()
+                })
+                .collect(),
+        }
+    }
+
+    /// Number of index samples
+    pub fn len(&self) -> usize {
+        self.index.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.values.is_empty()
+    }
+}
+
+/// Whether `value` is a missing sample, `NaN` or the `null_value` sentinel.
+/// Every null test of the crate goes through here.
+pub fn is_null(value: f64, null_value: Option<f64>) -> bool {
+    value.is_nan() || null_value.is_some_and(|null| (value - null).abs() < 1e-9)
+}
+
+/// Position of `at` within the monotonic (increasing or decreasing) `index`:
+/// the sample at or before it and the fraction of the way to the next one.
+fn locate(index: &[f64], at: f64) -> Option<(usize, f64)> {
+    let (first, last) = (*index.first()?, *index.last()?);
+    let increasing = last >= first;
+
+    let inside = if increasing {
+        first <= at && at <= last
+    } else {
+        last <= at && at <= first
+    };
+    if !inside {
+        return None;
+    }
+
+    let i = index
+        .partition_point(|v| if increasing { *v <= at } else { *v >= at })
+        .saturating_sub(1);
+    if i + 1 >= index.len() {
+        return Some((index.len() - 1, 0.0));
+    }
+
+    let span = index[i + 1] - index[i];
+    let t = if span == 0.0 {
+        0.0
+    } else {
+        (at - index[i]) / span
+    };
+
+    Some((i, t))
+}
+
+fn interpolate(values: &[f64], (i, t): (usize, f64), is_null: impl Fn(f64) -> bool) -> Option<f64> {
+    let a = *values.get(i)?;
+    if is_null(a) {
+        return None;
+    }
+    if t == 0.0 {
+        return Some(a);
+    }
+
+    let b = *values.get(i + 1)?;
+    if is_null(b) {
+        return None;
+    }
+
+    Some(a + (b - a) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve_set() -> CurveSet {
+        let mut index = Curve::new("DEPT", "m");
+        index.values = vec![100.0, 101.0, 102.0, 103.0];
+
+        let mut gr = Curve::new("GR", "gAPI");
+        gr.null_value(Some(-999.25));
+        gr.values = vec![0.0, 10.0, -999.25, 30.0];
+
+        CurveSet {
+            index,
+            curves: vec![gr],
+            arrays: vec![ArrayCurve {
+                mnemonic: "NMR".to_string(),
+                values: vec![
+                    vec![0.0, 1.0],
+                    vec![2.0, -1.0],
+                    vec![4.0, 5.0],
+                    vec![6.0, 7.0],
+                ],
+                null_value: Some(-1.0),
+                ..Default::default()
+            }],
+            texts: vec![TextCurve {
+                mnemonic: "LITH".to_string(),
+                values: ["sand", "shale", "lime", "dolo"].map(String::from).to_vec(),
+                ..Default::default()
+            }],
+        }
+    }
+
+    fn assert_samples(values: &[f64], expected: &[f64]) {
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                value == expected || value.is_nan() && expected.is_nan(),
+                "{values:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn resamples_across_a_null_sample() {
+        let resampled = curve_set().resample(0.5);
+
+        assert_eq!(
+            resampled.index.values,
+            vec![100.0, 100.5, 101.0, 101.5, 102.0, 102.5, 103.0]
+        );
+
+        // every sample next to the null one is missing, the others are interpolated
+        let gr = resampled.curve("GR").unwrap();
+        assert_samples(
+            &gr.values,
+            &[0.0, 5.0, 10.0, f64::NAN, f64::NAN, f64::NAN, 30.0],
+        );
+        assert_eq!(gr.null_value, Some(-999.25));
+
+        let nmr = resampled.array("NMR").unwrap();
+        let column = |i: usize| -> Vec<f64> { nmr.values.iter().map(|row| row[i]).collect() };
+        assert_samples(&column(0), &[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert_samples(
+            &column(1),
+            &[1.0, f64::NAN, f64::NAN, f64::NAN, 5.0, 6.0, 7.0],
+        );
+
+        let lith = resampled.text("LITH").unwrap();
+        assert_eq!(
+            lith.values,
+            vec!["sand", "shale", "shale", "lime", "lime", "dolo", "dolo"]
+        );
+    }
+
+    #[test]
+    fn resamples_a_decreasing_index() {
+        let mut curves = curve_set();
+        curves.index.values.reverse();
+        let resampled = curves.resample(1.5);
+
+        assert_eq!(resampled.index.values, vec![103.0, 101.5, 100.0]);
+        assert_samples(
+            &resampled.curve("GR").unwrap().values,
+            &[0.0, f64::NAN, 30.0],
+        );
+    }
+}
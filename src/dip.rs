@@ -0,0 +1,95 @@
+//! Dips from dipmeter and borehole image interpretation, drawn as tadpoles by
+//! [`crate::plot::channel_context::ChannelContext::draw_tadpoles`].
+//!
+//! ```ignore
+//! channel_context.configure_style().dip_scale().title("DIP".to_string()).draw(&[])?;
+//! channel_context.draw_tadpoles(
+//!     &[Dip::new(1530.2, 12.0, 135.0, DipType::Bedding)],
+//!     &TadpoleStyles::default(),
+//! )?;
+//! ```
+
+use chartrs_backend::BackendColor;
+
+use crate::marker::{MarkerShape, PointMarker};
+
+/// What a dip was picked on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DipType {
+    Bedding,
+    Fracture,
+    Fault,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dip {
+    pub depth: f64,
+    /// Degrees from horizontal, `0` to `90`
+    pub angle: f64,
+    /// Degrees clockwise from north
+    pub azimuth: f64,
+    pub dip_type: DipType,
+}
+
+impl Dip {
+    pub fn new(depth: f64, angle: f64, azimuth: f64, dip_type: DipType) -> Self {
+        Self {
+            depth,
+            angle,
+            azimuth,
+            dip_type,
+        }
+    }
+}
+
+/// How the tadpoles of one [`DipType`] look: a marker at the dip angle and
+/// a tail from its edge.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TadpoleStyle {
+    pub marker: PointMarker,
+    /// Length of the tail in pixels, from the edge of the marker
+    pub tail_length: u32,
+}
+
+impl TadpoleStyle {
+    pub fn new(shape: MarkerShape, color: BackendColor) -> Self {
+        Self {
+            marker: PointMarker::new(shape, 4, color),
+            tail_length: 12,
+        }
+    }
+}
+
+/// The style of each [`DipType`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TadpoleStyles {
+    pub bedding: TadpoleStyle,
+    pub fracture: TadpoleStyle,
+    pub fault: TadpoleStyle,
+}
+
+impl Default for TadpoleStyles {
+    /// Green circles for bedding, blue triangles for fractures and red squares for faults
+    fn default() -> Self {
+        let color = |r, g, b| BackendColor {
+            alpha: 1.0,
+            rgb: (r, g, b),
+        };
+
+        Self {
+            bedding: TadpoleStyle::new(MarkerShape::Circle, color(0, 128, 0)),
+            fracture: TadpoleStyle::new(MarkerShape::Triangle, color(0, 0, 255)),
+            fault: TadpoleStyle::new(MarkerShape::Square, color(255, 0, 0)),
+        }
+    }
+}
+
+impl TadpoleStyles {
+    pub fn get(&self, dip_type: DipType) -> &TadpoleStyle {
+        match dip_type {
+            DipType::Bedding => &self.bedding,
+            DipType::Fracture => &self.fracture,
+            DipType::Fault => &self.fault,
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Discrete curves (facies codes, flags, zone indices) drawn as colored
+//! blocks in a discrete track, see
+//! [`crate::plot::log_plot_context::LogPlotContext::add_discrete_track`].
+//!
+//! ```ignore
+//! let mut discrete_context = plot_context.add_discrete_track(1524.0..1850.0, 80);
+//! discrete_context
+//!     .table
+//!     .add(1, "Sand", YELLOW.to_backend_color())
+//!     .add(2, "Shale", GREEN.to_backend_color());
+//! discrete_context.draw("FACIES", curves.points("FACIES").ok_or("missing FACIES")?)?;
+//! ```
+
+use chartrs_backend::BackendColor;
+
+use crate::curve::is_null;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiscreteEntry {
+    pub code: i64,
+    pub label: String,
+    pub color: BackendColor,
+}
+
+/// Color and label of each code of a discrete curve.
+#[derive(Debug, Clone, Default)]
+pub struct DiscreteTable {
+    entries: Vec<DiscreteEntry>,
+}
+
+impl DiscreteTable {
+    /// A pay or net flag: `1` drawn in `color` as `label`, `0` left blank
+    pub fn flag<L>(label: L, color: BackendColor) -> Self
+    where
+        L: Into<String>,
+    {
+        let mut table = Self::default();
+        table.add(1, label, color);
+        table
+    }
+
+    /// Add a code, replacing the entry of the same code
+    pub fn add<L>(&mut self, code: i64, label: L, color: BackendColor) -> &mut Self
+    where
+        L: Into<String>,
+    {
+        self.entries.retain(|entry| entry.code != code);
+        self.entries.push(DiscreteEntry {
+            code,
+            label: label.into(),
+            color,
+        });
+        self
+    }
+
+    pub fn get(&self, code: i64) -> Option<&DiscreteEntry> {
+        self.entries.iter().find(|entry| entry.code == code)
+    }
+
+    pub fn entries(&self) -> &[DiscreteEntry] {
+        &self.entries
+    }
+}
+
+/// A depth interval of constant code.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiscreteBlock {
+    pub top: f64,
+    pub base: f64,
+    pub code: i64,
+}
+
+/// Group the `(value, depth)` samples of a discrete curve into blocks of
+/// the same code, values being rounded to the nearest code. Each sample
+/// covers the depths halfway to its neighbours; missing samples (`NaN` or
+/// `null_value`) end a block.
+pub fn discrete_blocks<I>(points: I, null_value: Option<f64>) -> Vec<DiscreteBlock>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+{
+    let samples: Vec<(Option<i64>, f64)> = points
+        .into_iter()
+        .filter(|(_, depth)| !depth.is_nan())
+        .map(|(value, depth)| {
+            (
+                (!is_null(value, null_value)).then(|| value.round() as i64),
+                depth,
+            )
+        })
+        .collect();
+
+    let mut blocks: Vec<DiscreteBlock> = vec![];
+    let mut open = false;
+
+    for (i, (code, depth)) in samples.iter().copied().enumerate() {
+        let code = match code {
+            Some(code) => code,
+            None => {
+                open = false;
+                continue;
+            }
+        };
+
+        // halfway to the neighbouring samples, mirrored at the ends
+        let previous = i.checked_sub(1).map(|i| samples[i].1);
+        let next = samples.get(i + 1).map(|(_, depth)| *depth);
+        let (previous, next) = match (previous, next) {
+            (Some(previous), Some(next)) => (previous, next),
+            (Some(previous), None) => (previous, 2.0 * depth - previous),
+            (None, Some(next)) => (2.0 * depth - next, next),
+            (None, None) => (depth, depth),
+        };
+        let (top, base) = ((previous + depth) / 2.0, (depth + next) / 2.0);
+
+        match blocks.last_mut() {
+            Some(block) if open && block.code == code => block.base = base,
+            _ => blocks.push(DiscreteBlock { top, base, code }),
+        }
+        open = true;
+    }
+
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(top: f64, base: f64, code: i64) -> DiscreteBlock {
+        DiscreteBlock { top, base, code }
+    }
+
+    #[test]
+    fn consecutive_equal_codes_make_one_block() {
+        let points = [(1.0, 100.0), (1.2, 101.0), (2.0, 102.0), (1.6, 103.0)];
+
+        assert_eq!(
+            discrete_blocks(points, None),
+            vec![block(99.5, 101.5, 1), block(101.5, 103.5, 2)]
+        );
+    }
+
+    #[test]
+    fn missing_codes_end_a_block() {
+        let points = [
+            (1.0, 100.0),
+            (-999.25, 101.0),
+            (1.0, 102.0),
+            (f64::NAN, 103.0),
+            (1.0, 104.0),
+        ];
+
+        assert_eq!(
+            discrete_blocks(points, Some(-999.25)),
+            vec![
+                block(99.5, 100.5, 1),
+                block(101.5, 102.5, 1),
+                block(103.5, 104.5, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn last_block_is_mirrored_past_the_last_sample() {
+        let points = [
+            (3.0, 100.0),
+            (3.0, 100.5),
+            (4.0, 101.0),
+            (f64::NAN, f64::NAN),
+        ];
+
+        assert_eq!(
+            discrete_blocks(points, None),
+            vec![block(99.75, 100.75, 3), block(100.75, 101.25, 4)]
+        );
+        assert_eq!(
+            discrete_blocks([(5.0, 100.0)], None),
+            vec![block(100.0, 100.0, 5)]
+        );
+    }
+}
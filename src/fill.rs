@@ -0,0 +1,406 @@
+use chartrs_backend::BackendColor;
+
+/// What a curve is shaded towards.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FillBaseline {
+    /// A constant value on the curve's range, e.g. a sand/shale cutoff
+    Value(f64),
+    LeftEdge,
+    RightEdge,
+}
+
+/// How the shaded area is colored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FillStyle {
+    Solid(BackendColor),
+    /// Blend from the first color at the curve's `min` to the second at its `max`
+    Gradient(BackendColor, BackendColor),
+    /// `(from value, color)` steps in ascending order; a value takes the color
+    /// of the last step it reaches, values below the first step its color.
+    Palette(Vec<(f64, BackendColor)>),
+}
+
+/// Shading between a curve and a baseline, see
+/// [`crate::plot_legend::PlotCurve::fill`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurveFill {
+    pub baseline: FillBaseline,
+    pub style: FillStyle,
+}
+
+impl CurveFill {
+    pub fn new(baseline: FillBaseline, style: FillStyle) -> Self {
+        Self { baseline, style }
+    }
+
+    /// Color of the fill for `value`, at `position` (`0.0` to `1.0`) across the curve's range.
+    pub fn color_at(&self, position: f64, value: f64) -> BackendColor {
+        match &self.style {
+            FillStyle::Solid(color) => *color,
+            FillStyle::Gradient(from, to) => blend(from, to, position.clamp(0.0, 1.0)),
+            FillStyle::Palette(steps) => steps
+                .iter()
+                .take_while(|(from, _)| value >= *from)
+                .last()
+                .or_else(|| steps.first())
+                .map(|(_, color)| *color)
+                .unwrap_or(BackendColor {
+                    alpha: 0.0,
+                    rgb: (0, 0, 0),
+                }),
+        }
+    }
+}
+
+/// Colors of the area between two curves, see
+/// [`crate::plot::channel_context::ChannelContext::draw_crossover_fill`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CrossoverFill {
+    /// Where curve A is left of curve B
+    pub left_color: BackendColor,
+    /// Where curve A is right of curve B
+    pub right_color: BackendColor,
+}
+
+impl CrossoverFill {
+    pub fn new(left_color: BackendColor, right_color: BackendColor) -> Self {
+        Self {
+            left_color,
+            right_color,
+        }
+    }
+}
+
+/// Areas between curves A and B, given as `(position, depth)` samples in
+/// depth order with `None` where a sample is missing, within the `depths`
+/// window. Each area is a polygon of `(position, depth)` corners with the
+/// color of its side; where the curves cross, the area is split at the
+/// crossing depth interpolated between the samples.
+pub(crate) fn crossover_areas(
+    a: &[Option<(f64, f64)>],
+    b: &[Option<(f64, f64)>],
+    depths: (f64, f64),
+    fill: &CrossoverFill,
+) -> Vec<(Vec<(f64, f64)>, BackendColor)> {
+    let (top, bottom) = (depths.0.min(depths.1), depths.0.max(depths.1));
+    let (runs_a, runs_b) = (sample_runs(a), sample_runs(b));
+
+    let mut breaks: Vec<f64> = runs_a
+        .iter()
+        .chain(&runs_b)
+        .flatten()
+        .map(|(_, depth)| *depth)
+        .filter(|depth| (top..=bottom).contains(depth))
+        .chain([top, bottom])
+        .collect();
+    breaks.sort_by(f64::total_cmp);
+    breaks.dedup();
+
+    let mut areas = Vec::new();
+    let mut open: Option<OpenArea> = None;
+
+    for interval in breaks.windows(2) {
+        let (d0, d1) = (interval[0], interval[1]);
+        let middle = (d0 + d1) / 2.0;
+        let (run_a, run_b) = match (run_at(&runs_a, middle), run_at(&runs_b, middle)) {
+            (Some(run_a), Some(run_b)) => (run_a, run_b),
+            _ => {
+                close_area(&mut open, &mut areas, fill);
+                continue;
+            }
+        };
+
+        let (a0, b0) = (position_at(run_a, d0), position_at(run_b, d0));
+        let (a1, b1) = (position_at(run_a, d1), position_at(run_b, d1));
+        let (diff0, diff1) = (a0 - b0, a1 - b1);
+
+        let corners = if diff0 * diff1 < 0.0 {
+            let t = diff0 / (diff0 - diff1);
+            let (depth, position) = (d0 + (d1 - d0) * t, a0 + (a1 - a0) * t);
+            vec![(d0, a0, b0), (depth, position, position), (d1, a1, b1)]
+        } else {
+            vec![(d0, a0, b0), (d1, a1, b1)]
+        };
+
+        for piece in corners.windows(2) {
+            let ((d0, a0, b0), (d1, a1, b1)) = (piece[0], piece[1]);
+            let side = (a0 - b0) + (a1 - b1);
+            if side == 0.0 {
+                close_area(&mut open, &mut areas, fill);
+                continue;
+            }
+
+            let left = side < 0.0;
+            if !matches!(&open, Some(area) if area.left == left) {
+                close_area(&mut open, &mut areas, fill);
+                open = Some(OpenArea {
+                    along_a: vec![(a0, d0)],
+                    along_b: vec![(b0, d0)],
+                    left,
+                });
+            }
+            if let Some(area) = &mut open {
+                area.along_a.push((a1, d1));
+                area.along_b.push((b1, d1));
+            }
+        }
+    }
+    close_area(&mut open, &mut areas, fill);
+
+    areas
+}
+
+/// Runs of consecutive samples between the missing ones, each in increasing depth
+fn sample_runs(samples: &[Option<(f64, f64)>]) -> Vec<Vec<(f64, f64)>> {
+    let mut runs: Vec<Vec<(f64, f64)>> = samples
+        .split(Option::is_none)
+        .filter(|run| run.len() > 1)
+        .map(|run| run.iter().flatten().copied().collect())
+        .collect();
+
+    for run in &mut runs {
+        if run[0].1 > run[run.len() - 1].1 {
+            run.reverse();
+        }
+    }
+
+    runs
+}
+
+fn run_at(runs: &[Vec<(f64, f64)>], depth: f64) -> Option<&[(f64, f64)]> {
+    runs.iter()
+        .find(|run| run[0].1 <= depth && depth <= run[run.len() - 1].1)
+        .map(Vec::as_slice)
+}
+
+/// Position of a run at `depth` within it, linearly interpolated between the samples
+fn position_at(run: &[(f64, f64)], depth: f64) -> f64 {
+    let i = run.partition_point(|(_, at)| *at < depth);
+    if i == 0 {
+        return run[0].0;
+    }
+
+    let ((p0, d0), (p1, d1)) = (run[i - 1], run[i.min(run.len() - 1)]);
+    if d1 == d0 {
+        p1
+    } else {
+        p0 + (p1 - p0) * (depth - d0) / (d1 - d0)
+    }
+}
+
+/// An area between the curves still being traced down
+struct OpenArea {
+    along_a: Vec<(f64, f64)>,
+    along_b: Vec<(f64, f64)>,
+    /// Whether A is left of B
+    left: bool,
+}
+
+fn close_area(
+    open: &mut Option<OpenArea>,
+    areas: &mut Vec<(Vec<(f64, f64)>, BackendColor)>,
+    fill: &CrossoverFill,
+) {
+    if let Some(area) = open.take() {
+        let mut corners = area.along_a;
+        corners.extend(area.along_b.into_iter().rev());
+        let color = if area.left {
+            fill.left_color
+        } else {
+            fill.right_color
+        };
+        areas.push((corners, color));
+    }
+}
+
+/// Linear blend of two colors, `t` from `0.0` (`from`) to `1.0` (`to`)
+pub(crate) fn blend(from: &BackendColor, to: &BackendColor, t: f64) -> BackendColor {
+    let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+
+    BackendColor {
+        alpha: from.alpha + (to.alpha - from.alpha) * t,
+        rgb: (
+            channel(from.rgb.0, to.rgb.0),
+            channel(from.rgb.1, to.rgb.1),
+            channel(from.rgb.2, to.rgb.2),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(r: u8, g: u8, b: u8) -> BackendColor {
+        BackendColor {
+            alpha: 1.0,
+            rgb: (r, g, b),
+        }
+    }
+
+    #[test]
+    fn gradient_runs_from_min_to_max_color() {
+        let fill = CurveFill::new(
+            FillBaseline::LeftEdge,
+            FillStyle::Gradient(color(0, 0, 0), color(200, 100, 50)),
+        );
+
+        assert_eq!(fill.color_at(0.0, 10.0), color(0, 0, 0));
+        assert_eq!(fill.color_at(1.0, 10.0), color(200, 100, 50));
+        assert_eq!(fill.color_at(0.5, 10.0), color(100, 50, 25));
+    }
+
+    #[test]
+    fn gradient_clamps_positions_outside_the_range() {
+        let fill = CurveFill::new(
+            FillBaseline::LeftEdge,
+            FillStyle::Gradient(color(0, 0, 0), color(200, 100, 50)),
+        );
+
+        assert_eq!(fill.color_at(-0.5, 10.0), color(0, 0, 0));
+        assert_eq!(fill.color_at(1.5, 10.0), color(200, 100, 50));
+    }
+
+    #[test]
+    fn palette_takes_the_last_step_reached() {
+        let fill = CurveFill::new(
+            FillBaseline::Value(75.0),
+            FillStyle::Palette(vec![
+                (0.0, color(255, 255, 0)),
+                (75.0, color(128, 128, 128)),
+                (120.0, color(0, 0, 0)),
+            ]),
+        );
+
+        assert_eq!(fill.color_at(0.0, 0.0), color(255, 255, 0));
+        assert_eq!(fill.color_at(0.0, 74.9), color(255, 255, 0));
+        assert_eq!(fill.color_at(0.0, 75.0), color(128, 128, 128));
+        assert_eq!(fill.color_at(0.0, 120.0), color(0, 0, 0));
+        assert_eq!(fill.color_at(0.0, 500.0), color(0, 0, 0));
+    }
+
+    #[test]
+    fn palette_colors_values_below_the_first_step_with_it() {
+        let fill = CurveFill::new(
+            FillBaseline::LeftEdge,
+            FillStyle::Palette(vec![(10.0, color(255, 0, 0)), (20.0, color(0, 255, 0))]),
+        );
+
+        assert_eq!(fill.color_at(0.0, -5.0), color(255, 0, 0));
+    }
+
+    #[test]
+    fn empty_palette_is_transparent() {
+        let fill = CurveFill::new(FillBaseline::LeftEdge, FillStyle::Palette(Vec::new()));
+
+        assert_eq!(fill.color_at(0.0, 1.0).alpha, 0.0);
+    }
+
+    #[test]
+    fn blend_interpolates_alpha_and_rounds_channels() {
+        let from = BackendColor {
+            alpha: 0.0,
+            rgb: (0, 10, 255),
+        };
+        let to = BackendColor {
+            alpha: 1.0,
+            rgb: (255, 11, 0),
+        };
+
+        let mid = blend(&from, &to, 0.5);
+
+        assert_eq!(mid.alpha, 0.5);
+        assert_eq!(mid.rgb, (128, 11, 128));
+        assert_eq!(blend(&from, &to, 0.0), from);
+        assert_eq!(blend(&from, &to, 1.0), to);
+    }
+
+    fn crossover() -> CrossoverFill {
+        CrossoverFill::new(color(255, 0, 0), color(0, 0, 255))
+    }
+
+    fn samples(points: &[(f64, f64)]) -> Vec<Option<(f64, f64)>> {
+        points.iter().copied().map(Some).collect()
+    }
+
+    #[test]
+    fn crossover_splits_at_the_interpolated_crossing() {
+        let a = samples(&[(0.25, 0.0), (0.75, 8.0)]);
+        let b = samples(&[(0.75, 0.0), (0.25, 8.0)]);
+
+        let areas = crossover_areas(&a, &b, (0.0, 8.0), &crossover());
+
+        // a - b goes from -0.5 to 0.5, so they cross half way down
+        assert_eq!(
+            areas,
+            vec![
+                (
+                    vec![(0.25, 0.0), (0.5, 4.0), (0.5, 4.0), (0.75, 0.0)],
+                    color(255, 0, 0)
+                ),
+                (
+                    vec![(0.5, 4.0), (0.75, 8.0), (0.25, 8.0), (0.5, 4.0)],
+                    color(0, 0, 255)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn crossover_merges_samples_of_both_curves() {
+        let a = samples(&[(0.0, 0.0), (0.0, 10.0)]);
+        let b = samples(&[(0.5, 0.0), (0.6, 4.0), (0.5, 10.0)]);
+
+        let areas = crossover_areas(&a, &b, (0.0, 10.0), &crossover());
+
+        assert_eq!(
+            areas,
+            vec![(
+                vec![
+                    (0.0, 0.0),
+                    (0.0, 4.0),
+                    (0.0, 10.0),
+                    (0.5, 10.0),
+                    (0.6, 4.0),
+                    (0.5, 0.0)
+                ],
+                color(255, 0, 0)
+            )]
+        );
+    }
+
+    #[test]
+    fn crossover_leaves_out_missing_samples_and_the_outside_of_the_window() {
+        let mut a = samples(&[(0.8, 0.0), (0.8, 10.0), (0.8, 20.0), (0.8, 30.0)]);
+        a[2] = None;
+        let b = samples(&[(0.2, 0.0), (0.2, 30.0)]);
+
+        let areas = crossover_areas(&a, &b, (5.0, 30.0), &crossover());
+
+        assert_eq!(
+            areas,
+            vec![(
+                vec![(0.8, 5.0), (0.8, 10.0), (0.2, 10.0), (0.2, 5.0)],
+                color(0, 0, 255)
+            )]
+        );
+    }
+
+    #[test]
+    fn crossover_skips_where_the_curves_coincide() {
+        let a = samples(&[(0.5, 0.0), (0.5, 10.0)]);
+
+        assert!(crossover_areas(&a, &a, (0.0, 10.0), &crossover()).is_empty());
+    }
+
+    #[test]
+    fn crossover_reads_samples_in_decreasing_depth() {
+        let a = samples(&[(0.75, 8.0), (0.25, 0.0)]);
+        let b = samples(&[(0.25, 8.0), (0.75, 0.0)]);
+
+        let areas = crossover_areas(&a, &b, (8.0, 0.0), &crossover());
+
+        assert_eq!(areas.len(), 2);
+        assert_eq!(areas[0].0[1], (0.5, 4.0));
+    }
+}
@@ -0,0 +1,386 @@
+//! Borehole images (FMI, UBI ...) drawn as a raster inside a channel by
+//! [`crate::plot::channel_context::ChannelContext::draw_image`].
+//!
+//! ```ignore
+//! let image = BoreholeImage::new(depths, samples);
+//! let normalization = Normalization::Dynamic { window: 0.6 };
+//!
+//! channel_context.draw_image_head("FMI", &ColorMap::Copper, &normalization, "")?;
+//! channel_context.draw_image(&image, &normalization, &ColorMap::Copper)?;
+//! ```
+
+use chartrs::{
+    coord::Shift,
+    element::{BackendCoordOnly, CoordMapper, Drawable, PointCollection},
+    prelude::{DrawingArea, Stroke},
+};
+use chartrs_backend::{
+    BackendColor, BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind, TextStyle,
+};
+
+use crate::{curve::is_null, fill::blend};
+
+/// Samples around the borehole at each depth: `samples[row][column]`, the
+/// columns covering the azimuths from 0° to 360° in equal steps.
+#[derive(Debug, Clone, Default)]
+pub struct BoreholeImage {
+    /// In order, increasing or decreasing
+    pub depths: Vec<f64>,
+    pub samples: Vec<Vec<f64>>,
+    /// Sentinel of missing samples. `NaN` is always missing.
+    pub null_value: Option<f64>,
+}
+
+impl BoreholeImage {
+    pub fn new(depths: Vec<f64>, samples: Vec<Vec<f64>>) -> Self {
+        Self {
+            depths,
+            samples,
+            null_value: None,
+        }
+    }
+
+    pub fn null_value(&mut self, null_value: Option<f64>) -> &mut Self {
+        self.null_value = null_value;
+        self
+    }
+
+    pub fn is_null(&self, value: f64) -> bool {
+        is_null(value, self.null_value)
+    }
+
+    /// Smallest and largest valid sample, for a static normalization over the whole image
+    pub fn value_range(&self) -> Option<(f64, f64)> {
+        self.samples
+            .iter()
+            .flatten()
+            .filter(|value| !self.is_null(**value))
+            .fold(None, |range, value| match range {
+                None => Some((*value, *value)),
+                Some((min, max)) => Some((min.min(*value), max.max(*value))),
+            })
+    }
+
+    /// Each sample as a `0.0` to `1.0` fraction under `normalization`, `None`
+    /// where it is missing. An empty static range, e.g. the
+    /// [`BoreholeImage::value_range`] of a constant image, puts every sample
+    /// in the middle.
+    pub fn normalized(&self, normalization: &Normalization) -> Vec<Vec<Option<f64>>> {
+        match *normalization {
+            Normalization::Static { min, max } => self
+                .samples
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|value| {
+                            if self.is_null(*value) {
+                                None
+                            } else if max == min {
+                                Some(0.5)
+                            } else {
+                                Some(((value - min) / (max - min)).clamp(0.0, 1.0))
+                                    .filter(|fraction| fraction.is_finite())
+                            }
+                        })
+                        .collect()
+                })
+                .collect(),
+            Normalization::Dynamic { window } => self.normalized_dynamic(window),
+        }
+    }
+
+    /// Histogram equalization of each row against the samples of the rows
+    /// within `window / 2` in depth of it.
+    fn normalized_dynamic(&self, window: f64) -> Vec<Vec<Option<f64>>> {
+        let rows = self.samples.len().min(self.depths.len());
+        let half = window.abs() / 2.0;
+
+        let (mut first, mut last) = (0, 0);
+        let mut normalized = Vec::with_capacity(rows);
+
+        for row in 0..rows {
+            let depth = self.depths[row];
+            while (self.depths[first] - depth).abs() > half {
+                first += 1;
+            }
+            last = last.max(row);
+            while last + 1 < rows && (self.depths[last + 1] - depth).abs() <= half {
+                last += 1;
+            }
+
+            let mut window: Vec<f64> = self.samples[first..=last]
+                .iter()
+                .flatten()
+                .copied()
+                .filter(|value| !self.is_null(*value))
+                .collect();
+            window.sort_by(f64::total_cmp);
+
+            normalized.push(
+                self.samples[row]
+                    .iter()
+                    .map(|value| {
+                        if self.is_null(*value) {
+                            return None;
+                        }
+                        let below = window.partition_point(|other| other < value);
+                        let up_to = window.partition_point(|other| other <= value);
+                        Some((below + up_to) as f64 / 2.0 / window.len() as f64)
+                    })
+                    .collect(),
+            );
+        }
+
+        normalized
+    }
+}
+
+/// How samples are mapped onto the color map.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Normalization {
+    /// The same `min` to `max` range for the whole image
+    Static { min: f64, max: f64 },
+    /// Rank of each sample among the samples within a sliding depth
+    /// `window`, bringing out features whatever the local contrast
+    Dynamic { window: f64 },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColorMap {
+    /// Dark brown for low values through orange to pale yellow for high
+    /// ones, the usual resistivity image colors
+    Copper,
+    Grayscale,
+    Viridis,
+    /// `(fraction, color)` stops in ascending order, blended in between
+    Custom(Vec<(f64, BackendColor)>),
+}
+
+const fn rgb(r: u8, g: u8, b: u8) -> BackendColor {
+    BackendColor {
+        alpha: 1.0,
+        rgb: (r, g, b),
+    }
+}
+
+const COPPER: [(f64, BackendColor); 3] = [
+    (0.0, rgb(40, 20, 0)),
+    (0.5, rgb(190, 100, 20)),
+    (1.0, rgb(255, 240, 160)),
+];
+
+const GRAYSCALE: [(f64, BackendColor); 2] = [(0.0, rgb(0, 0, 0)), (1.0, rgb(255, 255, 255))];
+
+const VIRIDIS: [(f64, BackendColor); 5] = [
+    (0.0, rgb(68, 1, 84)),
+    (0.25, rgb(59, 82, 139)),
+    (0.5, rgb(33, 145, 140)),
+    (0.75, rgb(94, 201, 98)),
+    (1.0, rgb(253, 231, 37)),
+];
+
+impl ColorMap {
+    fn stops(&self) -> &[(f64, BackendColor)] {
+        match self {
+            ColorMap::Copper => &COPPER,
+            ColorMap::Grayscale => &GRAYSCALE,
+            ColorMap::Viridis => &VIRIDIS,
+            ColorMap::Custom(stops) => stops,
+        }
+    }
+
+    /// Color of `fraction`, from `0.0` to `1.0`
+    pub fn color_at(&self, fraction: f64) -> BackendColor {
+        let stops = self.stops();
+        let fraction = fraction.clamp(0.0, 1.0);
+
+        let next = stops.partition_point(|(at, _)| *at < fraction);
+        let below = next.checked_sub(1).map(|i| stops[i]);
+        match (below, stops.get(next).copied()) {
+            (Some((from_at, from)), Some((to_at, to))) if to_at > from_at => {
+                blend(&from, &to, (fraction - from_at) / (to_at - from_at))
+            }
+            (_, Some((_, color))) | (Some((_, color)), None) => color,
+            (None, None) => rgb(0, 0, 0),
+        }
+    }
+}
+
+/// Height of the bar of colors
+const BAR_HEIGHT: i32 = 16;
+
+/// The color map across the head of an image channel, with the values at its
+/// ends and a caption under it.
+pub struct ColorBar<'a, Coord> {
+    points: [Coord; 2],
+    color_map: ColorMap,
+    labels: [String; 3],
+    text_style: TextStyle<'a>,
+}
+
+impl<'a> ColorBar<'a, BackendCoord> {
+    /// `labels` are drawn under the left end, the middle and the right end of the bar
+    pub fn with_area<DB>(
+        area: &DrawingArea<DB, Shift>,
+        color_map: ColorMap,
+        labels: [String; 3],
+        text_style: TextStyle<'a>,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+    {
+        let (w, h) = area.dim_in_pixel();
+
+        Self {
+            points: [
+                BackendCoord::new(0_i32, 0_i32),
+                BackendCoord::new(w as i32, h as i32),
+            ],
+            color_map,
+            labels,
+            text_style,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a ColorBar<'a, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB> Drawable<DB> for ColorBar<'a, Coord>
+where
+    DB: DrawingBackend,
+{
+    fn draw<I>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<<DB as DrawingBackend>::ErrorType>>
+    where
+        I: Iterator<Item = <BackendCoordOnly as CoordMapper>::Output>,
+    {
+        let (top_left, bottom_right) = match (points.next(), points.next()) {
+            (Some(top_left), Some(bottom_right)) => (top_left, bottom_right),
+            _ => return Ok(()),
+        };
+
+        let width = (bottom_right.x - top_left.x).max(1);
+        let total_height = BAR_HEIGHT + 5 + self.text_style.size() as i32;
+        let bar_top = top_left.y + ((bottom_right.y - top_left.y) - total_height).max(0) / 2;
+
+        for x in top_left.x..bottom_right.x {
+            let color = self
+                .color_map
+                .color_at((x - top_left.x) as f64 / width as f64);
+
+            backend.draw_line(
+                BackendCoord::new(x, bar_top),
+                BackendCoord::new(x, bar_top + BAR_HEIGHT),
+                &Stroke::new(1., color),
+            )?;
+        }
+
+        let text_y = bar_top + BAR_HEIGHT + 5;
+        let [left, center, right] = &self.labels;
+
+        backend.draw_text(
+            left,
+            &self.text_style,
+            BackendCoord::new(top_left.x + 5, text_y),
+        )?;
+
+        let (_, (max_x, _max_y)) = self
+            .text_style
+            .layout_box(center)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        backend.draw_text(
+            center,
+            &self.text_style,
+            BackendCoord::new(top_left.x + width / 2 - max_x / 2, text_y),
+        )?;
+
+        let (_, (max_x, _max_y)) = self
+            .text_style
+            .layout_box(right)
+            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+        backend.draw_text(
+            right,
+            &self.text_style,
+            BackendCoord::new(bottom_right.x - max_x - 5, text_y),
+        )?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_normalization_clamps_to_the_range_and_skips_nulls() {
+        let mut image = BoreholeImage::new(
+            vec![100.0, 100.1],
+            vec![vec![10.0, 15.0, -999.25], vec![f64::NAN, 5.0, 30.0]],
+        );
+        image.null_value(Some(-999.25));
+
+        assert_eq!(image.value_range(), Some((5.0, 30.0)));
+        assert_eq!(
+            image.normalized(&Normalization::Static {
+                min: 10.0,
+                max: 20.0
+            }),
+            vec![
+                vec![Some(0.0), Some(0.5), None],
+                vec![None, Some(0.0), Some(1.0)]
+            ]
+        );
+    }
+
+    #[test]
+    fn static_normalization_of_a_constant_image_is_the_middle() {
+        let mut image =
+            BoreholeImage::new(vec![100.0, 100.1], vec![vec![7.0, 7.0], vec![7.0, -1.0]]);
+        image.null_value(Some(-1.0));
+
+        let (min, max) = image.value_range().unwrap();
+        assert_eq!((min, max), (7.0, 7.0));
+        assert_eq!(
+            image.normalized(&Normalization::Static { min, max }),
+            vec![vec![Some(0.5), Some(0.5)], vec![Some(0.5), None]]
+        );
+    }
+
+    #[test]
+    fn dynamic_normalization_ranks_within_the_window() {
+        let image = BoreholeImage::new(
+            vec![100.0, 100.1, 105.0],
+            vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![8.0, 8.0]],
+        );
+
+        let normalized = image.normalized(&Normalization::Dynamic { window: 1.0 });
+
+        // the first two rows share a window, the last is alone and constant
+        assert_eq!(normalized[0], vec![Some(0.125), Some(0.375)]);
+        assert_eq!(normalized[1], vec![Some(0.625), Some(0.875)]);
+        assert_eq!(normalized[2], vec![Some(0.5), Some(0.5)]);
+    }
+
+    #[test]
+    fn image_without_valid_samples_has_no_range() {
+        let image = BoreholeImage::new(vec![100.0], vec![vec![f64::NAN, f64::NAN]]);
+
+        assert_eq!(image.value_range(), None);
+        assert_eq!(
+            image.normalized(&Normalization::Dynamic { window: 1.0 }),
+            vec![vec![None, None]]
+        );
+    }
+}
@@ -0,0 +1,480 @@
+//! CSV/TSV curve import.
+//!
+//! ```ignore
+//! let curves = CsvImporter::new()
+//!     .delimiter(';')
+//!     .decimal_separator(',')
+//!     .unit_row(true)
+//!     .index_column("Depth")
+//!     .map_column("Gamma", "GR")
+//!     .null_value("-999.25")
+//!     .open("core.csv")?;
+//! ```
+
+use std::{fmt, fs::File, io::Read, path::Path};
+
+use crate::curve::{is_null, Curve, CurveSet, TextCurve};
+
+#[derive(Debug)]
+pub enum CsvErrorKind {
+    Io(std::io::Error),
+    MissingHeader,
+    UnknownColumn(String),
+    ColumnCount { expected: usize, found: usize },
+    InvalidIndex(String),
+}
+
+/// Error raised while importing a CSV file. `line` is 1-based, `0` when the
+/// error is not tied to a line.
+#[derive(Debug)]
+pub struct CsvError {
+    pub line: usize,
+    pub kind: CsvErrorKind,
+}
+
+impl CsvError {
+    fn new(line: usize, kind: CsvErrorKind) -> Self {
+        Self { line, kind }
+    }
+}
+
+impl fmt::Display for CsvErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvErrorKind::Io(e) => write!(f, "I/O error: {}", e),
+            CsvErrorKind::MissingHeader => write!(f, "missing header row"),
+            CsvErrorKind::UnknownColumn(name) => write!(f, "unknown column `{}`", name),
+            CsvErrorKind::ColumnCount { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+            CsvErrorKind::InvalidIndex(value) => write!(f, "invalid index value `{}`", value),
+        }
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "line {}: {}", self.line, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            CsvErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for CsvError {
+    fn from(e: std::io::Error) -> Self {
+        CsvError::new(0, CsvErrorKind::Io(e))
+    }
+}
+
+/// Imports delimited text into a [`CurveSet`].
+///
+/// The first row holds the column names, optionally followed by a unit row.
+/// Null sentinels become `NaN`. A column whose values are mostly not numbers
+/// is imported as a [`TextCurve`]; in a mostly numeric one, cells that are
+/// not numbers, e.g. `--` or `N/A`, are missing too.
+#[derive(Debug, Clone)]
+pub struct CsvImporter {
+    delimiter: char,
+    decimal_separator: char,
+    unit_row: bool,
+    index_column: Option<String>,
+    column_map: Vec<(String, String)>,
+    null_values: Vec<String>,
+}
+
+impl Default for CsvImporter {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            decimal_separator: '.',
+            unit_row: false,
+            index_column: None,
+            column_map: vec![],
+            null_values: vec![String::new()],
+        }
+    }
+}
+
+impl CsvImporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Importer for tab separated files
+    pub fn tsv() -> Self {
+        Self {
+            delimiter: '\t',
+            ..Default::default()
+        }
+    }
+
+    pub fn delimiter(&mut self, delimiter: char) -> &mut Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn decimal_separator(&mut self, decimal_separator: char) -> &mut Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Whether the second row holds the units of the columns
+    pub fn unit_row(&mut self, unit_row: bool) -> &mut Self {
+        self.unit_row = unit_row;
+        self
+    }
+
+    /// Name of the index column, the first column when not set
+    pub fn index_column<I>(&mut self, name: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.index_column = Some(name.into());
+        self
+    }
+
+    /// Import `column` under the given mnemonic. Once a column is mapped,
+    /// unmapped columns other than the index are skipped.
+    pub fn map_column<C, M>(&mut self, column: C, mnemonic: M) -> &mut Self
+    where
+        C: Into<String>,
+        M: Into<String>,
+    {
+        self.column_map.push((column.into(), mnemonic.into()));
+        self
+    }
+
+    /// Declare a value meaning "no sample", e.g. `-999.25` or `NA`. Empty
+    /// cells are always treated as missing.
+    pub fn null_value<I>(&mut self, sentinel: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.null_values.push(sentinel.into());
+        self
+    }
+
+    pub fn open<P: AsRef<Path>>(&self, path: P) -> Result<CurveSet, CsvError> {
+        self.import_reader(File::open(path)?)
+    }
+
+    pub fn import_reader<R: Read>(&self, mut reader: R) -> Result<CurveSet, CsvError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+
+        self.import_str(&String::from_utf8_lossy(&bytes))
+    }
+
+    pub fn import_str(&self, text: &str) -> Result<CurveSet, CsvError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim_end_matches('\r')))
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let names = match lines.next() {
+            Some((_, line)) => self.split(line),
+            None => return Err(CsvError::new(0, CsvErrorKind::MissingHeader)),
+        };
+
+        let units = if self.unit_row {
+            lines
+                .next()
+                .map(|(_, line)| self.split(line))
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        let index = match &self.index_column {
+            Some(index) => self
+                .position(&names, index)
+                .ok_or_else(|| CsvError::new(1, CsvErrorKind::UnknownColumn(index.clone())))?,
+            None => 0,
+        };
+
+        // (column, mnemonic) of everything to import, index first
+        let mut selected = vec![(index, names[index].clone())];
+        if self.column_map.is_empty() {
+            selected.extend(
+                names
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i != index)
+                    .map(|(i, name)| (i, name.clone())),
+            );
+        } else {
+            for (column, mnemonic) in self.column_map.iter() {
+                let i = self
+                    .position(&names, column)
+                    .ok_or_else(|| CsvError::new(1, CsvErrorKind::UnknownColumn(column.clone())))?;
+                if i == index {
+                    selected[0].1 = mnemonic.clone();
+                } else {
+                    selected.push((i, mnemonic.clone()));
+                }
+            }
+        }
+
+        let mut cells: Vec<Vec<String>> = vec![vec![]; selected.len()];
+        for (line_no, line) in lines {
+            let row = self.split(line);
+            if row.len() != names.len() {
+                return Err(CsvError::new(
+                    line_no,
+                    CsvErrorKind::ColumnCount {
+                        expected: names.len(),
+                        found: row.len(),
+                    },
+                ));
+            }
+
+            if self.parse_number(&row[index]).is_none() && !self.is_null(&row[index]) {
+                return Err(CsvError::new(
+                    line_no,
+                    CsvErrorKind::InvalidIndex(row[index].clone()),
+                ));
+            }
+
+            for (column, (i, _)) in cells.iter_mut().zip(selected.iter()) {
+                column.push(row[*i].clone());
+            }
+        }
+
+        let mut curve_set = CurveSet::default();
+
+        for (n, ((i, mnemonic), column)) in selected.into_iter().zip(cells).enumerate() {
+            let unit = units.get(i).cloned().unwrap_or_default();
+
+            let numbers: Vec<Option<f64>> = column
+                .iter()
+                .map(|cell| {
+                    if self.is_null(cell) {
+                        Some(f64::NAN)
+                    } else {
+                        self.parse_number(cell)
+                    }
+                })
+                .collect();
+
+            let invalid = numbers.iter().filter(|value| value.is_none()).count();
+            let valid = numbers
+                .iter()
+                .filter(|value| value.is_some_and(|value| !value.is_nan()))
+                .count();
+
+            if invalid == 0 || valid > invalid {
+                let curve = Curve {
+                    mnemonic,
+                    unit,
+                    description: names[i].clone(),
+                    values: numbers
+                        .into_iter()
+                        .map(|value| value.unwrap_or(f64::NAN))
+                        .collect(),
+                    null_value: None,
+                };
+                if n == 0 {
+                    curve_set.index = curve;
+                } else {
+                    curve_set.curves.push(curve);
+                }
+            } else {
+                curve_set.texts.push(TextCurve {
+                    mnemonic,
+                    unit,
+                    description: names[i].clone(),
+                    values: column,
+                });
+            }
+        }
+
+        Ok(curve_set)
+    }
+
+    fn position(&self, names: &[String], name: &str) -> Option<usize> {
+        names.iter().position(|n| n.eq_ignore_ascii_case(name))
+    }
+
+    fn is_null(&self, cell: &str) -> bool {
+        if self.null_values.iter().any(|null| null == cell) {
+            return true;
+        }
+
+        // numeric sentinels also match when written differently, e.g. `-999.250`
+        match self.parse_number(cell) {
+            Some(value) => self
+                .null_values
+                .iter()
+                .filter_map(|null| self.parse_number(null))
+                .any(|null| is_null(value, Some(null))),
+            None => false,
+        }
+    }
+
+    fn parse_number(&self, cell: &str) -> Option<f64> {
+        if self.decimal_separator == '.' {
+            cell.parse().ok()
+        } else {
+            cell.replace(self.decimal_separator, ".").parse().ok()
+        }
+    }
+
+    /// Split a row; double quotes protect delimiters and `""` is a literal quote.
+    fn split(&self, line: &str) -> Vec<String> {
+        let mut cells = vec![];
+        let mut cell = String::new();
+        let mut quoted = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    chars.next();
+                    cell.push('"');
+                }
+                '"' => quoted = !quoted,
+                c if c == self.delimiter && !quoted => {
+                    cells.push(cell.trim().to_string());
+                    cell.clear();
+                }
+                c => cell.push(c),
+            }
+        }
+        cells.push(cell.trim().to_string());
+
+        cells
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_names_and_unit_row() {
+        let text = "Depth,Gamma,Lith\nm,gAPI,\n1000.0,45.2,sand\n1000.5,50.1,\"shale, silty\"\n";
+        let curves = CsvImporter::new().unit_row(true).import_str(text).unwrap();
+
+        assert_eq!(curves.index.mnemonic, "Depth");
+        assert_eq!(curves.index.unit, "m");
+        assert_eq!(curves.index.values, vec![1000.0, 1000.5]);
+
+        let gamma = curves.curve("Gamma").unwrap();
+        assert_eq!(gamma.unit, "gAPI");
+        assert_eq!(gamma.values, vec![45.2, 50.1]);
+
+        let lith = curves.text("Lith").unwrap();
+        assert_eq!(lith.unit, "");
+        assert_eq!(lith.values, vec!["sand", "shale, silty"]);
+    }
+
+    #[test]
+    fn without_unit_row_the_second_row_is_data() {
+        let text = "Depth,Gamma\n1000.0,45.2\n1000.5,50.1\n";
+        let curves = CsvImporter::new().import_str(text).unwrap();
+
+        assert_eq!(curves.index.unit, "");
+        assert_eq!(curves.index.values, vec![1000.0, 1000.5]);
+    }
+
+    #[test]
+    fn reads_tab_separated_files() {
+        let text = "GR\tDEPT\tRHOB\ngAPI\tm\tg/cm3\n45.2\t1000.0\t2.45\n50.1\t1000.5\t2.50\n";
+        let curves = CsvImporter::tsv()
+            .unit_row(true)
+            .index_column("dept")
+            .map_column("RHOB", "DEN")
+            .import_str(text)
+            .unwrap();
+
+        assert_eq!(curves.index.mnemonic, "DEPT");
+        assert_eq!(curves.index.values, vec![1000.0, 1000.5]);
+        assert_eq!(curves.curve("DEN").unwrap().unit, "g/cm3");
+        assert!(curves.curve("GR").is_none());
+    }
+
+    #[test]
+    fn custom_null_values_become_nan() {
+        let text = "DEPT,GR,DT\n1000.0,-999.250,NA\n1000.5,,80.0\n1001.0,-999.25,90.0\n";
+        let curves = CsvImporter::new()
+            .null_value("-999.25")
+            .null_value("NA")
+            .import_str(text)
+            .unwrap();
+
+        let (gr, dt) = (curves.curve("GR").unwrap(), curves.curve("DT").unwrap());
+        assert!(gr.values.iter().all(|value| value.is_nan()));
+        assert!(dt.values[0].is_nan());
+        assert_eq!(&dt.values[1..], &[80.0, 90.0]);
+    }
+
+    #[test]
+    fn cells_that_are_not_numbers_are_missing_in_a_numeric_column() {
+        let text = "DEPT,GR,LITH\n1000.0,45.2,sand\n1000.5,--,shale\n1001.0,50.1,N/A\n";
+        let curves = CsvImporter::new().import_str(text).unwrap();
+
+        let gr = curves.curve("GR").unwrap();
+        assert_eq!(gr.values[0], 45.2);
+        assert!(gr.values[1].is_nan());
+        assert_eq!(gr.values[2], 50.1);
+
+        // a column of words stays text
+        assert!(curves.curve("LITH").is_none());
+        assert_eq!(curves.text("LITH").unwrap().values[2], "N/A");
+    }
+
+    #[test]
+    fn reads_decimal_commas() {
+        let text = "Depth;Gamma\nm;gAPI\n1000,5;45,2\n1001,0;-999,25\n";
+        let curves = CsvImporter::new()
+            .delimiter(';')
+            .decimal_separator(',')
+            .unit_row(true)
+            .null_value("-999.25")
+            .import_str(text)
+            .unwrap();
+
+        assert_eq!(curves.index.values, vec![1000.5, 1001.0]);
+
+        let gamma = curves.curve("Gamma").unwrap();
+        assert_eq!(gamma.values[0], 45.2);
+        assert!(gamma.values[1].is_nan());
+    }
+
+    #[test]
+    fn reports_the_line_of_a_bad_row() {
+        let text = "DEPT,GR\n\n1000.0,45.2\n1000.5\n";
+        let error = CsvImporter::new().import_str(text).unwrap_err();
+        assert_eq!(error.line, 4);
+        assert!(matches!(
+            error.kind,
+            CsvErrorKind::ColumnCount {
+                expected: 2,
+                found: 1
+            }
+        ));
+
+        let text = "DEPT,GR\nten,45.2\n";
+        let error = CsvImporter::new().import_str(text).unwrap_err();
+        assert_eq!(error.line, 2);
+        assert!(matches!(error.kind, CsvErrorKind::InvalidIndex(value) if value == "ten"));
+
+        let error = CsvImporter::new()
+            .index_column("MD")
+            .import_str(text)
+            .unwrap_err();
+        assert!(matches!(error.kind, CsvErrorKind::UnknownColumn(name) if name == "MD"));
+    }
+}
@@ -0,0 +1,245 @@
+//! Explicitly formatted logical records: set, template and objects.
+
+use super::{
+    repcode::{Cursor, IDENT},
+    Attribute, DlisError, DlisErrorKind, Object, ObjectSet,
+};
+
+// component roles, the top three bits of a component descriptor
+const ABSATR: u8 = 0;
+const ATTRIB: u8 = 1;
+const INVATR: u8 = 2;
+const OBJECT: u8 = 3;
+const RDSET: u8 = 5;
+const RSET: u8 = 6;
+const SET: u8 = 7;
+
+// attribute component characteristics
+const LABEL: u8 = 0x10;
+const COUNT: u8 = 0x08;
+const REPCODE: u8 = 0x04;
+const UNITS: u8 = 0x02;
+const VALUE: u8 = 0x01;
+
+fn role(descriptor: u8) -> u8 {
+    descriptor >> 5
+}
+
+pub(super) fn parse_set(body: &[u8], offset: usize) -> Result<ObjectSet, DlisError> {
+    let mut cursor = Cursor::new(body, offset);
+
+    // set component
+    let descriptor = cursor.ushort()?;
+    if !matches!(role(descriptor), SET | RSET | RDSET) {
+        return Err(cursor.error(DlisErrorKind::InvalidDescriptor(descriptor)));
+    }
+
+    let mut set = ObjectSet::default();
+    if descriptor & 0x10 != 0 {
+        set.set_type = cursor.ident()?;
+    }
+    if descriptor & 0x08 != 0 {
+        set.name = cursor.ident()?;
+    }
+
+    // template: attributes up to the first object
+    let mut template: Vec<(Attribute, bool)> = vec![];
+    while let Some(descriptor) = cursor.peek() {
+        match role(descriptor) {
+            OBJECT => break,
+            ATTRIB | INVATR => {
+                cursor.ushort()?;
+                let default = Attribute {
+                    count: 1,
+                    repr_code: IDENT,
+                    ..Default::default()
+                };
+                let attribute = read_attribute(&mut cursor, descriptor, &default)?;
+                template.push((attribute, role(descriptor) == INVATR));
+            }
+            _ => return Err(cursor.error(DlisErrorKind::InvalidDescriptor(descriptor))),
+        }
+    }
+
+    // objects
+    while let Some(descriptor) = cursor.peek() {
+        if role(descriptor) != OBJECT {
+            return Err(cursor.error(DlisErrorKind::InvalidDescriptor(descriptor)));
+        }
+        cursor.ushort()?;
+
+        let mut object = Object::default();
+        if descriptor & 0x10 != 0 {
+            object.name = cursor.obname()?;
+        }
+
+        for (default, invariant) in template.iter() {
+            if *invariant {
+                object.attributes.push(default.clone());
+                continue;
+            }
+
+            // an object may stop early, the remaining attributes keep their defaults
+            let attribute = match cursor.peek() {
+                Some(descriptor) if role(descriptor) == ABSATR => {
+                    cursor.ushort()?;
+                    continue;
+                }
+                Some(descriptor) if matches!(role(descriptor), ATTRIB | INVATR) => {
+                    cursor.ushort()?;
+                    read_attribute(&mut cursor, descriptor, default)?
+                }
+                _ => default.clone(),
+            };
+
+            object.attributes.push(attribute);
+        }
+
+        set.objects.push(object);
+    }
+
+    Ok(set)
+}
+
+fn read_attribute(
+    cursor: &mut Cursor,
+    descriptor: u8,
+    default: &Attribute,
+) -> Result<Attribute, DlisError> {
+    let mut attribute = Attribute {
+        label: default.label.clone(),
+        count: default.count,
+        repr_code: default.repr_code,
+        units: default.units.clone(),
+        values: vec![],
+    };
+
+    if descriptor & LABEL != 0 {
+        attribute.label = cursor.ident()?;
+    }
+    if descriptor & COUNT != 0 {
+        attribute.count = cursor.uvari()? as usize;
+    }
+    if descriptor & REPCODE != 0 {
+        attribute.repr_code = cursor.ushort()?;
+    }
+    if descriptor & UNITS != 0 {
+        attribute.units = cursor.ident()?;
+    }
+
+    attribute.values = if descriptor & VALUE != 0 {
+        (0..attribute.count)
+            .map(|_| cursor.value(attribute.repr_code))
+            .collect::<Result<_, _>>()?
+    } else if descriptor & COUNT == 0 && descriptor & REPCODE == 0 {
+        default.values.clone()
+    } else {
+        vec![]
+    };
+
+    Ok(attribute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::dlis::{
+        record::tests::{ident, obname},
+        repcode::{ObjectName, Value, ASCII, UVARI},
+    };
+
+    /// A `CHANNEL` set: `LONG-NAME` (ASCII), `DIMENSION` (UVARI, defaults
+    /// to `1`), `UNITS` and the invariant `SOURCE`; `GR` leaves out its
+    /// dimension, `DT` stops after its long name.
+    fn channel_set() -> Vec<u8> {
+        let mut body = vec![SET << 5 | 0x18];
+        body.extend(ident("CHANNEL"));
+        body.extend(ident("0"));
+
+        // template
+        body.push(ATTRIB << 5 | LABEL | REPCODE);
+        body.extend(ident("LONG-NAME"));
+        body.push(ASCII);
+        body.push(ATTRIB << 5 | LABEL | COUNT | REPCODE | VALUE);
+        body.extend(ident("DIMENSION"));
+        body.extend([1, UVARI, 1]);
+        body.push(ATTRIB << 5 | LABEL);
+        body.extend(ident("UNITS"));
+        body.push(INVATR << 5 | LABEL | VALUE);
+        body.extend(ident("SOURCE"));
+        body.extend(ident("TOOL"));
+
+        // objects
+        body.push(OBJECT << 5 | 0x10);
+        body.extend(obname("GR"));
+        body.push(ATTRIB << 5 | VALUE);
+        body.extend([9]);
+        body.extend_from_slice(b"Gamma Ray");
+        body.push(ABSATR << 5);
+        body.push(ATTRIB << 5 | VALUE);
+        body.extend(ident("gAPI"));
+
+        body.push(OBJECT << 5 | 0x10);
+        body.extend(obname("DT"));
+        body.push(ATTRIB << 5 | VALUE);
+        body.extend([5]);
+        body.extend_from_slice(b"Sonic");
+
+        body
+    }
+
+    #[test]
+    fn reads_set_template_and_objects() {
+        let set = parse_set(&channel_set(), 100).unwrap();
+
+        assert_eq!(set.set_type, "CHANNEL");
+        assert_eq!(set.name, "0");
+        assert_eq!(set.objects.len(), 2);
+
+        let gr = &set.objects[0];
+        assert_eq!(
+            gr.name,
+            ObjectName {
+                origin: 1,
+                copy: 0,
+                id: "GR".to_string()
+            }
+        );
+        assert_eq!(gr.text("LONG-NAME"), Some("Gamma Ray"));
+        assert_eq!(gr.attribute("LONG-NAME").unwrap().repr_code, ASCII);
+        assert_eq!(gr.text("UNITS"), Some("gAPI"));
+        assert_eq!(gr.text("SOURCE"), Some("TOOL"));
+    }
+
+    #[test]
+    fn absent_attributes_are_left_out() {
+        let set = parse_set(&channel_set(), 100).unwrap();
+
+        assert!(set.objects[0].attribute("DIMENSION").is_none());
+        assert!(set.objects[0].values("DIMENSION").is_empty());
+    }
+
+    #[test]
+    fn missing_attributes_take_the_template_defaults() {
+        let set = parse_set(&channel_set(), 100).unwrap();
+        let dt = &set.objects[1];
+
+        assert_eq!(dt.text("LONG-NAME"), Some("Sonic"));
+        assert_eq!(dt.values("DIMENSION"), &[Value::Integer(1)]);
+        assert_eq!(dt.attribute("DIMENSION").unwrap().repr_code, UVARI);
+        assert!(dt.attribute("UNITS").unwrap().values.is_empty());
+        assert_eq!(dt.text("SOURCE"), Some("TOOL"));
+    }
+
+    #[test]
+    fn reports_truncated_and_malformed_sets() {
+        let body = channel_set();
+
+        let error = parse_set(&body[..body.len() - 2], 100).unwrap_err();
+        assert_eq!(error.offset, 100);
+        assert!(matches!(error.kind, DlisErrorKind::UnexpectedEof));
+
+        let error = parse_set(&body[1..], 100).unwrap_err();
+        assert!(matches!(error.kind, DlisErrorKind::InvalidDescriptor(_)));
+    }
+}
@@ -0,0 +1,598 @@
+//! DLIS (RP66 v1) support.
+//!
+//! The decoder works on an in-memory byte buffer: the storage unit label is
+//! followed by visible records, which are split into logical record segments
+//! and reassembled into explicitly (EFLR) and indirectly (IFLR) formatted
+//! logical records. `FRAME` and `CHANNEL` objects describe the layout of the
+//! frame data, which is decoded into one [`DlisChannel`] per channel.
+//!
+//! ```ignore
+//! let dlis = DlisFile::open("run1.dlis")?;
+//! let frame = &dlis.logical_files[0].frames[0];
+//! let curves = frame.to_curve_set();
+//! ```
+
+mod eflr;
+mod record;
+pub mod repcode;
+
+use std::{fmt, fs::File, io::Read, path::Path};
+
+use crate::curve::{ArrayCurve, Curve, CurveSet};
+
+use self::repcode::{Cursor, ObjectName, Value};
+
+#[derive(Debug)]
+pub enum DlisErrorKind {
+    Io(std::io::Error),
+    InvalidStorageUnitLabel,
+    InvalidVisibleRecord,
+    InvalidSegment,
+    UnexpectedEof,
+    InvalidDescriptor(u8),
+    InvalidRepresentationCode(u8),
+    UnknownFrame(String),
+}
+
+/// Error raised while decoding a DLIS file, `offset` is the byte offset of
+/// the visible record, segment or logical record at fault.
+#[derive(Debug)]
+pub struct DlisError {
+    pub offset: usize,
+    pub kind: DlisErrorKind,
+}
+
+impl DlisError {
+    pub(crate) fn new(offset: usize, kind: DlisErrorKind) -> Self {
+        Self { offset, kind }
+    }
+}
+
+impl fmt::Display for DlisErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DlisErrorKind::Io(e) => write!(f, "I/O error: {}", e),
+            DlisErrorKind::InvalidStorageUnitLabel => write!(f, "invalid storage unit label"),
+            DlisErrorKind::InvalidVisibleRecord => write!(f, "invalid visible record header"),
+            DlisErrorKind::InvalidSegment => write!(f, "invalid logical record segment"),
+            DlisErrorKind::UnexpectedEof => write!(f, "unexpected end of data"),
+            DlisErrorKind::InvalidDescriptor(d) => {
+                write!(f, "invalid component descriptor {:#04x}", d)
+            }
+            DlisErrorKind::InvalidRepresentationCode(code) => {
+                write!(f, "invalid representation code {}", code)
+            }
+            DlisErrorKind::UnknownFrame(name) => write!(f, "frame data for unknown frame {}", name),
+        }
+    }
+}
+
+impl fmt::Display for DlisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offset {}: {}", self.offset, self.kind)
+    }
+}
+
+impl std::error::Error for DlisError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            DlisErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for DlisError {
+    fn from(e: std::io::Error) -> Self {
+        DlisError::new(0, DlisErrorKind::Io(e))
+    }
+}
+
+/// The 80 byte label at the start of every storage unit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageUnitLabel {
+    pub sequence_number: u32,
+    pub version: String,
+    pub structure: String,
+    pub max_record_length: u32,
+    pub storage_set_identifier: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Attribute {
+    pub label: String,
+    pub count: usize,
+    pub repr_code: u8,
+    pub units: String,
+    pub values: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Object {
+    pub name: ObjectName,
+    pub attributes: Vec<Attribute>,
+}
+
+impl Object {
+    pub fn attribute(&self, label: &str) -> Option<&Attribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.label.eq_ignore_ascii_case(label))
+    }
+
+    pub fn values(&self, label: &str) -> &[Value] {
+        self.attribute(label)
+            .map(|attribute| attribute.values.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// First value of an attribute as text
+    pub fn text(&self, label: &str) -> Option<&str> {
+        self.values(label).first().and_then(Value::as_str)
+    }
+
+    /// First value of an attribute as a number
+    pub fn number(&self, label: &str) -> Option<f64> {
+        self.values(label).first().and_then(Value::as_f64)
+    }
+}
+
+/// The objects of one EFLR, e.g. all `CHANNEL` objects.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ObjectSet {
+    pub set_type: String,
+    pub name: String,
+    pub objects: Vec<Object>,
+}
+
+/// A channel of a frame with its decoded samples.
+///
+/// `values` holds one row per frame; scalar channels have rows of one
+/// element, array channels `dimension.iter().product()` elements.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DlisChannel {
+    pub name: ObjectName,
+    pub long_name: String,
+    pub units: String,
+    pub repr_code: u8,
+    pub dimension: Vec<usize>,
+    pub values: Vec<Vec<f64>>,
+}
+
+impl DlisChannel {
+    /// Number of elements per frame
+    pub fn width(&self) -> usize {
+        self.dimension.iter().product::<usize>().max(1)
+    }
+
+    pub fn is_scalar(&self) -> bool {
+        self.width() == 1
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DlisFrame {
+    pub name: ObjectName,
+    pub index_type: String,
+    pub direction: String,
+    pub spacing: Option<f64>,
+    pub frame_numbers: Vec<u32>,
+    pub channels: Vec<DlisChannel>,
+}
+
+impl DlisFrame {
+    pub fn channel(&self, id: &str) -> Option<&DlisChannel> {
+        self.channels
+            .iter()
+            .find(|channel| channel.name.id.eq_ignore_ascii_case(id))
+    }
+
+    /// The index channel: the first channel when the frame declares an `INDEX-TYPE`.
+    pub fn index(&self) -> Option<&DlisChannel> {
+        if self.index_type.is_empty() {
+            None
+        } else {
+            self.channels.first()
+        }
+    }
+
+    /// Convert the frame into curves. Frames without an index channel are
+    /// indexed by frame number.
+    pub fn to_curve_set(&self) -> CurveSet {
+        let mut channels = self.channels.iter();
+
+        let index = match self.index() {
+            Some(index) => {
+                channels.next();
+                Curve {
+                    mnemonic: index.name.id.clone(),
+                    unit: index.units.clone(),
+                    description: index.long_name.clone(),
+                    values: index.values.iter().map(|row| row[0]).collect(),
+                    null_value: None,
+                }
+            }
+            None => Curve {
+                mnemonic: String::from("FRAME"),
+                values: self.frame_numbers.iter().map(|n| *n as f64).collect(),
+                ..Default::default()
+            },
+        };
+
+        let mut curve_set = CurveSet {
+            index,
+            ..Default::default()
+        };
+
+        for channel in channels {
+            if channel.is_scalar() {
+                curve_set.curves.push(Curve {
+                    mnemonic: channel.name.id.clone(),
+                    unit: channel.units.clone(),
+                    description: channel.long_name.clone(),
+                    values: channel.values.iter().map(|row| row[0]).collect(),
+                    null_value: None,
+                });
+            } else {
+                curve_set.arrays.push(ArrayCurve {
+                    mnemonic: channel.name.id.clone(),
+                    unit: channel.units.clone(),
+                    description: channel.long_name.clone(),
+                    values: channel.values.clone(),
+                    null_value: None,
+                });
+            }
+        }
+
+        curve_set
+    }
+}
+
+/// A logical file: everything from one `FILE-HEADER` to the next.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DlisLogicalFile {
+    pub sets: Vec<ObjectSet>,
+    pub frames: Vec<DlisFrame>,
+}
+
+impl DlisLogicalFile {
+    /// All objects of the given set type, e.g. `CHANNEL` or `ORIGIN`
+    pub fn objects<'f>(&'f self, set_type: &str) -> impl Iterator<Item = &'f Object> + 'f {
+        let set_type = set_type.to_string();
+
+        self.sets
+            .iter()
+            .filter(move |set| set.set_type.eq_ignore_ascii_case(&set_type))
+            .flat_map(|set| set.objects.iter())
+    }
+
+    pub fn object(&self, set_type: &str, name: &ObjectName) -> Option<&Object> {
+        self.objects(set_type).find(|object| &object.name == name)
+    }
+
+    pub fn frame(&self, id: &str) -> Option<&DlisFrame> {
+        self.frames
+            .iter()
+            .find(|frame| frame.name.id.eq_ignore_ascii_case(id))
+    }
+
+    fn is_empty(&self) -> bool {
+        self.sets.is_empty() && self.frames.is_empty()
+    }
+
+    /// Lay out a frame from its `FRAME` object and the referenced `CHANNEL` objects.
+    fn build_frame(&self, name: &ObjectName) -> Option<DlisFrame> {
+        let frame = self.object("FRAME", name)?;
+
+        let channels = frame
+            .values("CHANNELS")
+            .iter()
+            .filter_map(Value::as_name)
+            .map(|channel_name| {
+                let channel = self.object("CHANNEL", channel_name);
+                let text = |label| {
+                    channel
+                        .and_then(|c| c.text(label))
+                        .unwrap_or_default()
+                        .to_string()
+                };
+
+                DlisChannel {
+                    name: channel_name.clone(),
+                    long_name: text("LONG-NAME"),
+                    units: text("UNITS"),
+                    repr_code: channel
+                        .and_then(|c| c.number("REPRESENTATION-CODE"))
+                        .map(|code| code as u8)
+                        .unwrap_or(repcode::FSINGL),
+                    dimension: channel
+                        .map(|c| c.values("DIMENSION"))
+                        .unwrap_or(&[])
+                        .iter()
+                        .filter_map(Value::as_f64)
+                        .map(|d| d as usize)
+                        .collect(),
+                    values: vec![],
+                }
+            })
+            .collect();
+
+        Some(DlisFrame {
+            name: name.clone(),
+            index_type: frame.text("INDEX-TYPE").unwrap_or_default().to_string(),
+            direction: frame.text("DIRECTION").unwrap_or_default().to_string(),
+            spacing: frame.number("SPACING"),
+            frame_numbers: vec![],
+            channels,
+        })
+    }
+
+    /// Add the frames that never received any frame data.
+    fn finish(&mut self) {
+        let names: Vec<ObjectName> = self
+            .objects("FRAME")
+            .map(|frame| frame.name.clone())
+            .filter(|name| !self.frames.iter().any(|frame| &frame.name == name))
+            .collect();
+
+        for name in names {
+            if let Some(frame) = self.build_frame(&name) {
+                self.frames.push(frame);
+            }
+        }
+    }
+
+    fn read_frame_data(&mut self, body: &[u8], offset: usize) -> Result<(), DlisError> {
+        let mut cursor = Cursor::new(body, offset);
+        let name = cursor.obname()?;
+        let frame_number = cursor.uvari()?;
+
+        let idx = match self.frames.iter().position(|frame| frame.name == name) {
+            Some(idx) => idx,
+            None => {
+                let frame = self
+                    .build_frame(&name)
+                    .ok_or_else(|| cursor.error(DlisErrorKind::UnknownFrame(name.id.clone())))?;
+                self.frames.push(frame);
+                self.frames.len() - 1
+            }
+        };
+
+        let frame = &mut self.frames[idx];
+        frame.frame_numbers.push(frame_number);
+
+        for channel in frame.channels.iter_mut() {
+            let row = (0..channel.width())
+                .map(|_| {
+                    cursor
+                        .value(channel.repr_code)
+                        .map(|value| value.as_f64().unwrap_or(f64::NAN))
+                })
+                .collect::<Result<_, _>>()?;
+            channel.values.push(row);
+        }
+
+        Ok(())
+    }
+}
+
+/// A decoded storage unit.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DlisFile {
+    pub label: StorageUnitLabel,
+    pub logical_files: Vec<DlisLogicalFile>,
+}
+
+// logical record types
+const FHLR: u8 = 0;
+const FDATA: u8 = 0;
+
+impl DlisFile {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, DlisError> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, DlisError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+
+        Self::parse(&bytes)
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self, DlisError> {
+        let label = record::read_storage_unit_label(data)?;
+
+        let mut logical_files = vec![];
+        let mut current = DlisLogicalFile::default();
+
+        for record in record::read_logical_records(data)? {
+            // encrypted records can not be read without the producer's key
+            if record.encrypted {
+                continue;
+            }
+
+            if record.explicit {
+                if record.kind == FHLR && !current.is_empty() {
+                    current.finish();
+                    logical_files.push(std::mem::take(&mut current));
+                }
+                current
+                    .sets
+                    .push(eflr::parse_set(&record.body, record.offset)?);
+            } else if record.kind == FDATA {
+                current.read_frame_data(&record.body, record.offset)?;
+            }
+        }
+
+        if !current.is_empty() {
+            current.finish();
+            logical_files.push(current);
+        }
+
+        Ok(Self {
+            label,
+            logical_files,
+        })
+    }
+
+    /// All frames of all logical files
+    pub fn frames(&self) -> impl Iterator<Item = &DlisFrame> {
+        self.logical_files
+            .iter()
+            .flat_map(|file| file.frames.iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        record::tests::{ident, obname, segment, storage_unit_label, visible_record},
+        repcode::{DTIME, FSHORT, FSINGL, ISINGL, OBNAME, USHORT, UVARI, VSINGL},
+        *,
+    };
+
+    const EFLR: u8 = 0x80;
+
+    /// `CHANNEL` set of a depth index and one channel per representation
+    /// code, `D` holding two elements per frame
+    fn channel_set() -> Vec<u8> {
+        // set with a type, template attributes with a label and a
+        // representation code, DIMENSION defaulting to one element
+        let mut body = vec![0xF0];
+        body.extend(ident("CHANNEL"));
+
+        body.push(0x34);
+        body.extend(ident("UNITS"));
+        body.push(repcode::UNITS);
+        body.push(0x34);
+        body.extend(ident("REPRESENTATION-CODE"));
+        body.push(USHORT);
+        body.push(0x3D);
+        body.extend(ident("DIMENSION"));
+        body.extend([1, UVARI, 1]);
+
+        let channels: [(&str, &str, u8, Option<u8>); 6] = [
+            ("TDEP", "m", FSINGL, None),
+            ("A", "", FSHORT, None),
+            ("B", "", ISINGL, None),
+            ("C", "", VSINGL, None),
+            ("T", "", DTIME, None),
+            ("D", "ms", FSINGL, Some(2)),
+        ];
+        // named objects with attribute values only
+        for (name, units, code, dimension) in channels {
+            body.push(0x70);
+            body.extend(obname(name));
+            body.push(0x21);
+            body.extend(ident(units));
+            body.extend([0x21, code]);
+            if let Some(dimension) = dimension {
+                body.extend([0x21, dimension]);
+            }
+        }
+
+        body
+    }
+
+    fn frame_set() -> Vec<u8> {
+        let mut body = vec![0xF0];
+        body.extend(ident("FRAME"));
+
+        body.push(0x30);
+        body.extend(ident("INDEX-TYPE"));
+        body.push(0x34);
+        body.extend(ident("CHANNELS"));
+        body.push(OBNAME);
+
+        body.push(0x70);
+        body.extend(obname("F1"));
+        body.push(0x21);
+        body.extend(ident("BOREHOLE-DEPTH"));
+        // a count and the values
+        body.extend([0x29, 6]);
+        for name in ["TDEP", "A", "B", "C", "T", "D"] {
+            body.extend(obname(name));
+        }
+
+        body
+    }
+
+    /// Frame `number` at `depth`: 153 through each floating point code,
+    /// or -153 when `negative`
+    fn frame(number: u8, depth: f32, negative: bool, d: [f32; 2]) -> Vec<u8> {
+        let sign = if negative { 0x80 } else { 0 };
+
+        let mut body = obname("F1");
+        body.push(number);
+        body.extend(depth.to_be_bytes());
+        body.extend(if sign == 0 {
+            [0x4C, 0x88]
+        } else {
+            [0xB3, 0x88]
+        });
+        body.extend([0x42 | sign, 0x99, 0x00, 0x00]);
+        body.extend([0x19, 0x44 | sign, 0x00, 0x00]);
+        body.extend([87, 0x14, 19, 21, 20, 15, 0x02, 0x6C]);
+        for value in d {
+            body.extend(value.to_be_bytes());
+        }
+        body
+    }
+
+    fn file(frames: &[Vec<u8>]) -> Vec<u8> {
+        let mut segments = vec![
+            segment(EFLR, 3, &channel_set()),
+            segment(EFLR, 4, &frame_set()),
+        ];
+        segments.extend(frames.iter().map(|body| segment(0, FDATA, body)));
+
+        let mut data = storage_unit_label();
+        data.extend(visible_record(&segments));
+        data
+    }
+
+    #[test]
+    fn decodes_frame_data() {
+        let data = file(&[
+            frame(1, 1000.0, false, [1.0, 2.0]),
+            frame(2, 1000.5, true, [3.0, 4.0]),
+        ]);
+        let dlis = DlisFile::parse(&data).unwrap();
+
+        assert_eq!(dlis.label.sequence_number, 1);
+        assert_eq!(dlis.logical_files.len(), 1);
+
+        let frame = dlis.logical_files[0].frame("F1").unwrap();
+        assert_eq!(frame.index_type, "BOREHOLE-DEPTH");
+        assert_eq!(frame.frame_numbers, vec![1, 2]);
+        assert_eq!(frame.channel("A").unwrap().repr_code, FSHORT);
+        assert_eq!(frame.channel("D").unwrap().dimension, vec![2]);
+
+        let curves = frame.to_curve_set();
+        assert_eq!(curves.index.mnemonic, "TDEP");
+        assert_eq!(curves.index.unit, "m");
+        assert_eq!(curves.index.values, vec![1000.0, 1000.5]);
+        for name in ["A", "B", "C"] {
+            assert_eq!(curves.curve(name).unwrap().values, vec![153.0, -153.0]);
+        }
+
+        // a time has no numeric value, but its bytes are skipped
+        assert!(curves.curve("T").unwrap().values.iter().all(|v| v.is_nan()));
+        let d = curves.array("D").unwrap();
+        assert_eq!(d.unit, "ms");
+        assert_eq!(d.values, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    }
+
+    #[test]
+    fn reports_truncated_frame_data() {
+        let mut truncated = frame(2, 1000.5, true, [3.0, 4.0]);
+        truncated.truncate(truncated.len() - 2);
+
+        let data = file(&[frame(1, 1000.0, false, [1.0, 2.0]), truncated]);
+        let offset = data.len() - (4 + frame(2, 1000.5, true, [3.0, 4.0]).len() - 2);
+        let error = DlisFile::parse(&data).unwrap_err();
+
+        assert_eq!(error.offset, offset);
+        assert!(matches!(error.kind, DlisErrorKind::UnexpectedEof));
+    }
+}
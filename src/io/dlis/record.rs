@@ -0,0 +1,278 @@
+//! Storage unit label, visible records and logical record segments.
+
+use super::{DlisError, DlisErrorKind, StorageUnitLabel};
+
+const SUL_LENGTH: usize = 80;
+
+// logical record segment attributes
+const EXPLICIT_FORMATTING: u8 = 0x80;
+const PREDECESSOR: u8 = 0x40;
+const SUCCESSOR: u8 = 0x20;
+const ENCRYPTION: u8 = 0x10;
+const CHECKSUM: u8 = 0x04;
+const TRAILING_LENGTH: u8 = 0x02;
+const PADDING: u8 = 0x01;
+
+/// A logical record assembled from its segments.
+#[derive(Debug)]
+pub(super) struct LogicalRecord {
+    /// File offset of the first segment
+    pub offset: usize,
+    /// EFLR when set, IFLR otherwise
+    pub explicit: bool,
+    pub encrypted: bool,
+    pub kind: u8,
+    pub body: Vec<u8>,
+}
+
+pub(super) fn read_storage_unit_label(data: &[u8]) -> Result<StorageUnitLabel, DlisError> {
+    let invalid = || DlisError::new(0, DlisErrorKind::InvalidStorageUnitLabel);
+
+    let label = data.get(..SUL_LENGTH).ok_or_else(invalid)?;
+    let field =
+        |range: std::ops::Range<usize>| String::from_utf8_lossy(&label[range]).trim().to_string();
+
+    let version = field(4..9);
+    if !version.starts_with("V1.") {
+        return Err(invalid());
+    }
+
+    Ok(StorageUnitLabel {
+        sequence_number: field(0..4).parse().unwrap_or(0),
+        version,
+        structure: field(9..15),
+        max_record_length: field(15..20).parse().unwrap_or(0),
+        storage_set_identifier: field(20..80),
+    })
+}
+
+/// Split the visible records following the storage unit label into logical records.
+pub(super) fn read_logical_records(data: &[u8]) -> Result<Vec<LogicalRecord>, DlisError> {
+    let mut records = vec![];
+    let mut current: Option<LogicalRecord> = None;
+
+    let mut pos = SUL_LENGTH;
+
+    while pos < data.len() {
+        // visible record header: length, 0xFF, major version 1
+        let header = data
+            .get(pos..pos + 4)
+            .ok_or_else(|| DlisError::new(pos, DlisErrorKind::UnexpectedEof))?;
+        let length = u16::from_be_bytes([header[0], header[1]]) as usize;
+
+        if header[2] != 0xFF || header[3] != 1 || length < 4 {
+            return Err(DlisError::new(pos, DlisErrorKind::InvalidVisibleRecord));
+        }
+
+        let end = pos + length;
+        if end > data.len() {
+            return Err(DlisError::new(pos, DlisErrorKind::UnexpectedEof));
+        }
+
+        let mut seg_pos = pos + 4;
+        while seg_pos < end {
+            let seg_header = data
+                .get(seg_pos..seg_pos + 4)
+                .ok_or_else(|| DlisError::new(seg_pos, DlisErrorKind::UnexpectedEof))?;
+            let seg_length = u16::from_be_bytes([seg_header[0], seg_header[1]]) as usize;
+            let attributes = seg_header[2];
+            let kind = seg_header[3];
+
+            if seg_length < 4 || seg_pos + seg_length > end {
+                return Err(DlisError::new(seg_pos, DlisErrorKind::InvalidSegment));
+            }
+
+            let body = segment_body(&data[seg_pos + 4..seg_pos + seg_length], attributes)
+                .ok_or_else(|| DlisError::new(seg_pos, DlisErrorKind::InvalidSegment))?;
+
+            let record = match current.as_mut() {
+                Some(record) if attributes & PREDECESSOR != 0 => record,
+                Some(_) => {
+                    return Err(DlisError::new(seg_pos, DlisErrorKind::InvalidSegment));
+                }
+                None if attributes & PREDECESSOR != 0 => {
+                    return Err(DlisError::new(seg_pos, DlisErrorKind::InvalidSegment));
+                }
+                None => current.insert(LogicalRecord {
+                    offset: seg_pos,
+                    explicit: attributes & EXPLICIT_FORMATTING != 0,
+                    encrypted: attributes & ENCRYPTION != 0,
+                    kind,
+                    body: vec![],
+                }),
+            };
+
+            record.body.extend_from_slice(body);
+
+            if attributes & SUCCESSOR == 0 {
+                records.extend(current.take());
+            }
+
+            seg_pos += seg_length;
+        }
+
+        pos = end;
+    }
+
+    if let Some(record) = current {
+        return Err(DlisError::new(record.offset, DlisErrorKind::UnexpectedEof));
+    }
+
+    Ok(records)
+}
+
+/// Strip the trailer (pad bytes, checksum, trailing length) off a segment body.
+fn segment_body(body: &[u8], attributes: u8) -> Option<&[u8]> {
+    let mut len = body.len();
+
+    if attributes & TRAILING_LENGTH != 0 {
+        len = len.checked_sub(2)?;
+    }
+    if attributes & CHECKSUM != 0 {
+        len = len.checked_sub(2)?;
+    }
+    // encrypted bodies keep their padding, it cannot be read back anyway
+    if attributes & PADDING != 0 && attributes & ENCRYPTION == 0 {
+        let pad = *body.get(len.checked_sub(1)?)? as usize;
+        len = len.checked_sub(pad)?;
+    }
+
+    Some(&body[..len])
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// A storage unit label of sequence 1, version V1.00, 8192 byte records
+    pub(in crate::io::dlis) fn storage_unit_label() -> Vec<u8> {
+        format!("{:>4}V1.00RECORD{:>5}{:<60}", 1, 8192, "TEST STORAGE SET").into_bytes()
+    }
+
+    /// An `IDENT` value
+    pub(in crate::io::dlis) fn ident(text: &str) -> Vec<u8> {
+        let mut bytes = vec![text.len() as u8];
+        bytes.extend_from_slice(text.as_bytes());
+        bytes
+    }
+
+    /// An `OBNAME` value of origin 1, copy 0
+    pub(in crate::io::dlis) fn obname(id: &str) -> Vec<u8> {
+        let mut bytes = vec![1, 0];
+        bytes.extend(ident(id));
+        bytes
+    }
+
+    /// A logical record segment with its 4 byte header
+    pub(in crate::io::dlis) fn segment(attributes: u8, kind: u8, body: &[u8]) -> Vec<u8> {
+        let length = (4 + body.len()) as u16;
+        let mut bytes = length.to_be_bytes().to_vec();
+        bytes.extend([attributes, kind]);
+        bytes.extend_from_slice(body);
+        bytes
+    }
+
+    /// A visible record holding `segments`
+    pub(in crate::io::dlis) fn visible_record(segments: &[Vec<u8>]) -> Vec<u8> {
+        let length = (4 + segments.iter().map(Vec::len).sum::<usize>()) as u16;
+        let mut bytes = length.to_be_bytes().to_vec();
+        bytes.extend([0xFF, 0x01]);
+        for segment in segments {
+            bytes.extend_from_slice(segment);
+        }
+        bytes
+    }
+
+    #[test]
+    fn reads_storage_unit_label() {
+        let label = read_storage_unit_label(&storage_unit_label()).unwrap();
+
+        assert_eq!(
+            label,
+            StorageUnitLabel {
+                sequence_number: 1,
+                version: "V1.00".to_string(),
+                structure: "RECORD".to_string(),
+                max_record_length: 8192,
+                storage_set_identifier: "TEST STORAGE SET".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_short_or_foreign_labels() {
+        let short = &storage_unit_label()[..40];
+        let foreign = storage_unit_label()
+            .iter()
+            .enumerate()
+            .map(|(i, byte)| if i == 5 { b'2' } else { *byte })
+            .collect::<Vec<u8>>();
+
+        for data in [short, &foreign] {
+            let error = read_storage_unit_label(data).unwrap_err();
+            assert!(matches!(error.kind, DlisErrorKind::InvalidStorageUnitLabel));
+        }
+    }
+
+    #[test]
+    fn joins_segments_across_visible_records() {
+        let mut data = storage_unit_label();
+        // first half of an EFLR, padded with two bytes
+        data.extend(visible_record(&[segment(
+            EXPLICIT_FORMATTING | SUCCESSOR | PADDING,
+            3,
+            &[1, 2, 3, 0, 2],
+        )]));
+        // second half with a checksum and a trailing length, then an IFLR
+        data.extend(visible_record(&[
+            segment(
+                EXPLICIT_FORMATTING | PREDECESSOR | CHECKSUM | TRAILING_LENGTH,
+                3,
+                &[4, 5, 0xAB, 0xCD, 0, 10],
+            ),
+            segment(0, 0, &[6, 7]),
+        ]));
+
+        let records = read_logical_records(&data).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert!(records[0].explicit);
+        assert_eq!(records[0].kind, 3);
+        assert_eq!(records[0].offset, 84);
+        assert_eq!(records[0].body, vec![1, 2, 3, 4, 5]);
+        assert!(!records[1].explicit);
+        assert_eq!(records[1].body, vec![6, 7]);
+    }
+
+    #[test]
+    fn reports_truncated_records() {
+        // visible record cut short
+        let mut data = storage_unit_label();
+        let mut record = visible_record(&[segment(EXPLICIT_FORMATTING, 3, &[1, 2, 3, 4])]);
+        record.truncate(record.len() - 2);
+        data.extend(record);
+
+        let error = read_logical_records(&data).unwrap_err();
+        assert_eq!(error.offset, 80);
+        assert!(matches!(error.kind, DlisErrorKind::UnexpectedEof));
+
+        // logical record announcing a successor that never comes
+        let mut data = storage_unit_label();
+        data.extend(visible_record(&[segment(
+            EXPLICIT_FORMATTING | SUCCESSOR,
+            3,
+            &[1, 2],
+        )]));
+
+        let error = read_logical_records(&data).unwrap_err();
+        assert_eq!(error.offset, 84);
+        assert!(matches!(error.kind, DlisErrorKind::UnexpectedEof));
+
+        // padding longer than the segment
+        let mut data = storage_unit_label();
+        data.extend(visible_record(&[segment(PADDING, 0, &[1, 9])]));
+
+        let error = read_logical_records(&data).unwrap_err();
+        assert!(matches!(error.kind, DlisErrorKind::InvalidSegment));
+    }
+}
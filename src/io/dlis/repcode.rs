@@ -0,0 +1,360 @@
+//! RP66 v1 representation codes (appendix B of the standard).
+
+use super::{DlisError, DlisErrorKind};
+
+pub const FSHORT: u8 = 1;
+pub const FSINGL: u8 = 2;
+pub const FSING1: u8 = 3;
+pub const FSING2: u8 = 4;
+pub const ISINGL: u8 = 5;
+pub const VSINGL: u8 = 6;
+pub const FDOUBL: u8 = 7;
+pub const FDOUB1: u8 = 8;
+pub const FDOUB2: u8 = 9;
+pub const CSINGL: u8 = 10;
+pub const CDOUBL: u8 = 11;
+pub const SSHORT: u8 = 12;
+pub const SNORM: u8 = 13;
+pub const SLONG: u8 = 14;
+pub const USHORT: u8 = 15;
+pub const UNORM: u8 = 16;
+pub const ULONG: u8 = 17;
+pub const UVARI: u8 = 18;
+pub const IDENT: u8 = 19;
+pub const ASCII: u8 = 20;
+pub const DTIME: u8 = 21;
+pub const ORIGIN: u8 = 22;
+pub const OBNAME: u8 = 23;
+pub const OBJREF: u8 = 24;
+pub const ATTREF: u8 = 25;
+pub const STATUS: u8 = 26;
+pub const UNITS: u8 = 27;
+
+/// `OBNAME`: the identifier of an object within a logical file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ObjectName {
+    pub origin: u32,
+    pub copy: u8,
+    pub id: String,
+}
+
+/// `DTIME`: a date and time, `time_zone` is 0 (local standard), 1 (local
+/// daylight savings) or 2 (GMT).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DateTime {
+    pub year: u16,
+    pub time_zone: u8,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub millisecond: u16,
+}
+
+/// A decoded value of any representation code.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Float(f64),
+    /// Values with bounds (`FSING1`, `FDOUB2` ...) and complex numbers; the
+    /// first element is the value itself
+    Floats(Vec<f64>),
+    Text(String),
+    Time(DateTime),
+    Name(ObjectName),
+    /// `OBJREF`: object type and name
+    Reference(String, ObjectName),
+    /// `ATTREF`: object type, name and attribute label
+    AttributeReference(String, ObjectName, String),
+    Status(bool),
+}
+
+impl Value {
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Integer(v) => Some(*v as f64),
+            Value::Float(v) => Some(*v),
+            Value::Floats(v) => v.first().copied(),
+            Value::Status(v) => Some(if *v { 1.0 } else { 0.0 }),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Text(v) => Some(v),
+            Value::Name(name) => Some(&name.id),
+            _ => None,
+        }
+    }
+
+    pub fn as_name(&self) -> Option<&ObjectName> {
+        match self {
+            Value::Name(name) | Value::Reference(_, name) => Some(name),
+            _ => None,
+        }
+    }
+}
+
+/// Reads representation codes out of the body of a logical record.
+pub(super) struct Cursor<'b> {
+    data: &'b [u8],
+    pos: usize,
+    /// file offset of the logical record, used for errors
+    offset: usize,
+}
+
+impl<'b> Cursor<'b> {
+    pub fn new(data: &'b [u8], offset: usize) -> Self {
+        Self {
+            data,
+            pos: 0,
+            offset,
+        }
+    }
+
+    pub fn error(&self, kind: DlisErrorKind) -> DlisError {
+        DlisError::new(self.offset, kind)
+    }
+
+    pub fn peek(&self) -> Option<u8> {
+        self.data.get(self.pos).copied()
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'b [u8], DlisError> {
+        if self.pos + len > self.data.len() {
+            return Err(self.error(DlisErrorKind::UnexpectedEof));
+        }
+        let bytes = &self.data[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], DlisError> {
+        let mut bytes = [0; N];
+        bytes.copy_from_slice(self.take(N)?);
+        Ok(bytes)
+    }
+
+    pub fn ushort(&mut self) -> Result<u8, DlisError> {
+        Ok(self.take(1)?[0])
+    }
+
+    pub fn unorm(&mut self) -> Result<u16, DlisError> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    pub fn ulong(&mut self) -> Result<u32, DlisError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    /// Variable length unsigned integer, one, two or four bytes long.
+    pub fn uvari(&mut self) -> Result<u32, DlisError> {
+        let first = self.ushort()?;
+
+        match first {
+            0x00..=0x7F => Ok(first as u32),
+            0x80..=0xBF => Ok((((first & 0x3F) as u32) << 8) | self.ushort()? as u32),
+            _ => {
+                let rest = self.take(3)?;
+                Ok((((first & 0x3F) as u32) << 24)
+                    | ((rest[0] as u32) << 16)
+                    | ((rest[1] as u32) << 8)
+                    | rest[2] as u32)
+            }
+        }
+    }
+
+    pub fn ident(&mut self) -> Result<String, DlisError> {
+        let len = self.ushort()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    pub fn ascii(&mut self) -> Result<String, DlisError> {
+        let len = self.uvari()? as usize;
+        Ok(String::from_utf8_lossy(self.take(len)?).into_owned())
+    }
+
+    pub fn obname(&mut self) -> Result<ObjectName, DlisError> {
+        Ok(ObjectName {
+            origin: self.uvari()?,
+            copy: self.ushort()?,
+            id: self.ident()?,
+        })
+    }
+
+    fn fsingl(&mut self) -> Result<f64, DlisError> {
+        Ok(f32::from_be_bytes(self.array()?) as f64)
+    }
+
+    fn fdoubl(&mut self) -> Result<f64, DlisError> {
+        Ok(f64::from_be_bytes(self.array()?))
+    }
+
+    fn fsingls(&mut self, count: usize) -> Result<Value, DlisError> {
+        (0..count)
+            .map(|_| self.fsingl())
+            .collect::<Result<_, _>>()
+            .map(Value::Floats)
+    }
+
+    fn fdoubls(&mut self, count: usize) -> Result<Value, DlisError> {
+        (0..count)
+            .map(|_| self.fdoubl())
+            .collect::<Result<_, _>>()
+            .map(Value::Floats)
+    }
+
+    /// Decode one value of the given representation code.
+    pub fn value(&mut self, code: u8) -> Result<Value, DlisError> {
+        Ok(match code {
+            FSHORT => Value::Float(fshort(self.unorm()?)),
+            FSINGL => Value::Float(self.fsingl()?),
+            FSING1 => self.fsingls(2)?,
+            FSING2 => self.fsingls(3)?,
+            ISINGL => Value::Float(isingl(self.array()?)),
+            VSINGL => Value::Float(vsingl(self.array()?)),
+            FDOUBL => Value::Float(self.fdoubl()?),
+            FDOUB1 => self.fdoubls(2)?,
+            FDOUB2 => self.fdoubls(3)?,
+            CSINGL => self.fsingls(2)?,
+            CDOUBL => self.fdoubls(2)?,
+            SSHORT => Value::Integer(self.ushort()? as i8 as i64),
+            SNORM => Value::Integer(i16::from_be_bytes(self.array()?) as i64),
+            SLONG => Value::Integer(i32::from_be_bytes(self.array()?) as i64),
+            USHORT => Value::Integer(self.ushort()? as i64),
+            UNORM => Value::Integer(self.unorm()? as i64),
+            ULONG => Value::Integer(self.ulong()? as i64),
+            UVARI | ORIGIN => Value::Integer(self.uvari()? as i64),
+            IDENT | UNITS => Value::Text(self.ident()?),
+            ASCII => Value::Text(self.ascii()?),
+            DTIME => {
+                let [year, tz_month, day, hour, minute, second] = self.array()?;
+                Value::Time(DateTime {
+                    year: 1900 + year as u16,
+                    time_zone: tz_month >> 4,
+                    month: tz_month & 0x0F,
+                    day,
+                    hour,
+                    minute,
+                    second,
+                    millisecond: self.unorm()?,
+                })
+            }
+            OBNAME => Value::Name(self.obname()?),
+            OBJREF => Value::Reference(self.ident()?, self.obname()?),
+            ATTREF => Value::AttributeReference(self.ident()?, self.obname()?, self.ident()?),
+            STATUS => Value::Status(self.ushort()? != 0),
+            _ => return Err(self.error(DlisErrorKind::InvalidRepresentationCode(code))),
+        })
+    }
+}
+
+/// 12 bit two's complement fraction followed by a 4 bit exponent.
+fn fshort(v: u16) -> f64 {
+    let mantissa = (v as i16) >> 4;
+    let exponent = (v & 0x000F) as i32;
+
+    mantissa as f64 / 2048.0 * 2f64.powi(exponent)
+}
+
+/// IBM System/360 single precision: sign, excess 64 base 16 exponent, 24 bit fraction.
+fn isingl(bytes: [u8; 4]) -> f64 {
+    let v = u32::from_be_bytes(bytes);
+    let sign = if v & 0x8000_0000 != 0 { -1.0 } else { 1.0 };
+    let exponent = ((v >> 24) & 0x7F) as i32 - 64;
+    let fraction = (v & 0x00FF_FFFF) as f64 / (1 << 24) as f64;
+
+    sign * fraction * 16f64.powi(exponent)
+}
+
+/// VAX F floating point, stored as two little endian 16 bit words.
+fn vsingl(bytes: [u8; 4]) -> f64 {
+    let sign = if bytes[1] & 0x80 != 0 { -1.0 } else { 1.0 };
+    let exponent = (((bytes[1] & 0x7F) as i32) << 1) | (bytes[0] >> 7) as i32;
+
+    if exponent == 0 {
+        return 0.0;
+    }
+
+    let fraction = (((bytes[0] & 0x7F) as u32) << 16) | ((bytes[3] as u32) << 8) | bytes[2] as u32;
+
+    sign * (0.5 + fraction as f64 / (1 << 24) as f64) * 2f64.powi(exponent - 128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(code: u8, bytes: &[u8]) -> Value {
+        let mut cursor = Cursor::new(bytes, 0);
+        let value = cursor.value(code).unwrap();
+        assert_eq!(cursor.peek(), None, "all bytes of the value are read");
+        value
+    }
+
+    #[test]
+    fn decodes_floating_point_codes() {
+        // 153 and -153 in each format, from appendix B of RP66 v1
+        let cases: [(u8, [u8; 4], [u8; 4]); 3] = [
+            (FSINGL, [0x43, 0x19, 0x00, 0x00], [0xC3, 0x19, 0x00, 0x00]),
+            (ISINGL, [0x42, 0x99, 0x00, 0x00], [0xC2, 0x99, 0x00, 0x00]),
+            (VSINGL, [0x19, 0x44, 0x00, 0x00], [0x19, 0xC4, 0x00, 0x00]),
+        ];
+
+        for (code, positive, negative) in cases {
+            assert_eq!(decode(code, &positive), Value::Float(153.0), "code {code}");
+            assert_eq!(decode(code, &negative), Value::Float(-153.0), "code {code}");
+        }
+
+        assert_eq!(decode(FSHORT, &[0x4C, 0x88]), Value::Float(153.0));
+        assert_eq!(decode(FSHORT, &[0xB3, 0x88]), Value::Float(-153.0));
+        assert_eq!(decode(VSINGL, &[0x00, 0x00, 0x00, 0x00]), Value::Float(0.0));
+    }
+
+    #[test]
+    fn decodes_dtime() {
+        // 1987-04-19 21:20:15.620, local daylight savings time
+        let value = decode(DTIME, &[87, 0x14, 19, 21, 20, 15, 0x02, 0x6C]);
+
+        assert_eq!(
+            value,
+            Value::Time(DateTime {
+                year: 1987,
+                time_zone: 1,
+                month: 4,
+                day: 19,
+                hour: 21,
+                minute: 20,
+                second: 15,
+                millisecond: 620,
+            })
+        );
+    }
+
+    #[test]
+    fn decodes_variable_length_integers() {
+        assert_eq!(decode(UVARI, &[0x7F]), Value::Integer(127));
+        assert_eq!(decode(UVARI, &[0x81, 0x00]), Value::Integer(256));
+        assert_eq!(
+            decode(UVARI, &[0xC0, 0x01, 0x00, 0x00]),
+            Value::Integer(65536)
+        );
+    }
+
+    #[test]
+    fn reports_truncated_values_and_unknown_codes() {
+        let mut cursor = Cursor::new(&[0x43, 0x19], 42);
+        let error = cursor.value(FSINGL).unwrap_err();
+        assert_eq!(error.offset, 42);
+        assert!(matches!(error.kind, DlisErrorKind::UnexpectedEof));
+
+        let mut cursor = Cursor::new(&[0], 42);
+        let error = cursor.value(99).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            DlisErrorKind::InvalidRepresentationCode(99)
+        ));
+    }
+}
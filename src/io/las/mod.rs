@@ -0,0 +1,224 @@
+//! LAS (Log ASCII Standard) support.
+//!
+//! ```ignore
+//! let las = LasFile::open("well.las")?;
+//! let gr = las.data.points("GR").unwrap();
+//!
+//! LasWriter::from_las(&las).step(0.1).save("resampled.las", &las.data)?;
+//! ```
+
+mod reader;
+mod writer;
+
+pub use writer::LasWriter;
+
+use std::{fmt, fs::File, io::Read, path::Path, str::FromStr};
+
+use crate::curve::{Curve, CurveSet};
+
+/// One `MNEM.UNIT  VALUE : DESCRIPTION` line of a header section.
+///
+/// LAS 3.0 lines may end with `{format} | association`; both are split off
+/// the description into their own fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct HeaderItem {
+    pub mnemonic: String,
+    pub unit: String,
+    pub value: String,
+    pub description: String,
+    pub format: String,
+    pub association: String,
+}
+
+impl HeaderItem {
+    /// The value parsed as a number, if it is one.
+    pub fn number(&self) -> Option<f64> {
+        self.value.trim().parse().ok()
+    }
+}
+
+#[derive(Debug)]
+pub enum LasErrorKind {
+    Io(std::io::Error),
+    MissingSection(&'static str),
+    MissingDefinition(String),
+    UnknownSection(String),
+    MalformedLine(String),
+    InvalidNumber(String),
+    UnsupportedVersion(String),
+    ColumnCount { expected: usize, found: usize },
+}
+
+/// Error raised while reading a LAS file. `line` is 1-based, `0` when the
+/// error is not tied to a line (I/O errors, missing sections).
+#[derive(Debug)]
+pub struct LasError {
+    pub line: usize,
+    pub kind: LasErrorKind,
+}
+
+impl LasError {
+    pub(crate) fn new(line: usize, kind: LasErrorKind) -> Self {
+        Self { line, kind }
+    }
+}
+
+impl fmt::Display for LasErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LasErrorKind::Io(e) => write!(f, "I/O error: {}", e),
+            LasErrorKind::MissingSection(section) => write!(f, "missing section {}", section),
+            LasErrorKind::MissingDefinition(set) => {
+                write!(f, "no definition section for data set {}", set)
+            }
+            LasErrorKind::UnknownSection(section) => write!(f, "unknown section {}", section),
+            LasErrorKind::MalformedLine(line) => write!(f, "malformed header line `{}`", line),
+            LasErrorKind::InvalidNumber(token) => write!(f, "invalid number `{}`", token),
+            LasErrorKind::UnsupportedVersion(version) => {
+                write!(f, "unsupported LAS version `{}`", version)
+            }
+            LasErrorKind::ColumnCount { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl fmt::Display for LasError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "line {}: {}", self.line, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for LasError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            LasErrorKind::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for LasError {
+    fn from(e: std::io::Error) -> Self {
+        LasError::new(0, LasErrorKind::Io(e))
+    }
+}
+
+/// A LAS 3.0 data set other than the main `Log` one, e.g. `Core` or `Tops`,
+/// made of its `~<name>_Parameter`, `~<name>_Definition` and `~<name>_Data`
+/// sections.
+#[derive(Debug, Clone, Default)]
+pub struct LasDataSet {
+    pub name: String,
+    pub parameters: Vec<HeaderItem>,
+    pub definitions: Vec<HeaderItem>,
+    pub data: CurveSet,
+}
+
+/// A parsed LAS file.
+///
+/// `data` holds the `~ASCII` section (`~Log_Data` in LAS 3.0), one [`Curve`]
+/// per `~CURVE` entry. The first curve is the index and every curve carries
+/// the `NULL` value of the `~WELL` section. Array channels such as `NMR[1]`
+/// .. `NMR[64]` are grouped into one [`crate::curve::ArrayCurve`] and string
+/// columns end up in [`CurveSet::texts`].
+#[derive(Debug, Clone, Default)]
+pub struct LasFile {
+    pub version: Vec<HeaderItem>,
+    pub well: Vec<HeaderItem>,
+    pub curves: Vec<HeaderItem>,
+    pub parameters: Vec<HeaderItem>,
+    pub other: String,
+    pub data: CurveSet,
+
+    /// The remaining LAS 3.0 data sets, in file order
+    pub data_sets: Vec<LasDataSet>,
+}
+
+impl LasFile {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, LasError> {
+        Self::from_reader(File::open(path)?)
+    }
+
+    /// Read a LAS file. Bytes that are not valid UTF-8 (old files are often
+    /// Latin-1) are replaced rather than rejected.
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, LasError> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes)?;
+
+        Self::parse(&String::from_utf8_lossy(&bytes))
+    }
+
+    pub fn parse(text: &str) -> Result<Self, LasError> {
+        reader::parse(text)
+    }
+
+    pub fn version_item(&self, mnemonic: &str) -> Option<&HeaderItem> {
+        find_item(&self.version, mnemonic)
+    }
+
+    pub fn well_item(&self, mnemonic: &str) -> Option<&HeaderItem> {
+        find_item(&self.well, mnemonic)
+    }
+
+    pub fn parameter(&self, mnemonic: &str) -> Option<&HeaderItem> {
+        find_item(&self.parameters, mnemonic)
+    }
+
+    /// `VERS` of the `~VERSION` section
+    pub fn las_version(&self) -> Option<f64> {
+        self.version_item("VERS").and_then(HeaderItem::number)
+    }
+
+    /// `DLM` of the `~VERSION` section, `SPACE` when missing (LAS 2.0)
+    pub fn delimiter(&self) -> &str {
+        self.version_item("DLM")
+            .map(|item| item.value.trim())
+            .unwrap_or("SPACE")
+    }
+
+    /// `WRAP` of the `~VERSION` section
+    pub fn is_wrapped(&self) -> bool {
+        self.version_item("WRAP")
+            .map(|item| item.value.trim().eq_ignore_ascii_case("YES"))
+            .unwrap_or(false)
+    }
+
+    /// `NULL` of the `~WELL` section
+    pub fn null_value(&self) -> Option<f64> {
+        self.well_item("NULL").and_then(HeaderItem::number)
+    }
+
+    pub fn index(&self) -> &Curve {
+        &self.data.index
+    }
+
+    pub fn curve(&self, mnemonic: &str) -> Option<&Curve> {
+        self.data.curve(mnemonic)
+    }
+
+    pub fn data_set(&self, name: &str) -> Option<&LasDataSet> {
+        self.data_sets
+            .iter()
+            .find(|set| set.name.eq_ignore_ascii_case(name))
+    }
+}
+
+impl FromStr for LasFile {
+    type Err = LasError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+pub(crate) fn find_item<'i>(items: &'i [HeaderItem], mnemonic: &str) -> Option<&'i HeaderItem> {
+    items
+        .iter()
+        .find(|item| item.mnemonic.eq_ignore_ascii_case(mnemonic))
+}
@@ -0,0 +1,860 @@
+use std::collections::HashMap;
+
+use crate::curve::{ArrayCurve, Curve, CurveSet, TextCurve};
+
+use super::{HeaderItem, LasDataSet, LasError, LasErrorKind, LasFile};
+
+/// Name of the main data set; its sections map to `~CURVE`, `~PARAMETER`
+/// and `~ASCII` of LAS 2.0.
+const LOG_SET: &str = "Log";
+
+#[derive(Debug, Clone, PartialEq)]
+enum Section {
+    Version,
+    Well,
+    Other,
+    Parameter(String),
+    Definition(String),
+    Data { set: String, definition: String },
+}
+
+impl Section {
+    /// Parse a section title such as `~Curve Information`, `~A  DEPTH  GR` or,
+    /// for LAS 3.0, `~Core_Data | Core_Definition`.
+    fn from_header(line_no: usize, line: &str, v3: bool) -> Result<Self, LasError> {
+        let header = line[1..].trim();
+        let (name, association) = match header.split_once('|') {
+            Some((name, association)) => (name.trim(), association.trim()),
+            None => (header, ""),
+        };
+        let name = name.split_whitespace().next().unwrap_or("");
+
+        let unknown = || LasError::new(line_no, LasErrorKind::UnknownSection(line.to_string()));
+
+        if v3 {
+            if let Some((set, kind)) = split_set_name(name) {
+                return Ok(match kind {
+                    SetSection::Parameter => Section::Parameter(set),
+                    SetSection::Definition => Section::Definition(set),
+                    SetSection::Data => {
+                        let definition = match split_set_name(association) {
+                            Some((definition, SetSection::Definition)) => definition,
+                            _ => set.clone(),
+                        };
+                        Section::Data { set, definition }
+                    }
+                });
+            }
+
+            // legacy LAS 2.0 titles are still accepted, but only spelled out
+            let legacy = ["VERSION", "WELL", "OTHER", "CURVE", "PARAMETER", "ASCII"];
+            if name.len() > 1 && !legacy.iter().any(|l| l.eq_ignore_ascii_case(name)) {
+                return Err(unknown());
+            }
+        }
+
+        match name.chars().next().map(|c| c.to_ascii_uppercase()) {
+            Some('V') => Ok(Section::Version),
+            Some('W') => Ok(Section::Well),
+            Some('O') => Ok(Section::Other),
+            Some('C') => Ok(Section::Definition(LOG_SET.to_string())),
+            Some('P') => Ok(Section::Parameter(LOG_SET.to_string())),
+            Some('A') => Ok(Section::Data {
+                set: LOG_SET.to_string(),
+                definition: LOG_SET.to_string(),
+            }),
+            _ => Err(unknown()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SetSection {
+    Parameter,
+    Definition,
+    Data,
+}
+
+/// Split `Log_Definition[2]` into the data set name `Log[2]` and its section kind.
+fn split_set_name(name: &str) -> Option<(String, SetSection)> {
+    let (set, kind) = name.rsplit_once('_')?;
+    let (kind, run) = match kind.find('[') {
+        Some(bracket) => kind.split_at(bracket),
+        None => (kind, ""),
+    };
+
+    let kind = match kind.to_ascii_uppercase().as_str() {
+        "PARAMETER" => SetSection::Parameter,
+        "DEFINITION" => SetSection::Definition,
+        "DATA" => SetSection::Data,
+        _ => return None,
+    };
+
+    Some((format!("{}{}", set, run), kind))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Delimiter {
+    Space,
+    Comma,
+    Tab,
+}
+
+impl Delimiter {
+    fn from_dlm(dlm: &str) -> Self {
+        match dlm.to_ascii_uppercase().as_str() {
+            "COMMA" => Delimiter::Comma,
+            "TAB" => Delimiter::Tab,
+            _ => Delimiter::Space,
+        }
+    }
+
+    fn is_delimiter(self, c: char) -> bool {
+        match self {
+            Delimiter::Space => c.is_whitespace(),
+            Delimiter::Comma => c == ',',
+            Delimiter::Tab => c == '\t',
+        }
+    }
+
+    /// Split a data line; double quotes protect delimiters inside strings.
+    fn split(self, line: &str) -> Vec<String> {
+        let mut tokens = vec![];
+        let mut token = String::new();
+        let mut has_token = false;
+        let mut quoted = false;
+
+        for c in line.chars() {
+            if c == '"' {
+                quoted = !quoted;
+                has_token = true;
+            } else if !quoted && self.is_delimiter(c) {
+                // repeated spaces are one delimiter, repeated commas are empty values
+                if self != Delimiter::Space || has_token {
+                    tokens.push(token.trim().to_string());
+                }
+                token.clear();
+                has_token = false;
+            } else {
+                token.push(c);
+                has_token = true;
+            }
+        }
+
+        if has_token || (self != Delimiter::Space && !tokens.is_empty()) {
+            tokens.push(token.trim().to_string());
+        }
+
+        tokens
+    }
+}
+
+enum Cell {
+    Number(f64),
+    Text(String),
+}
+
+enum Column {
+    Number(Vec<f64>),
+    Text(Vec<String>),
+}
+
+/// Collects a data section, both in wrapped and unwrapped mode.
+struct DataReader {
+    wrapped: bool,
+    delimiter: Delimiter,
+    columns: Vec<Column>,
+    pending: Vec<Cell>,
+    pending_line: usize,
+}
+
+impl DataReader {
+    fn new(definitions: &[HeaderItem], wrapped: bool, delimiter: Delimiter) -> Self {
+        Self {
+            wrapped,
+            delimiter,
+            columns: definitions
+                .iter()
+                .map(|item| {
+                    if is_text_format(&item.format) {
+                        Column::Text(vec![])
+                    } else {
+                        Column::Number(vec![])
+                    }
+                })
+                .collect(),
+            pending: Vec::with_capacity(definitions.len()),
+            pending_line: 0,
+        }
+    }
+
+    fn push_line(&mut self, line_no: usize, line: &str) -> Result<(), LasError> {
+        let expected = self.columns.len();
+
+        if self.pending.is_empty() {
+            self.pending_line = line_no;
+        }
+
+        for token in self.delimiter.split(line) {
+            let cell = match self.columns.get(self.pending.len()) {
+                Some(Column::Text(_)) => Cell::Text(token),
+                _ if token.is_empty() => Cell::Number(f64::NAN),
+                _ => Cell::Number(parse_number(line_no, &token)?),
+            };
+            self.pending.push(cell);
+        }
+
+        if self.wrapped {
+            // a wrapped row starts on the index line and may span several lines
+            if self.pending.len() > expected {
+                return Err(LasError::new(
+                    line_no,
+                    LasErrorKind::ColumnCount {
+                        expected,
+                        found: self.pending.len(),
+                    },
+                ));
+            }
+            if self.pending.len() == expected {
+                self.flush();
+            }
+        } else {
+            if self.pending.len() != expected {
+                return Err(LasError::new(
+                    line_no,
+                    LasErrorKind::ColumnCount {
+                        expected,
+                        found: self.pending.len(),
+                    },
+                ));
+            }
+            self.flush();
+        }
+
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        for (column, cell) in self.columns.iter_mut().zip(self.pending.drain(..)) {
+            match (column, cell) {
+                (Column::Number(values), Cell::Number(value)) => values.push(value),
+                (Column::Text(values), Cell::Text(value)) => values.push(value),
+                _ => unreachable!("cells are parsed according to their column"),
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Vec<Column>, LasError> {
+        if !self.pending.is_empty() {
+            return Err(LasError::new(
+                self.pending_line,
+                LasErrorKind::ColumnCount {
+                    expected: self.columns.len(),
+                    found: self.pending.len(),
+                },
+            ));
+        }
+        Ok(self.columns)
+    }
+}
+
+pub(super) fn parse(text: &str) -> Result<LasFile, LasError> {
+    let mut las = LasFile::default();
+
+    let mut section = None;
+    let mut data: Option<(String, DataReader)> = None;
+    let mut has_log_data = false;
+
+    for (i, line) in text.lines().enumerate() {
+        let line_no = i + 1;
+        let trimmed = line.trim();
+
+        if trimmed.is_empty() || (trimmed.starts_with('#') && section != Some(Section::Other)) {
+            continue;
+        }
+
+        if trimmed.starts_with('~') {
+            if let Some((set, reader)) = data.take() {
+                store_data(&mut las, &set, reader)?;
+            }
+
+            let next = Section::from_header(line_no, trimmed, is_v3(&las))?;
+
+            if let Section::Data { set, definition } = &next {
+                check_version(&las)?;
+
+                let definitions = definitions(&las, definition);
+                if definitions.is_empty() {
+                    let kind = if definition.eq_ignore_ascii_case(LOG_SET) {
+                        LasErrorKind::MissingSection("~CURVE")
+                    } else {
+                        LasErrorKind::MissingDefinition(definition.clone())
+                    };
+                    return Err(LasError::new(line_no, kind));
+                }
+
+                let delimiter = Delimiter::from_dlm(las.delimiter());
+                let reader = DataReader::new(definitions, las.is_wrapped(), delimiter);
+
+                has_log_data |= set.eq_ignore_ascii_case(LOG_SET);
+                data = Some((set.clone(), reader));
+            }
+
+            section = Some(next);
+            continue;
+        }
+
+        match &section {
+            Some(Section::Other) => {
+                las.other.push_str(trimmed);
+                las.other.push('\n');
+            }
+            Some(Section::Data { .. }) => {
+                // the delimiter trims each cell, a leading tab is an empty cell
+                if let Some((_, reader)) = data.as_mut() {
+                    reader.push_line(line_no, line)?;
+                }
+            }
+            Some(header) => {
+                let mut item = parse_header_line(line_no, trimmed)?;
+                if is_v3(&las) {
+                    split_format_and_association(&mut item);
+                }
+
+                match header {
+                    Section::Version => las.version.push(item),
+                    Section::Well => las.well.push(item),
+                    Section::Parameter(set) => data_set_mut(&mut las, set).0.push(item),
+                    Section::Definition(set) => data_set_mut(&mut las, set).1.push(item),
+                    Section::Other | Section::Data { .. } => unreachable!(),
+                }
+            }
+            None => {
+                return Err(LasError::new(
+                    line_no,
+                    LasErrorKind::MissingSection("~VERSION"),
+                ))
+            }
+        }
+    }
+
+    if let Some((set, reader)) = data.take() {
+        store_data(&mut las, &set, reader)?;
+    }
+
+    check_version(&las)?;
+
+    if !has_log_data {
+        let columns = las.curves.iter().map(|_| Column::Number(vec![])).collect();
+        las.data = build_curve_set(&las.curves, columns, las.null_value());
+    }
+
+    Ok(las)
+}
+
+fn is_v3(las: &LasFile) -> bool {
+    las.las_version().map(|v| v >= 3.0).unwrap_or(false)
+}
+
+fn check_version(las: &LasFile) -> Result<(), LasError> {
+    let version = las
+        .version_item("VERS")
+        .ok_or_else(|| LasError::new(0, LasErrorKind::MissingSection("~VERSION")))?;
+
+    match version.number() {
+        Some(v) if v == 1.2 || v == 2.0 || v == 3.0 => Ok(()),
+        _ => Err(LasError::new(
+            0,
+            LasErrorKind::UnsupportedVersion(version.value.clone()),
+        )),
+    }
+}
+
+/// Parameter and definition lists of a data set, created on first use.
+fn data_set_mut<'l>(
+    las: &'l mut LasFile,
+    set: &str,
+) -> (&'l mut Vec<HeaderItem>, &'l mut Vec<HeaderItem>) {
+    if set.eq_ignore_ascii_case(LOG_SET) {
+        return (&mut las.parameters, &mut las.curves);
+    }
+
+    let idx = match las
+        .data_sets
+        .iter()
+        .position(|s| s.name.eq_ignore_ascii_case(set))
+    {
+        Some(idx) => idx,
+        None => {
+            las.data_sets.push(LasDataSet {
+                name: set.to_string(),
+                ..Default::default()
+            });
+            las.data_sets.len() - 1
+        }
+    };
+
+    let data_set = &mut las.data_sets[idx];
+    (&mut data_set.parameters, &mut data_set.definitions)
+}
+
+fn definitions<'l>(las: &'l LasFile, set: &str) -> &'l [HeaderItem] {
+    if set.eq_ignore_ascii_case(LOG_SET) {
+        &las.curves
+    } else {
+        las.data_set(set)
+            .map(|s| s.definitions.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+fn store_data(las: &mut LasFile, set: &str, reader: DataReader) -> Result<(), LasError> {
+    let columns = reader.finish()?;
+    let null_value = las.null_value();
+
+    if set.eq_ignore_ascii_case(LOG_SET) {
+        las.data = build_curve_set(&las.curves, columns, null_value);
+    } else {
+        data_set_mut(las, set);
+        if let Some(data_set) = las
+            .data_sets
+            .iter_mut()
+            .find(|s| s.name.eq_ignore_ascii_case(set))
+        {
+            data_set.data = build_curve_set(&data_set.definitions, columns, null_value);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether a LAS 3.0 format code (`{S}`, `{A}`, `{F10.4}`, `{DD/MM/YYYY}` ...) holds text.
+fn is_text_format(format: &str) -> bool {
+    let format = format.trim().to_ascii_uppercase();
+    if format == "A" {
+        return true;
+    }
+    let format = format.strip_prefix('A').unwrap_or(&format);
+
+    !(format.is_empty()
+        || format.starts_with('F')
+        || format.starts_with('E')
+        || format.starts_with('I'))
+}
+
+/// Split `NMR[12]` into `("NMR", 12)`.
+fn split_array_name(mnemonic: &str) -> Option<(&str, usize)> {
+    let open = mnemonic.find('[')?;
+    let index = mnemonic[open + 1..]
+        .strip_suffix(']')?
+        .trim()
+        .parse()
+        .ok()?;
+
+    Some((mnemonic[..open].trim(), index))
+}
+
+fn build_curve_set(
+    definitions: &[HeaderItem],
+    columns: Vec<Column>,
+    null_value: Option<f64>,
+) -> CurveSet {
+    let mut curve_set = CurveSet::default();
+    let mut array_elements: HashMap<usize, Vec<(usize, Vec<f64>)>> = HashMap::new();
+
+    for (i, (item, column)) in definitions.iter().zip(columns).enumerate() {
+        let values = match column {
+            Column::Number(values) => values,
+            Column::Text(values) => {
+                curve_set.texts.push(TextCurve {
+                    mnemonic: item.mnemonic.clone(),
+                    unit: item.unit.clone(),
+                    description: item.description.clone(),
+                    values,
+                });
+                continue;
+            }
+        };
+
+        let curve = Curve {
+            mnemonic: item.mnemonic.clone(),
+            unit: item.unit.clone(),
+            description: item.description.clone(),
+            values,
+            null_value,
+        };
+
+        if i == 0 {
+            curve_set.index = curve;
+            continue;
+        }
+
+        match split_array_name(&item.mnemonic) {
+            Some((name, element)) => {
+                let idx = match curve_set
+                    .arrays
+                    .iter()
+                    .position(|array| array.mnemonic == name)
+                {
+                    Some(idx) => idx,
+                    None => {
+                        curve_set.arrays.push(ArrayCurve {
+                            mnemonic: name.to_string(),
+                            unit: curve.unit.clone(),
+                            description: curve.description.clone(),
+                            values: vec![],
+                            null_value,
+                        });
+                        curve_set.arrays.len() - 1
+                    }
+                };
+                array_elements
+                    .entry(idx)
+                    .or_default()
+                    .push((element, curve.values));
+            }
+            None => curve_set.curves.push(curve),
+        }
+    }
+
+    for (idx, mut elements) in array_elements {
+        elements.sort_by_key(|(element, _)| *element);
+
+        curve_set.arrays[idx].values = (0..curve_set.index.values.len())
+            .map(|row| {
+                elements
+                    .iter()
+                    .map(|(_, values)| values.get(row).copied().unwrap_or(f64::NAN))
+                    .collect()
+            })
+            .collect();
+    }
+
+    curve_set
+}
+
+/// Split a `MNEM.UNIT  VALUE : DESCRIPTION` line.
+///
+/// The unit starts right after the first dot and ends at the first space; the
+/// description starts after the last colon, so values such as times may
+/// contain colons themselves.
+fn parse_header_line(line_no: usize, line: &str) -> Result<HeaderItem, LasError> {
+    let malformed = || LasError::new(line_no, LasErrorKind::MalformedLine(line.to_string()));
+
+    let dot = line.find('.').ok_or_else(malformed)?;
+    let mnemonic = line[..dot].trim();
+    let rest = &line[dot + 1..];
+
+    let unit_end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let (unit, rest) = rest.split_at(unit_end);
+
+    // the unit may run straight into the description when the value is empty
+    let (unit, rest) = match (unit.find(':'), rest.contains(':')) {
+        (Some(colon), false) => (&unit[..colon], &line[dot + 1 + colon..]),
+        _ => (unit, rest),
+    };
+
+    let colon = rest.rfind(':').ok_or_else(malformed)?;
+
+    if mnemonic.is_empty() {
+        return Err(malformed());
+    }
+
+    Ok(HeaderItem {
+        mnemonic: mnemonic.to_string(),
+        unit: unit.to_string(),
+        value: rest[..colon].trim().to_string(),
+        description: rest[colon + 1..].trim().to_string(),
+        ..Default::default()
+    })
+}
+
+/// Move the LAS 3.0 `{format}` and `| association` suffixes out of the description.
+fn split_format_and_association(item: &mut HeaderItem) {
+    if let Some((description, association)) = item.description.rsplit_once('|') {
+        item.association = association.trim().to_string();
+        item.description = description.trim().to_string();
+    }
+
+    if item.description.ends_with('}') {
+        if let Some(open) = item.description.rfind('{') {
+            item.format = item.description[open + 1..item.description.len() - 1]
+                .trim()
+                .to_string();
+            item.description = item.description[..open].trim().to_string();
+        }
+    }
+}
+
+fn parse_number(line_no: usize, token: &str) -> Result<f64, LasError> {
+    token
+        .parse()
+        .map_err(|_| LasError::new(line_no, LasErrorKind::InvalidNumber(token.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::las::{LasErrorKind, LasFile};
+
+    const HEADER: &str = "\
+~VERSION INFORMATION
+ VERS.   2.0 : CWLS LOG ASCII STANDARD - VERSION 2.0
+ WRAP.   NO  : ONE LINE PER DEPTH STEP
+~WELL INFORMATION
+ STRT.M  1000.0 : START DEPTH
+ STOP.M  1001.0 : STOP DEPTH
+ STEP.M     0.5 : STEP
+ NULL.  -999.25 : NULL VALUE
+ WELL.  EXAMPLE-1 : WELL
+~CURVE INFORMATION
+ DEPT.M      : DEPTH
+ GR  .GAPI   : GAMMA RAY
+ DT  .US/F   : SONIC
+";
+
+    #[test]
+    fn reads_unwrapped_data() {
+        let text = format!("{HEADER}~A\n1000.0 45.0 80.0\n1000.5 50.0 82.5\n1001.0 55.0 85.0\n");
+        let las = LasFile::parse(&text).unwrap();
+
+        assert_eq!(las.las_version(), Some(2.0));
+        assert_eq!(las.well_item("WELL").unwrap().value, "EXAMPLE-1");
+        assert_eq!(las.index().mnemonic, "DEPT");
+        assert_eq!(las.index().values, vec![1000.0, 1000.5, 1001.0]);
+        assert_eq!(las.curve("gr").unwrap().unit, "GAPI");
+        assert_eq!(las.curve("DT").unwrap().values, vec![80.0, 82.5, 85.0]);
+    }
+
+    #[test]
+    fn reads_wrapped_data() {
+        let text = format!("{HEADER}~A\n1000.0\n 45.0\n 80.0\n1000.5\n 50.0 82.5\n")
+            .replace("WRAP.   NO ", "WRAP.   YES");
+        let las = LasFile::parse(&text).unwrap();
+
+        assert!(las.is_wrapped());
+        assert_eq!(las.index().values, vec![1000.0, 1000.5]);
+        assert_eq!(las.curve("GR").unwrap().values, vec![45.0, 50.0]);
+        assert_eq!(las.curve("DT").unwrap().values, vec![80.0, 82.5]);
+    }
+
+    #[test]
+    fn wrapped_row_cut_short_reports_its_first_line() {
+        let text = format!("{HEADER}~A\n1000.0\n 45.0 80.0\n1000.5\n 50.0\n")
+            .replace("WRAP.   NO ", "WRAP.   YES");
+        let error = LasFile::parse(&text).unwrap_err();
+
+        assert_eq!(error.line, 17);
+        assert!(matches!(
+            error.kind,
+            LasErrorKind::ColumnCount {
+                expected: 3,
+                found: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn keeps_other_section_as_text() {
+        let text = format!(
+            "{HEADER}~OTHER\n  Logged after casing.\n# kept as well\n~A\n1000.0 45.0 80.0\n"
+        );
+        let las = LasFile::parse(&text).unwrap();
+
+        assert_eq!(las.other, "Logged after casing.\n# kept as well\n");
+        assert_eq!(las.index().values, vec![1000.0]);
+    }
+
+    #[test]
+    fn keeps_null_samples_with_the_null_value() {
+        let text = format!("{HEADER}~A\n1000.0 -999.25 80.0\n1000.5 50.0 -999.25\n");
+        let las = LasFile::parse(&text).unwrap();
+        let gr = las.curve("GR").unwrap();
+
+        assert_eq!(las.null_value(), Some(-999.25));
+        assert_eq!(gr.null_value, Some(-999.25));
+        assert_eq!(gr.values, vec![-999.25, 50.0]);
+        assert!(gr.is_null(gr.values[0]));
+        assert!(!gr.is_null(gr.values[1]));
+        assert!(las.curve("DT").unwrap().is_null(-999.25));
+    }
+
+    #[test]
+    fn reports_the_line_of_a_malformed_data_row() {
+        let text = format!("{HEADER}~A\n1000.0 45.0 80.0\n1000.5 50.0\n");
+        let error = LasFile::parse(&text).unwrap_err();
+
+        assert_eq!(error.line, 16);
+        assert!(matches!(
+            error.kind,
+            LasErrorKind::ColumnCount {
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert_eq!(error.to_string(), "line 16: expected 3 values, found 2");
+
+        let text = format!("{HEADER}~A\n1000.0 45.0 80.0\n1000.5 5O.0 82.5\n");
+        let error = LasFile::parse(&text).unwrap_err();
+
+        assert_eq!(error.line, 16);
+        assert!(matches!(error.kind, LasErrorKind::InvalidNumber(ref token) if token == "5O.0"));
+    }
+
+    const V3: &str = "\
+~Version
+ VERS.  3.0   : CWLS LOG ASCII STANDARD - VERSION 3.0
+ WRAP.  NO    : ONE LINE PER DEPTH STEP
+ DLM .  COMMA : DELIMITER
+~Well
+ NULL.  -999.25 : NULL VALUE
+~Log_Definition
+ DEPT  .M    : DEPTH {F}
+ GR    .GAPI : GAMMA RAY {F}
+ LITH  .     : LITHOLOGY {A}
+ NMR[1].MS   : T2 BIN 1 {F}
+ NMR[2].MS   : T2 BIN 2 {F}
+~Log_Data | Log_Definition
+1000.0,45.0,\"sand, fine\",1.0,2.0
+1000.5,,shale,3.0,-999.25
+~Core_Parameter
+ RUN.  1 : CORE RUN
+~Core_Definition
+ CTOP.M : CORE TOP {F}
+ CDES.  : DESCRIPTION {S}
+~Core_Data | Core_Definition
+1000.2,oil show
+1000.8,\"tight, no show\"
+";
+
+    #[test]
+    fn reads_las3_data_sets() {
+        let las = LasFile::parse(V3).unwrap();
+
+        let core = las.data_set("core").unwrap();
+        assert_eq!(core.parameters[0].mnemonic, "RUN");
+        assert_eq!(core.definitions[1].format, "S");
+        assert_eq!(core.data.index.mnemonic, "CTOP");
+        assert_eq!(core.data.index.values, vec![1000.2, 1000.8]);
+        assert_eq!(
+            core.data.text("CDES").unwrap().values,
+            vec!["oil show", "tight, no show"]
+        );
+
+        // the main data set is unaffected by the others
+        assert_eq!(las.index().values, vec![1000.0, 1000.5]);
+    }
+
+    #[test]
+    fn reads_las3_string_and_array_curves() {
+        let las = LasFile::parse(V3).unwrap();
+
+        assert_eq!(las.curves[1].format, "F");
+        assert_eq!(las.curves[1].description, "GAMMA RAY");
+
+        let gr = las.curve("GR").unwrap();
+        assert_eq!(gr.values[0], 45.0);
+        assert!(
+            gr.values[1].is_nan(),
+            "an empty value between commas is missing"
+        );
+
+        assert_eq!(
+            las.data.text("LITH").unwrap().values,
+            vec!["sand, fine", "shale"]
+        );
+        assert!(las.curve("LITH").is_none());
+
+        let nmr = las.data.array("NMR").unwrap();
+        assert_eq!(nmr.unit, "MS");
+        assert_eq!(nmr.width(), 2);
+        assert_eq!(nmr.values[0], vec![1.0, 2.0]);
+        assert_eq!(nmr.values[1], vec![3.0, -999.25]);
+        assert_eq!(nmr.null_value, Some(-999.25));
+    }
+
+    #[test]
+    fn splits_data_on_the_declared_delimiter() {
+        let tab = V3
+            .replace("DLM .  COMMA", "DLM .  TAB")
+            .replace(
+                "1000.0,45.0,\"sand, fine\",1.0,2.0",
+                "1000.0\t45.0\tsand, fine\t1.0\t2.0",
+            )
+            .replace("1000.5,,shale,3.0,-999.25", "1000.5\t\tshale\t3.0\t-999.25")
+            .replace("1000.2,oil show", "1000.2\toil show")
+            .replace("1000.8,\"tight, no show\"", "1000.8\ttight, no show");
+        let las = LasFile::parse(&tab).unwrap();
+
+        assert_eq!(las.delimiter(), "TAB");
+        assert!(las.curve("GR").unwrap().values[1].is_nan());
+        assert_eq!(
+            las.data.text("LITH").unwrap().values,
+            vec!["sand, fine", "shale"]
+        );
+
+        let space = V3
+            .replace(" DLM .  COMMA : DELIMITER\n", "")
+            .replace(
+                "1000.0,45.0,\"sand, fine\",1.0,2.0",
+                "1000.0  45.0 \"sand, fine\" 1.0 2.0",
+            )
+            .replace(
+                "1000.5,,shale,3.0,-999.25",
+                "1000.5 -999.25   shale 3.0 -999.25",
+            )
+            .replace("1000.2,oil show", "1000.2 \"oil show\"")
+            .replace("1000.8,\"tight, no show\"", "1000.8 \"tight, no show\"");
+        let las = LasFile::parse(&space).unwrap();
+
+        assert_eq!(las.delimiter(), "SPACE");
+        assert_eq!(las.curve("GR").unwrap().values, vec![45.0, -999.25]);
+        assert_eq!(
+            las.data.text("LITH").unwrap().values,
+            vec!["sand, fine", "shale"]
+        );
+        assert_eq!(las.data.array("NMR").unwrap().values[1], vec![3.0, -999.25]);
+    }
+
+    #[test]
+    fn keeps_a_leading_empty_tab_cell() {
+        let tab = V3
+            .replace("DLM .  COMMA", "DLM .  TAB")
+            .replace(
+                "1000.0,45.0,\"sand, fine\",1.0,2.0",
+                "1000.0\t45.0\tsand\t1.0\t2.0",
+            )
+            .replace(
+                "1000.5,,shale,3.0,-999.25",
+                "1000.5\t50.0\tshale\t3.0\t4.0 ",
+            )
+            .replace("1000.2,oil show", "\toil show")
+            .replace("1000.8,\"tight, no show\"", "1000.8\ttight");
+        let las = LasFile::parse(&tab).unwrap();
+
+        let core = las.data_set("core").unwrap();
+        assert!(core.data.index.values[0].is_nan());
+        assert_eq!(core.data.index.values[1], 1000.8);
+        assert_eq!(
+            core.data.text("CDES").unwrap().values,
+            vec!["oil show", "tight"]
+        );
+
+        // trailing spaces are still trimmed from the last cell
+        assert_eq!(las.data.array("NMR").unwrap().values[1], vec![3.0, 4.0]);
+    }
+
+    #[test]
+    fn tells_text_formats_from_numeric_ones() {
+        assert!(super::is_text_format("A"));
+        assert!(super::is_text_format(" a "));
+        assert!(super::is_text_format("S"));
+        assert!(super::is_text_format("DD/MM/YYYY"));
+
+        assert!(!super::is_text_format(""));
+        assert!(!super::is_text_format("F"));
+        assert!(!super::is_text_format("F10.4"));
+        assert!(!super::is_text_format("E0.00E+00"));
+        assert!(!super::is_text_format("I4"));
+        assert!(!super::is_text_format("AF10.4"));
+    }
+}
@@ -0,0 +1,458 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use crate::curve::{Curve, CurveSet};
+
+use super::{HeaderItem, LasFile};
+
+/// `~WELL` items computed from the data, user items with these mnemonics are ignored.
+const COMPUTED_WELL_ITEMS: [&str; 4] = ["STRT", "STOP", "STEP", "NULL"];
+
+/// Writes a [`CurveSet`] as an unwrapped LAS 2.0 file.
+///
+/// `STRT`, `STOP`, `STEP` and `NULL` are computed from the data; array curves
+/// are written as one column per element (`NMR[1]`, `NMR[2]` ...) and text
+/// curves are left out since LAS 2.0 data is numeric only.
+///
+/// ```ignore
+/// LasWriter::new()
+///     .well_item("WELL", "", "A-12", "WELL")
+///     .null_value(-999.25)
+///     .step(0.1524)
+///     .precision(3)
+///     .save("out.las", &curves)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct LasWriter {
+    well: Vec<HeaderItem>,
+    parameters: Vec<HeaderItem>,
+    null_value: f64,
+    step: Option<f64>,
+    precision: usize,
+}
+
+impl Default for LasWriter {
+    fn default() -> Self {
+        Self {
+            well: vec![],
+            parameters: vec![],
+            null_value: -999.25,
+            step: None,
+            precision: 4,
+        }
+    }
+}
+
+impl LasWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writer keeping the `~WELL` and `~PARAMETER` items and the null value of `las`
+    pub fn from_las(las: &LasFile) -> Self {
+        let mut writer = Self::new();
+        writer.well_items(&las.well);
+        writer.parameters.extend(las.parameters.iter().cloned());
+        if let Some(null_value) = las.null_value() {
+            writer.null_value(null_value);
+        }
+        writer
+    }
+
+    pub fn well_item<M, U, V, D>(
+        &mut self,
+        mnemonic: M,
+        unit: U,
+        value: V,
+        description: D,
+    ) -> &mut Self
+    where
+        M: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+        D: Into<String>,
+    {
+        self.well.push(HeaderItem {
+            mnemonic: mnemonic.into(),
+            unit: unit.into(),
+            value: value.into(),
+            description: description.into(),
+            ..Default::default()
+        });
+        self
+    }
+
+    pub fn well_items(&mut self, items: &[HeaderItem]) -> &mut Self {
+        self.well.extend(items.iter().cloned());
+        self
+    }
+
+    pub fn parameter<M, U, V, D>(
+        &mut self,
+        mnemonic: M,
+        unit: U,
+        value: V,
+        description: D,
+    ) -> &mut Self
+    where
+        M: Into<String>,
+        U: Into<String>,
+        V: Into<String>,
+        D: Into<String>,
+    {
+        self.parameters.push(HeaderItem {
+            mnemonic: mnemonic.into(),
+            unit: unit.into(),
+            value: value.into(),
+            description: description.into(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Value written for null and `NaN` samples, `-999.25` by default
+    pub fn null_value(&mut self, null_value: f64) -> &mut Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// Resample the curves to a regular index `step` apart before writing.
+    /// Without it the samples are written as they are.
+    pub fn step(&mut self, step: f64) -> &mut Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Number of decimals of the data values. The index, `STRT`, `STOP` and
+    /// `STEP` are written in full, rounding them could merge depths.
+    pub fn precision(&mut self, precision: usize) -> &mut Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P, data: &CurveSet) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer, data)?;
+        writer.flush()
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W, data: &CurveSet) -> io::Result<()> {
+        let resampled;
+        let data = match self.step {
+            Some(step) => {
+                resampled = data.resample(step);
+                &resampled
+            }
+            None => data,
+        };
+
+        let mut columns: Vec<Curve> = vec![data.index.clone()];
+        columns.extend(data.curves.iter().cloned());
+        for array in data.arrays.iter() {
+            columns.extend((0..array.width()).filter_map(|i| array.element(i)));
+        }
+
+        let index = &data.index;
+        let index_value = |value: Option<&f64>| {
+            value
+                .map(|value| self.format_index(index, *value))
+                .unwrap_or_else(|| self.null_value.to_string())
+        };
+        let step = match self.step {
+            Some(step) => step.abs() * direction(&index.values),
+            None => regular_step(&index.values).unwrap_or(0.0),
+        };
+
+        writeln!(writer, "~VERSION INFORMATION")?;
+        write_section(
+            &mut writer,
+            &[
+                item("VERS", "", "2.0", "CWLS LOG ASCII STANDARD - VERSION 2.0"),
+                item("WRAP", "", "NO", "ONE LINE PER DEPTH STEP"),
+            ],
+        )?;
+
+        let mut well = vec![
+            item(
+                "STRT",
+                &index.unit,
+                &index_value(index.values.first()),
+                "START DEPTH",
+            ),
+            item(
+                "STOP",
+                &index.unit,
+                &index_value(index.values.last()),
+                "STOP DEPTH",
+            ),
+            item("STEP", &index.unit, &full_precision(step), "STEP"),
+            item("NULL", "", &self.null_value.to_string(), "NULL VALUE"),
+        ];
+        well.extend(
+            self.well
+                .iter()
+                .filter(|item| {
+                    !COMPUTED_WELL_ITEMS
+                        .iter()
+                        .any(|computed| item.mnemonic.eq_ignore_ascii_case(computed))
+                })
+                .cloned(),
+        );
+        writeln!(writer, "~WELL INFORMATION")?;
+        write_section(&mut writer, &well)?;
+
+        let curves: Vec<HeaderItem> = columns
+            .iter()
+            .map(|curve| item(&curve.mnemonic, &curve.unit, "", &curve.description))
+            .collect();
+        writeln!(writer, "~CURVE INFORMATION")?;
+        write_section(&mut writer, &curves)?;
+
+        if !self.parameters.is_empty() {
+            writeln!(writer, "~PARAMETER INFORMATION")?;
+            write_section(&mut writer, &self.parameters)?;
+        }
+
+        let rows: Vec<Vec<String>> = (0..data.len())
+            .map(|row| {
+                columns
+                    .iter()
+                    .enumerate()
+                    .map(|(i, curve)| match curve.values.get(row) {
+                        Some(value) if i == 0 => self.format_index(curve, *value),
+                        Some(value) => self.format_value(curve, *value),
+                        None => self.null_value.to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let widths: Vec<usize> = columns
+            .iter()
+            .enumerate()
+            .map(|(i, curve)| {
+                rows.iter()
+                    .map(|row| row[i].len())
+                    .chain(std::iter::once(curve.mnemonic.len()))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        write!(writer, "~A")?;
+        for (curve, width) in columns.iter().zip(widths.iter()) {
+            write!(writer, " {:>width$}", curve.mnemonic, width = width)?;
+        }
+        writeln!(writer)?;
+
+        for row in rows {
+            write!(writer, "  ")?;
+            for (value, width) in row.iter().zip(widths.iter()) {
+                write!(writer, " {:>width$}", value, width = width)?;
+            }
+            writeln!(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn format_value(&self, curve: &Curve, value: f64) -> String {
+        if curve.is_null(value) {
+            self.null_value.to_string()
+        } else {
+            format!("{:.*}", self.precision, value)
+        }
+    }
+
+    fn format_index(&self, index: &Curve, value: f64) -> String {
+        if index.is_null(value) {
+            self.null_value.to_string()
+        } else {
+            full_precision(value)
+        }
+    }
+}
+
+fn item(mnemonic: &str, unit: &str, value: &str, description: &str) -> HeaderItem {
+    HeaderItem {
+        mnemonic: mnemonic.to_string(),
+        unit: unit.to_string(),
+        value: value.to_string(),
+        description: description.to_string(),
+        ..Default::default()
+    }
+}
+
+/// Write `MNEM.UNIT  VALUE : DESCRIPTION` lines with aligned columns.
+fn write_section<W: Write>(writer: &mut W, items: &[HeaderItem]) -> io::Result<()> {
+    let mnemonic_width = items
+        .iter()
+        .map(|item| item.mnemonic.len())
+        .max()
+        .unwrap_or(0);
+    let unit_width = items.iter().map(|item| item.unit.len()).max().unwrap_or(0);
+    let value_width = items.iter().map(|item| item.value.len()).max().unwrap_or(0);
+
+    for item in items {
+        let line = format!(
+            " {:<mw$}.{:<uw$}  {:<vw$} : {}",
+            item.mnemonic,
+            item.unit,
+            item.value,
+            item.description,
+            mw = mnemonic_width,
+            uw = unit_width,
+            vw = value_width,
+        );
+        writeln!(writer, "{}", line.trim_end())?;
+    }
+
+    Ok(())
+}
+
+/// `value` without its trailing zeros, rounded to 10 decimals so the
+/// `first + i × step` depths of a resampled index keep their short form
+fn full_precision(value: f64) -> String {
+    let text = format!("{:.10}", value);
+    let text = text.trim_end_matches('0');
+    match text.strip_suffix('.') {
+        Some(integer) => format!("{integer}.0"),
+        None => text.to_string(),
+    }
+}
+
+/// `-1.0` for a decreasing index, `1.0` otherwise
+fn direction(index: &[f64]) -> f64 {
+    match (index.first(), index.last()) {
+        (Some(first), Some(last)) if last < first => -1.0,
+        _ => 1.0,
+    }
+}
+
+/// The spacing of `index` when all samples are equally spaced
+fn regular_step(index: &[f64]) -> Option<f64> {
+    let step = index.get(1)? - index.first()?;
+    let tolerance = step.abs() * 1e-6;
+
+    index
+        .windows(2)
+        .all(|pair| (pair[1] - pair[0] - step).abs() <= tolerance)
+        .then_some(step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::ArrayCurve;
+
+    fn curves() -> CurveSet {
+        let mut gr = Curve::new("GR", "GAPI");
+        gr.description("GAMMA RAY").null_value(Some(-1.0));
+        gr.values = vec![45.0, -1.0, f64::NAN, 55.125];
+
+        let mut index = Curve::new("DEPT", "M");
+        index.values = vec![1000.0, 1000.5, 1001.0, 1001.5];
+
+        CurveSet {
+            index,
+            curves: vec![gr],
+            arrays: vec![ArrayCurve {
+                mnemonic: "NMR".to_string(),
+                unit: "V/V".to_string(),
+                values: vec![vec![0.1, 0.2]; 4],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    fn write(writer: &LasWriter, data: &CurveSet) -> String {
+        let mut bytes = vec![];
+        writer.write(&mut bytes, data).unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn round_trips_through_the_reader() {
+        let text = write(
+            LasWriter::new()
+                .well_item("WELL", "", "EXAMPLE-1", "WELL")
+                .well_item("NULL", "", "-1", "ignored, computed")
+                .parameter("BHT", "DEGC", "85", "BOTTOM HOLE TEMPERATURE"),
+            &curves(),
+        );
+        let las = LasFile::parse(&text).unwrap();
+
+        assert_eq!(las.las_version(), Some(2.0));
+        assert!(!las.is_wrapped());
+        assert_eq!(las.null_value(), Some(-999.25));
+        assert_eq!(las.well_item("WELL").unwrap().value, "EXAMPLE-1");
+        assert_eq!(las.well_item("STRT").unwrap().number(), Some(1000.0));
+        assert_eq!(las.well_item("STOP").unwrap().number(), Some(1001.5));
+        assert_eq!(las.well_item("STEP").unwrap().number(), Some(0.5));
+        assert_eq!(las.parameter("BHT").unwrap().unit, "DEGC");
+
+        assert_eq!(las.index().mnemonic, "DEPT");
+        assert_eq!(las.index().values, vec![1000.0, 1000.5, 1001.0, 1001.5]);
+
+        // null and NaN samples both come back as the written null value
+        let gr = las.curve("GR").unwrap();
+        assert_eq!(gr.unit, "GAPI");
+        assert_eq!(gr.description, "GAMMA RAY");
+        assert_eq!(gr.values, vec![45.0, -999.25, -999.25, 55.125]);
+        assert!(gr.is_null(gr.values[1]));
+
+        let nmr = las.data.array("NMR").unwrap();
+        assert_eq!(nmr.width(), 2);
+        assert_eq!(nmr.values[3], vec![0.1, 0.2]);
+    }
+
+    #[test]
+    fn writes_values_with_the_given_precision() {
+        let text = write(LasWriter::new().precision(2).null_value(-9999.0), &curves());
+        let las = LasFile::parse(&text).unwrap();
+        assert_eq!(las.well_item("STEP").unwrap().value, "0.5");
+        assert_eq!(las.well_item("NULL").unwrap().value, "-9999");
+
+        let rows: Vec<Vec<&str>> = text
+            .lines()
+            .skip_while(|line| !line.starts_with("~A"))
+            .skip(1)
+            .map(|line| line.split_whitespace().collect())
+            .collect();
+        assert_eq!(rows[0], ["1000.0", "45.00", "0.10", "0.20"]);
+        assert_eq!(rows[1][1], "-9999");
+        assert_eq!(rows[3][1], "55.12");
+    }
+
+    #[test]
+    fn resamples_onto_the_given_step() {
+        let text = write(LasWriter::new().step(0.25).precision(3), &curves());
+        let las = LasFile::parse(&text).unwrap();
+
+        assert_eq!(las.well_item("STEP").unwrap().number(), Some(0.25));
+        assert_eq!(las.index().len(), 7);
+        assert_eq!(las.curve("GR").unwrap().values[..2], [45.0, -999.25]);
+    }
+
+    #[test]
+    fn writes_the_index_in_full_whatever_the_precision() {
+        let text = write(LasWriter::new().step(0.1524).precision(0), &curves());
+        let las = LasFile::parse(&text).unwrap();
+
+        assert_eq!(las.well_item("STRT").unwrap().value, "1000.0");
+        assert_eq!(las.well_item("STOP").unwrap().value, "1001.3716");
+        assert_eq!(las.well_item("STEP").unwrap().value, "0.1524");
+        assert_eq!(
+            las.index().values,
+            vec![
+                1000.0, 1000.1524, 1000.3048, 1000.4572, 1000.6096, 1000.762, 1000.9144, 1001.0668,
+                1001.2192, 1001.3716
+            ]
+        );
+        assert_eq!(las.curve("GR").unwrap().values[0], 45.0);
+    }
+}
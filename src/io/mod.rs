@@ -0,0 +1,9 @@
+//! Readers and writers for well log exchange formats.
+//!
+//! Every reader produces a [`crate::curve::CurveSet`] so that the loaded
+//! curves can be handed to a channel without any further conversion.
+
+pub mod csv;
+pub mod dlis;
+pub mod las;
+pub mod witsml;
@@ -0,0 +1,672 @@
+//! WITSML `log` import from XML files exported from a store.
+//!
+//! Both the 1.4.1 layout (`logCurveInfo` + `logData`) and the 2.0 layout
+//! (`ChannelSet` with `Channel`s and a JSON-like `Data` block) are
+//! understood. Element names are matched case-insensitively and without
+//! their namespace. Time indexes are converted to seconds since the Unix
+//! epoch so depth- and time-indexed logs share the same curve model.
+
+use std::{fmt, fs, path::Path};
+
+use roxmltree::{Document, Node};
+
+use crate::curve::{Curve, CurveSet, TextCurve};
+
+#[derive(Debug)]
+pub enum WitsmlErrorKind {
+    Io(std::io::Error),
+    Xml(roxmltree::Error),
+    MissingElement(&'static str),
+    InvalidValue(String),
+    ColumnCount { expected: usize, found: usize },
+}
+
+/// Error raised while importing a WITSML file. `line` is the 1-based line
+/// of the offending element, `0` when not tied to one.
+#[derive(Debug)]
+pub struct WitsmlError {
+    pub line: usize,
+    pub kind: WitsmlErrorKind,
+}
+
+impl WitsmlError {
+    fn at(node: &Node, kind: WitsmlErrorKind) -> Self {
+        let line = node.document().text_pos_at(node.range().start).row as usize;
+        Self { line, kind }
+    }
+}
+
+impl fmt::Display for WitsmlErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WitsmlErrorKind::Io(e) => write!(f, "I/O error: {}", e),
+            WitsmlErrorKind::Xml(e) => write!(f, "XML error: {}", e),
+            WitsmlErrorKind::MissingElement(name) => write!(f, "missing element <{}>", name),
+            WitsmlErrorKind::InvalidValue(value) => write!(f, "invalid value `{}`", value),
+            WitsmlErrorKind::ColumnCount { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+        }
+    }
+}
+
+impl fmt::Display for WitsmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.kind)
+        } else {
+            write!(f, "line {}: {}", self.line, self.kind)
+        }
+    }
+}
+
+impl std::error::Error for WitsmlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            WitsmlErrorKind::Io(e) => Some(e),
+            WitsmlErrorKind::Xml(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for WitsmlError {
+    fn from(e: std::io::Error) -> Self {
+        Self {
+            line: 0,
+            kind: WitsmlErrorKind::Io(e),
+        }
+    }
+}
+
+impl From<roxmltree::Error> for WitsmlError {
+    fn from(e: roxmltree::Error) -> Self {
+        Self {
+            line: e.pos().row as usize,
+            kind: WitsmlErrorKind::Xml(e),
+        }
+    }
+}
+
+/// `logCurveInfo` (1.4.1) or `Channel` (2.0) of a log.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LogCurveInfo {
+    pub mnemonic: String,
+    pub unit: String,
+    pub description: String,
+    pub null_value: Option<f64>,
+    /// `typeLogData`: `double`, `long`, `string`, `date time` ...
+    pub data_type: String,
+}
+
+impl LogCurveInfo {
+    fn is_text(&self) -> bool {
+        matches!(
+            self.data_type.to_ascii_lowercase().as_str(),
+            "string" | "date time" | "datetime"
+        )
+    }
+}
+
+/// A WITSML `log` object.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WitsmlLog {
+    pub uid: String,
+    pub name: String,
+    pub name_well: String,
+    pub name_wellbore: String,
+    /// `measured depth`, `date time`, `elapsed time` ...
+    pub index_type: String,
+    pub index_curve: String,
+    pub direction: String,
+    pub curve_info: Vec<LogCurveInfo>,
+    pub data: CurveSet,
+}
+
+impl WitsmlLog {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Vec<Self>, WitsmlError> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+
+    /// Read every `log` object of a document; the root may be a `logs`
+    /// container or a single `log`.
+    pub fn parse(xml: &str) -> Result<Vec<Self>, WitsmlError> {
+        let document = Document::parse(xml)?;
+
+        document
+            .descendants()
+            .filter(|node| is(node, "log"))
+            .map(|node| Self::from_node(&node))
+            .collect()
+    }
+
+    /// Whether the index is a date time, in which case it holds seconds
+    /// since the Unix epoch.
+    pub fn is_time_indexed(&self) -> bool {
+        self.index_type.eq_ignore_ascii_case("date time")
+    }
+
+    fn from_node(log: &Node) -> Result<Self, WitsmlError> {
+        let mut witsml_log = WitsmlLog {
+            uid: log
+                .attribute("uid")
+                .or_else(|| log.attribute("uuid"))
+                .unwrap_or_default()
+                .to_string(),
+            name: child_text(log, "name")
+                .or_else(|| child(log, "Citation").and_then(|c| child_text(&c, "Title")))
+                .unwrap_or_default(),
+            name_well: child_text(log, "nameWell").unwrap_or_default(),
+            name_wellbore: child_text(log, "nameWellbore").unwrap_or_default(),
+            ..Default::default()
+        };
+
+        match child(log, "ChannelSet") {
+            Some(channel_set) => witsml_log.read_channel_set(&channel_set)?,
+            None => witsml_log.read_log_data(log)?,
+        }
+
+        Ok(witsml_log)
+    }
+
+    /// WITSML 1.x: `logCurveInfo` elements and `logData/data` rows.
+    fn read_log_data(&mut self, log: &Node) -> Result<(), WitsmlError> {
+        self.index_type = child_text(log, "indexType").unwrap_or_default();
+        self.index_curve = child_text(log, "indexCurve").unwrap_or_default();
+        self.direction = child_text(log, "direction").unwrap_or_default();
+
+        let null_value = child_text(log, "nullValue").and_then(|v| v.parse().ok());
+
+        self.curve_info = log
+            .children()
+            .filter(|node| is(node, "logCurveInfo"))
+            .map(|node| LogCurveInfo {
+                mnemonic: child_text(&node, "mnemonic").unwrap_or_default(),
+                unit: child_text(&node, "unit").unwrap_or_default(),
+                description: child_text(&node, "curveDescription").unwrap_or_default(),
+                null_value: child_text(&node, "nullValue")
+                    .and_then(|v| v.parse().ok())
+                    .or(null_value),
+                data_type: child_text(&node, "typeLogData").unwrap_or_default(),
+            })
+            .collect();
+
+        let log_data = child(log, "logData")
+            .ok_or_else(|| WitsmlError::at(log, WitsmlErrorKind::MissingElement("logData")))?;
+
+        // the mnemonic list gives the column order, logCurveInfo order otherwise
+        let columns: Vec<LogCurveInfo> = match child_text(&log_data, "mnemonicList") {
+            Some(list) => list
+                .split(',')
+                .map(|mnemonic| {
+                    let mnemonic = mnemonic.trim();
+                    self.info(mnemonic).cloned().unwrap_or(LogCurveInfo {
+                        mnemonic: mnemonic.to_string(),
+                        null_value,
+                        ..Default::default()
+                    })
+                })
+                .collect(),
+            None => self.curve_info.clone(),
+        };
+
+        let rows = log_data
+            .children()
+            .filter(|node| is(node, "data"))
+            .map(|node| {
+                let text = node.text().unwrap_or_default();
+                let cells = text
+                    .split(',')
+                    .map(|cell| cell.trim().to_string())
+                    .collect();
+                (node, cells)
+            });
+
+        self.data = self.build_curve_set(columns, rows)?;
+
+        Ok(())
+    }
+
+    /// WITSML 2.0: `ChannelSet` with `Index`, `Channel` and `Data/Data`.
+    fn read_channel_set(&mut self, channel_set: &Node) -> Result<(), WitsmlError> {
+        let indexes: Vec<Node> = channel_set
+            .children()
+            .filter(|node| is(node, "Index"))
+            .collect();
+
+        if let Some(index) = indexes.first() {
+            self.index_type = child_text(index, "IndexType").unwrap_or_default();
+            self.index_curve = child_text(index, "Mnemonic").unwrap_or_default();
+            self.direction = child_text(index, "Direction").unwrap_or_default();
+        }
+
+        let mut columns: Vec<LogCurveInfo> = indexes
+            .iter()
+            .map(|index| LogCurveInfo {
+                mnemonic: child_text(index, "Mnemonic").unwrap_or_default(),
+                unit: child_text(index, "Uom").unwrap_or_default(),
+                ..Default::default()
+            })
+            .collect();
+
+        columns.extend(
+            channel_set
+                .children()
+                .filter(|node| is(node, "Channel"))
+                .map(|node| {
+                    let citation = child(&node, "Citation");
+                    LogCurveInfo {
+                        mnemonic: child_text(&node, "Mnemonic").unwrap_or_default(),
+                        unit: child_text(&node, "Uom").unwrap_or_default(),
+                        description: citation
+                            .and_then(|c| child_text(&c, "Description"))
+                            .unwrap_or_default(),
+                        null_value: None,
+                        data_type: child_text(&node, "DataType").unwrap_or_default(),
+                    }
+                }),
+        );
+
+        self.curve_info = columns.clone();
+
+        let data = child(channel_set, "Data")
+            .map(|data| child(&data, "Data").unwrap_or(data))
+            .ok_or_else(|| WitsmlError::at(channel_set, WitsmlErrorKind::MissingElement("Data")))?;
+
+        let rows = split_json_rows(data.text().unwrap_or_default())
+            .into_iter()
+            .map(|cells| (data, cells));
+
+        self.data = self.build_curve_set(columns, rows)?;
+
+        Ok(())
+    }
+
+    fn info(&self, mnemonic: &str) -> Option<&LogCurveInfo> {
+        self.curve_info
+            .iter()
+            .find(|info| info.mnemonic.eq_ignore_ascii_case(mnemonic))
+    }
+
+    fn build_curve_set<'a, 'i: 'a, I>(
+        &self,
+        columns: Vec<LogCurveInfo>,
+        rows: I,
+    ) -> Result<CurveSet, WitsmlError>
+    where
+        I: Iterator<Item = (Node<'a, 'i>, Vec<String>)>,
+    {
+        let index_column = columns
+            .iter()
+            .position(|info| info.mnemonic.eq_ignore_ascii_case(&self.index_curve))
+            .unwrap_or(0);
+        let time_index = self.is_time_indexed();
+
+        let mut numbers: Vec<Vec<f64>> = vec![vec![]; columns.len()];
+        let mut texts: Vec<Vec<String>> = vec![vec![]; columns.len()];
+
+        for (node, cells) in rows {
+            if cells.len() != columns.len() {
+                return Err(WitsmlError::at(
+                    &node,
+                    WitsmlErrorKind::ColumnCount {
+                        expected: columns.len(),
+                        found: cells.len(),
+                    },
+                ));
+            }
+
+            for (i, (info, cell)) in columns.iter().zip(cells).enumerate() {
+                if i == index_column && time_index {
+                    let time = parse_date_time(&cell).ok_or_else(|| {
+                        WitsmlError::at(&node, WitsmlErrorKind::InvalidValue(cell.clone()))
+                    })?;
+                    numbers[i].push(time);
+                } else if info.is_text() {
+                    texts[i].push(cell);
+                } else if cell.is_empty() || cell == "null" {
+                    numbers[i].push(f64::NAN);
+                } else {
+                    let value = cell.parse().map_err(|_| {
+                        WitsmlError::at(&node, WitsmlErrorKind::InvalidValue(cell.clone()))
+                    })?;
+                    numbers[i].push(value);
+                }
+            }
+        }
+
+        let mut curve_set = CurveSet::default();
+
+        for (i, ((info, values), text)) in columns.into_iter().zip(numbers).zip(texts).enumerate() {
+            if i != index_column && info.is_text() {
+                curve_set.texts.push(TextCurve {
+                    mnemonic: info.mnemonic,
+                    unit: info.unit,
+                    description: info.description,
+                    values: text,
+                });
+                continue;
+            }
+
+            let curve = Curve {
+                mnemonic: info.mnemonic,
+                unit: if i == index_column && time_index {
+                    String::from("s")
+                } else {
+                    info.unit
+                },
+                description: info.description,
+                values,
+                null_value: info.null_value,
+            };
+
+            if i == index_column {
+                curve_set.index = curve;
+            } else {
+                curve_set.curves.push(curve);
+            }
+        }
+
+        Ok(curve_set)
+    }
+}
+
+fn is(node: &Node, name: &str) -> bool {
+    node.is_element() && node.tag_name().name().eq_ignore_ascii_case(name)
+}
+
+fn child<'a, 'i>(node: &Node<'a, 'i>, name: &str) -> Option<Node<'a, 'i>> {
+    node.children().find(|child| is(child, name))
+}
+
+fn child_text(node: &Node, name: &str) -> Option<String> {
+    child(node, name)
+        .and_then(|child| child.text().map(|text| text.trim().to_string()))
+        .filter(|text| !text.is_empty())
+}
+
+/// Split the 2.0 `Data` block, `[ [[index], [v1, v2]], ... ]`, into flat
+/// rows. A JSON `null` becomes an empty cell, as does an empty string.
+fn split_json_rows(text: &str) -> Vec<Vec<String>> {
+    let mut rows = vec![];
+    let mut row = vec![];
+    let mut cell = String::new();
+    // whether the cell holds anything, be it an empty string
+    let mut has_value = false;
+    let mut is_string = false;
+    // an empty cell before or between commas still counts
+    let (mut after_open, mut after_comma) = (false, false);
+    let mut depth = 0;
+    let mut quoted = false;
+
+    for c in text.chars() {
+        match c {
+            '"' => {
+                quoted = !quoted;
+                has_value = true;
+                is_string = true;
+            }
+            _ if quoted => cell.push(c),
+            '[' => {
+                depth += 1;
+                (after_open, after_comma) = (true, false);
+            }
+            ']' | ',' => {
+                if has_value || after_comma || (after_open && c == ',') {
+                    let value = cell.trim();
+                    let value = if !is_string && value == "null" {
+                        ""
+                    } else {
+                        value
+                    };
+                    row.push(value.to_string());
+                }
+                cell.clear();
+                has_value = false;
+                is_string = false;
+                (after_open, after_comma) = (false, c == ',');
+
+                // a row ends when its outer bracket closes
+                if c == ']' {
+                    depth -= 1;
+                    if depth == 1 {
+                        rows.push(std::mem::take(&mut row));
+                    }
+                }
+            }
+            _ => {
+                has_value |= !c.is_whitespace();
+                cell.push(c);
+            }
+        }
+    }
+
+    rows
+}
+
+/// Parse an ISO 8601 date time (`2001-10-31T08:15:00.000Z`,
+/// `2001-10-31T08:15:00+02:00`) into seconds since the Unix epoch.
+pub(crate) fn parse_date_time(text: &str) -> Option<f64> {
+    let text = text.trim();
+    let (date, time) = text.split_once(['T', ' ']).unwrap_or((text, "00:00:00"));
+
+    let mut date_parts = date.split('-');
+    let year: i64 = date_parts.next()?.parse().ok()?;
+    let month: i64 = date_parts.next()?.parse().ok()?;
+    let day: i64 = date_parts.next()?.parse().ok()?;
+
+    // time zone suffix
+    let (time, offset) = if let Some(time) = time.strip_suffix('Z') {
+        (time, 0.0)
+    } else if let Some(pos) = time.rfind(['+', '-']) {
+        let (time, zone) = time.split_at(pos);
+        let sign = if zone.starts_with('-') { -1.0 } else { 1.0 };
+        let (hours, minutes) = zone[1..].split_once(':').unwrap_or((&zone[1..], "0"));
+        let zone_offset = hours.parse::<f64>().ok()? * 3600.0 + minutes.parse::<f64>().ok()? * 60.0;
+        (time, sign * zone_offset)
+    } else {
+        (time, 0.0)
+    };
+
+    let mut time_parts = time.split(':');
+    let hour: f64 = time_parts.next()?.parse().ok()?;
+    let minute: f64 = time_parts.next().unwrap_or("0").parse().ok()?;
+    let second: f64 = time_parts.next().unwrap_or("0").parse().ok()?;
+
+    // days from civil, proleptic Gregorian calendar
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    Some(days as f64 * 86400.0 + hour * 3600.0 + minute * 60.0 + second - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WITSML_141: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<logs xmlns="http://www.witsml.org/schemas/1series" version="1.4.1.1">
+  <log uidWell="W-1" uidWellbore="B-1" uid="L-1">
+    <nameWell>EXAMPLE-1</nameWell>
+    <nameWellbore>EXAMPLE-1 ST0</nameWellbore>
+    <name>Depth log</name>
+    <indexType>measured depth</indexType>
+    <direction>increasing</direction>
+    <indexCurve>DEPT</indexCurve>
+    <nullValue>-999.25</nullValue>
+    <logCurveInfo uid="DEPT">
+      <mnemonic>DEPT</mnemonic>
+      <unit>m</unit>
+      <typeLogData>double</typeLogData>
+    </logCurveInfo>
+    <logCurveInfo uid="GR">
+      <mnemonic>GR</mnemonic>
+      <unit>gAPI</unit>
+      <curveDescription>Gamma ray</curveDescription>
+      <typeLogData>double</typeLogData>
+    </logCurveInfo>
+    <logCurveInfo uid="RT">
+      <mnemonic>RT</mnemonic>
+      <unit>ohm.m</unit>
+      <nullValue>-1</nullValue>
+      <typeLogData>double</typeLogData>
+    </logCurveInfo>
+    <logCurveInfo uid="LITH">
+      <mnemonic>LITH</mnemonic>
+      <unit>unitless</unit>
+      <typeLogData>string</typeLogData>
+    </logCurveInfo>
+    <logData>
+      <mnemonicList>DEPT,GR,RT,LITH</mnemonicList>
+      <unitList>m,gAPI,ohm.m,unitless</unitList>
+      <data>1000.0,45.2,12.5,sand</data>
+      <data>1000.5,-999.25,-1,shale</data>
+      <data>1001.0,,null,</data>
+    </logData>
+  </log>
+</logs>"#;
+
+    const WITSML_20: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Log xmlns="http://www.energistics.org/energyml/data/witsmlv2" uuid="L-2" schemaVersion="2.0">
+  <Citation>
+    <Title>Depth log</Title>
+  </Citation>
+  <ChannelSet uuid="CS-1">
+    <Index>
+      <IndexType>measured depth</IndexType>
+      <Uom>m</Uom>
+      <Direction>increasing</Direction>
+      <Mnemonic>DEPTH</Mnemonic>
+    </Index>
+    <Channel uuid="C-1">
+      <Citation>
+        <Title>GR</Title>
+        <Description>Gamma ray</Description>
+      </Citation>
+      <Mnemonic>GR</Mnemonic>
+      <DataType>double</DataType>
+      <Uom>gAPI</Uom>
+    </Channel>
+    <Channel uuid="C-2">
+      <Mnemonic>LITH</Mnemonic>
+      <DataType>string</DataType>
+      <Uom>Euc</Uom>
+    </Channel>
+    <Data>
+      <Data>[
+        [[1000.0], [45.2, "sand, fine"]],
+        [[1000.5], [null, "shale"]],
+        [[1001.0], [50.1, ""]],
+        [[1001.5], [, null]]
+      ]</Data>
+    </Data>
+  </ChannelSet>
+</Log>"#;
+
+    #[test]
+    fn reads_141_logs() {
+        let logs = WitsmlLog::parse(WITSML_141).unwrap();
+        assert_eq!(logs.len(), 1);
+
+        let log = &logs[0];
+        assert_eq!(log.uid, "L-1");
+        assert_eq!(log.name, "Depth log");
+        assert_eq!(log.name_well, "EXAMPLE-1");
+        assert_eq!(log.index_curve, "DEPT");
+        assert!(!log.is_time_indexed());
+
+        let data = &log.data;
+        assert_eq!(data.index.mnemonic, "DEPT");
+        assert_eq!(data.index.unit, "m");
+        assert_eq!(data.index.values, vec![1000.0, 1000.5, 1001.0]);
+
+        let gr = data.curve("GR").unwrap();
+        assert_eq!(gr.unit, "gAPI");
+        assert_eq!(gr.description, "Gamma ray");
+        assert_eq!(data.curve("RT").unwrap().unit, "ohm.m");
+        assert_eq!(data.text("LITH").unwrap().values, vec!["sand", "shale", ""]);
+    }
+
+    #[test]
+    fn falls_back_to_the_log_null_value() {
+        let logs = WitsmlLog::parse(WITSML_141).unwrap();
+        let (gr, rt) = (
+            logs[0].data.curve("GR").unwrap(),
+            logs[0].data.curve("RT").unwrap(),
+        );
+
+        assert_eq!(gr.null_value, Some(-999.25));
+        assert_eq!(rt.null_value, Some(-1.0));
+        assert!(gr.is_null(gr.values[1]));
+        assert!(rt.is_null(rt.values[1]));
+        assert!(!gr.is_null(rt.values[1]));
+    }
+
+    #[test]
+    fn reads_empty_and_null_141_cells_as_missing() {
+        let logs = WitsmlLog::parse(WITSML_141).unwrap();
+        let data = &logs[0].data;
+
+        assert!(data.curve("GR").unwrap().values[2].is_nan());
+        assert!(data.curve("RT").unwrap().values[2].is_nan());
+    }
+
+    #[test]
+    fn reads_20_channel_sets() {
+        let logs = WitsmlLog::parse(WITSML_20).unwrap();
+        assert_eq!(logs.len(), 1);
+
+        let log = &logs[0];
+        assert_eq!(log.uid, "L-2");
+        assert_eq!(log.name, "Depth log");
+        assert_eq!(log.index_curve, "DEPTH");
+        assert_eq!(log.direction, "increasing");
+
+        let data = &log.data;
+        assert_eq!(data.index.mnemonic, "DEPTH");
+        assert_eq!(data.index.unit, "m");
+        assert_eq!(data.index.values, vec![1000.0, 1000.5, 1001.0, 1001.5]);
+
+        let gr = data.curve("GR").unwrap();
+        assert_eq!(gr.unit, "gAPI");
+        assert_eq!(gr.description, "Gamma ray");
+        assert_eq!(gr.null_value, None);
+        assert_eq!(gr.values[0], 45.2);
+        assert_eq!(gr.values[2], 50.1);
+        assert!(gr.values[1].is_nan());
+        assert!(gr.values[3].is_nan());
+
+        let lith = data.text("LITH").unwrap();
+        assert_eq!(lith.unit, "Euc");
+        assert_eq!(lith.values, vec!["sand, fine", "shale", "", ""]);
+    }
+
+    #[test]
+    fn reports_the_line_of_a_short_row() {
+        let xml = WITSML_141.replace("1000.5,-999.25,-1,shale", "1000.5,-999.25,-1");
+        let error = WitsmlLog::parse(&xml).unwrap_err();
+
+        assert_eq!(error.line, 37);
+        assert!(matches!(
+            error.kind,
+            WitsmlErrorKind::ColumnCount {
+                expected: 4,
+                found: 3
+            }
+        ));
+    }
+
+    #[test]
+    fn converts_date_times_to_unix_seconds() {
+        assert_eq!(parse_date_time("1970-01-02T00:00:00Z"), Some(86400.0));
+        assert_eq!(
+            parse_date_time("2001-10-31T08:15:00.500+02:00"),
+            Some(1004508900.5)
+        );
+        assert_eq!(parse_date_time("not a date"), None);
+    }
+}
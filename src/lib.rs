@@ -1,7 +1,24 @@
+pub mod annotation;
 pub mod builder;
 pub mod cartesian_impl;
+pub mod completion;
+pub mod curve;
+pub mod dip;
+pub mod discrete;
+pub mod fill;
+pub mod image;
+pub mod io;
 pub mod log_plot_style;
+pub mod marker;
 pub mod mesh;
+pub mod nmr;
+pub mod pattern;
+pub mod pips;
 pub mod plot;
 pub mod plot_legend;
 pub mod series;
+pub mod tops;
+pub mod unit;
+pub mod waveform;
+pub mod well;
+pub mod well_header;
@@ -9,9 +9,15 @@ use chartrs::{
 };
 use chartrs_backend::{stroke::Stroke, FontDesc};
 
-use crate::plot::log_plot_context::LogPlotContext;
+use crate::{pips::Pips, plot::log_plot_context::LogPlotContext};
 
 type Fmt<'b, YV> = Option<&'b dyn Fn(&YV) -> String>;
+/// Pips as `(depth, is major)` and the stroke they are drawn with
+type PipMarks<YV> = Option<(Vec<(YV, bool)>, Stroke)>;
+
+/// Distance of the borehole volume pips from the left edge of the depth
+/// area, clear of the travel time ones
+const VOLUME_PIP_OFFSET: i32 = 14;
 
 pub struct LogPlotStyle<'a, 'b, Y, DB>
 where
@@ -25,6 +31,9 @@ where
 
     pub(super) y_label_format: Fmt<'b, Y::ValueType>,
 
+    pub(super) travel_time: PipMarks<Y::ValueType>,
+    pub(super) borehole_volume: PipMarks<Y::ValueType>,
+
     pub(super) chart_context: Option<&'b mut LogPlotContext<'a, DB, Cartesian2dY<Y>>>,
     pub(super) _phantom_data: PhantomData<Y>,
 }
@@ -43,6 +52,9 @@ where
 
             y_label_format: None,
 
+            travel_time: None,
+            borehole_volume: None,
+
             chart_context: Some(chart_context),
             _phantom_data: PhantomData,
         }
@@ -62,29 +74,27 @@ where
     }
 
     /// Set the formatter function for the Y label text
-    /// - `fmt`: The formatter function
+    /// - `fmt`: The formatter function, given the depth in the unit it is
+    ///   shown in when it is converted, see [`crate::builder::LogPlotBuilder::unit_system`]
     pub fn y_label_formatter(&mut self, fmt: &'b dyn Fn(&Y::ValueType) -> String) -> &mut Self {
         self.y_label_format = Some(fmt);
         self
     }
+}
 
-    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
-    where
-        Y: ValueFormatter<<Y as Ranged>::ValueType>,
-    {
+impl<'a, 'b, Y, YT, DB> LogPlotStyle<'a, 'b, Y, DB>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = YT> + ValueFormatter<YT>,
+    YT: 'static,
+{
+    pub fn draw(&mut self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let chart_context = if let Some(context) = self.chart_context.take() {
             context
         } else {
             return Err(DrawingAreaErrorKind::DrawingContextError);
         };
 
-        // draw head
-        chart_context.draw_head(self.title.as_str(), self.detail.as_str())?;
-        // draw head & body outline
-        chart_context.draw_area_rect(&Stroke::WIDTH2_BLACK_LINE)?;
-
-        let text_style = &FontDesc::default_font_with_parent_size(&self.parent_size).into();
-
         let range = chart_context.depth_body.get_y_axis_pixel_range();
         let (start, end) = (range.start, range.end);
 
@@ -98,22 +108,98 @@ where
             }
         }
 
+        // depth unit, and ticks at round values of the unit the depth is
+        // shown in when it is converted, which only an `f64` depth can be
+        let builder = chart_context.builder;
+        let (detail, converted_ticks) = match &builder.depth_unit {
+            Some(depth_unit) => {
+                let display_unit = builder
+                    .unit_system
+                    .map(|system| builder.units.unit_for(system, depth_unit))
+                    .unwrap_or(depth_unit.as_str());
+                let ticks = builder
+                    .units
+                    .conversion(depth_unit, display_unit)
+                    .filter(|conversion| !conversion.is_identity())
+                    .map(|conversion| {
+                        chart_context.converted_depth_ticks(
+                            LightPoints::new(bold_count, light_count),
+                            conversion,
+                            self.y_label_format,
+                        )
+                    });
+                let unit = match ticks {
+                    Some(None) => depth_unit.as_str(),
+                    _ => display_unit,
+                };
+
+                (format!("{} ({})", self.detail, unit), ticks.flatten())
+            }
+            None => (self.detail.clone(), None),
+        };
+
+        // draw well header
+        chart_context.draw_well_header()?;
+        // draw head
+        chart_context.draw_head(self.title.as_str(), detail.as_str())?;
+        // draw head & body outline
+        chart_context.draw_area_rect(&Stroke::WIDTH2_BLACK_LINE)?;
+
+        let text_style = &FontDesc::default_font_with_parent_size(&self.parent_size).into();
+
         // light & dark tick
-        chart_context.draw_depth_area_tick(
-            LightPoints::new(bold_count, light_count),
-            &Stroke::LIGHT_MESH_LINE,
-            &Stroke::BOLD_MESH_LINE,
-            text_style,
-            8,
-            |y_range, m| {
-                let label = self
-                    .y_label_format
-                    .map(|fmt_func| fmt_func(m.2))
-                    .unwrap_or(y_range.format_ext(m.2));
-                Some(label)
-            },
-        )?;
+        match converted_ticks {
+            Some(ticks) => chart_context.draw_depth_ticks(
+                ticks,
+                &Stroke::LIGHT_MESH_LINE,
+                &Stroke::BOLD_MESH_LINE,
+                text_style,
+                8,
+            )?,
+            None => chart_context.draw_depth_area_tick(
+                LightPoints::new(bold_count, light_count),
+                &Stroke::LIGHT_MESH_LINE,
+                &Stroke::BOLD_MESH_LINE,
+                text_style,
+                8,
+                |y_range, m| {
+                    let label = self
+                        .y_label_format
+                        .map(|fmt_func| fmt_func(m.2))
+                        .unwrap_or_else(|| y_range.format_ext(m.2));
+                    Some(label)
+                },
+            )?,
+        }
+
+        // integrated curves along the left edge
+        if let Some((marks, stroke)) = &self.travel_time {
+            chart_context.draw_depth_area_pips(marks, 0, stroke)?;
+        }
+        if let Some((marks, stroke)) = &self.borehole_volume {
+            chart_context.draw_depth_area_pips(marks, VOLUME_PIP_OFFSET, stroke)?;
+        }
 
         Ok(())
     }
 }
+
+impl<'a, 'b, Y, DB> LogPlotStyle<'a, 'b, Y, DB>
+where
+    Y: Ranged<ValueType = f64>,
+    DB: DrawingBackend,
+{
+    /// Mark the integrated one way time of `pips`, see
+    /// [`Pips::travel_time`], along the left edge of the depth area
+    pub fn travel_time_pips(&mut self, pips: &Pips) -> &mut Self {
+        self.travel_time = Some((pips.marks(), pips.stroke));
+        self
+    }
+
+    /// Mark the integrated hole volume of `pips`, see
+    /// [`Pips::borehole_volume`], next to the travel time pips
+    pub fn borehole_volume_pips(&mut self, pips: &Pips) -> &mut Self {
+        self.borehole_volume = Some((pips.marks(), pips.stroke));
+        self
+    }
+}
@@ -0,0 +1,151 @@
+//! Point markers, the symbols of the dip tadpoles and of point data such as
+//! core measurements, drawn by
+//! [`crate::plot::channel_context::ChannelContext::draw_core_points`].
+//!
+//! ```ignore
+//! let mut core_porosity = PlotCurve::default();
+//! core_porosity
+//!     .name("CPOR")
+//!     .range(0.45, -0.15, "v/v")
+//!     .marker(PointMarker::new(MarkerShape::Diamond, 4, BLUE.to_backend_color()));
+//!
+//! channel_context.draw_core_points(
+//!     &core_porosity,
+//!     &[CorePoint::new(1612.3, 0.21).value_error(0.02).depth_error(0.1)],
+//! )?;
+//! ```
+
+use chartrs::prelude::Stroke;
+use chartrs_backend::{BackendColor, BackendCoord, DrawingBackend, DrawingErrorKind};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MarkerShape {
+    Circle,
+    Square,
+    Triangle,
+    Diamond,
+    Cross,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PointMarker {
+    pub shape: MarkerShape,
+    /// Half the width of the marker in pixels
+    pub size: u32,
+    pub color: BackendColor,
+    pub filled: bool,
+}
+
+impl Default for PointMarker {
+    fn default() -> Self {
+        Self::new(
+            MarkerShape::Circle,
+            3,
+            BackendColor {
+                alpha: 1.0,
+                rgb: (0, 0, 0),
+            },
+        )
+    }
+}
+
+impl PointMarker {
+    pub fn new(shape: MarkerShape, size: u32, color: BackendColor) -> Self {
+        Self {
+            shape,
+            size,
+            color,
+            filled: true,
+        }
+    }
+
+    pub fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+}
+
+/// A measurement at a depth, with optional `±` uncertainties.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CorePoint {
+    pub depth: f64,
+    pub value: f64,
+    pub depth_error: Option<f64>,
+    pub value_error: Option<f64>,
+}
+
+impl CorePoint {
+    pub fn new(depth: f64, value: f64) -> Self {
+        Self {
+            depth,
+            value,
+            depth_error: None,
+            value_error: None,
+        }
+    }
+
+    pub fn depth_error(mut self, error: f64) -> Self {
+        self.depth_error = Some(error);
+        self
+    }
+
+    pub fn value_error(mut self, error: f64) -> Self {
+        self.value_error = Some(error);
+        self
+    }
+}
+
+/// Draw `marker` centered on `center`
+pub(crate) fn draw_marker<DB: DrawingBackend>(
+    backend: &mut DB,
+    center: BackendCoord,
+    marker: &PointMarker,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    let stroke = Stroke::new(1., marker.color);
+    let (x, y, size) = (center.x, center.y, marker.size as i32);
+
+    match marker.shape {
+        MarkerShape::Circle => backend.draw_circle(center, marker.size, &stroke, marker.filled),
+        MarkerShape::Square => backend.draw_rect(
+            BackendCoord::new(x - size, y - size),
+            BackendCoord::new(x + size, y + size),
+            &stroke,
+            marker.filled,
+        ),
+        MarkerShape::Triangle | MarkerShape::Diamond => {
+            let corners = if marker.shape == MarkerShape::Triangle {
+                vec![
+                    BackendCoord::new(x, y - size),
+                    BackendCoord::new(x + size, y + size),
+                    BackendCoord::new(x - size, y + size),
+                ]
+            } else {
+                vec![
+                    BackendCoord::new(x, y - size),
+                    BackendCoord::new(x + size, y),
+                    BackendCoord::new(x, y + size),
+                    BackendCoord::new(x - size, y),
+                ]
+            };
+
+            if marker.filled {
+                backend.fill_polygon(corners, &stroke)
+            } else {
+                let first = corners[0];
+                backend.draw_path(corners.into_iter().chain([first]), &stroke)
+            }
+        }
+        MarkerShape::Cross => {
+            backend.draw_line(
+                BackendCoord::new(x - size, y - size),
+                BackendCoord::new(x + size, y + size),
+                &stroke,
+            )?;
+            backend.draw_line(
+                BackendCoord::new(x - size, y + size),
+                BackendCoord::new(x + size, y - size),
+                &stroke,
+            )
+        }
+    }
+}
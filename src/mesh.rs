@@ -11,6 +11,57 @@ use chartrs_backend::stroke::Stroke;
 
 use crate::{plot::channel_context::ChannelContext, plot_legend::PlotCurve};
 
+/// Spacing of the vertical grid lines of a channel.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum XScale {
+    Linear,
+    /// Decade lines and 2 - 9 sub-decade lines between `min` at the left
+    /// edge and `max` at the right one, `min > max` for a reversed scale
+    Logarithmic {
+        min: f64,
+        max: f64,
+    },
+    /// Dip angle from `0°` to `90°`, a line every 10° and a bold one every 30°
+    Dip,
+}
+
+impl XScale {
+    /// Grid lines of the scale as `(position across the channel, is bold)`,
+    /// empty for a linear scale whose grid is the regular mesh.
+    pub(crate) fn grid(&self) -> Vec<(f64, bool)> {
+        match self {
+            XScale::Linear => vec![],
+            XScale::Logarithmic { .. } => self.log_grid(),
+            XScale::Dip => (1..9).map(|i| (i as f64 / 9.0, i % 3 == 0)).collect(),
+        }
+    }
+
+    /// Grid lines of a logarithmic scale as `(position across the channel,
+    /// is a decade)`, empty for any other scale.
+    pub(crate) fn log_grid(&self) -> Vec<(f64, bool)> {
+        let (left, right) = match *self {
+            XScale::Logarithmic { min, max } if min > 0.0 && max > 0.0 && min != max => (min, max),
+            _ => return vec![],
+        };
+        let (min, max) = (left.min(right), left.max(right));
+        let span = right.ln() - left.ln();
+
+        let mut lines = vec![];
+        let mut decade = 10f64.powi(min.log10().floor() as i32);
+        while decade <= max * (1.0 + 1e-9) {
+            for step in 1..10 {
+                let value = decade * step as f64;
+                if value >= min * (1.0 - 1e-9) && value <= max * (1.0 + 1e-9) {
+                    lines.push(((value.ln() - left.ln()) / span, step == 1));
+                }
+            }
+            decade *= 10.0;
+        }
+
+        lines
+    }
+}
+
 pub struct ChannelContextMeshStyle<'a, 'b, Y, DB>
 where
     Y: Ranged,
@@ -24,6 +75,7 @@ where
 
     pub(super) draw_x_mesh: bool,
     pub(super) draw_y_mesh: bool,
+    pub(super) x_scale: XScale,
 
     pub(super) title: String,
     pub legends: Vec<PlotCurve>,
@@ -46,6 +98,7 @@ where
 
             draw_x_mesh: true,
             draw_y_mesh: true,
+            x_scale: XScale::Linear,
 
             title: String::default(),
             legends: vec![],
@@ -66,6 +119,19 @@ where
         self
     }
 
+    /// Draw the vertical grid of a logarithmic scale from `min` on the left to `max` on the right
+    pub fn log_scale(&mut self, min: f64, max: f64) -> &mut Self {
+        self.x_scale = XScale::Logarithmic { min, max };
+        self
+    }
+
+    /// Draw the vertical grid of a 0 - 90° dip angle scale, for tadpoles. The
+    /// head shows the scale when [`Self::draw`] is given no curves.
+    pub fn dip_scale(&mut self) -> &mut Self {
+        self.x_scale = XScale::Dip;
+        self
+    }
+
     pub fn add_curve<F>(&mut self, mut desc: F) -> &mut Self
     where
         F: FnMut(&mut PlotCurve),
@@ -88,8 +154,18 @@ where
         } else {
             return Err(DrawingAreaErrorKind::DrawingContextError);
         };
+        // a dip track has no curve of its own, its angle scale takes the legend's place
+        let legends = match self.x_scale {
+            XScale::Dip if legends.is_empty() => {
+                let mut angle = PlotCurve::default();
+                angle.name("DIP ANGLE").range(0., 90., "deg");
+                vec![angle]
+            }
+            _ => legends.to_owned(),
+        };
+
         // draw head
-        chart_context.draw_head(self.title.as_str(), legends.to_owned())?;
+        chart_context.draw_head(self.title.as_str(), legends)?;
 
         chart_context.draw_area_rect(&Stroke::WIDTH2_BLACK_LINE)?;
 
@@ -120,7 +196,78 @@ where
             self,
             &self.dark_line_style.unwrap_or(Stroke::BOLD_MESH_LINE),
         )?;
+        //----- logarithmic or dip x mesh -----
+        if self.draw_x_mesh {
+            chart_context.draw_x_grid(
+                &self.x_scale.grid(),
+                &self.light_line_style.unwrap_or(Stroke::LIGHT_MESH_LINE),
+                &self.dark_line_style.unwrap_or(Stroke::BOLD_MESH_LINE),
+            )?;
+        }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_grid_has_a_line_per_step_and_bold_decades() {
+        let grid = XScale::Logarithmic {
+            min: 0.2,
+            max: 2000.0,
+        }
+        .log_grid();
+
+        // 0.2 - 0.9, three full decades, then 1000 and 2000
+        assert_eq!(grid.len(), 8 + 3 * 9 + 2);
+        assert_eq!(grid.iter().filter(|(_, bold)| *bold).count(), 4);
+
+        let (first, last) = (grid[0], grid[grid.len() - 1]);
+        assert!(first.0.abs() < 1e-9 && !first.1);
+        assert!((last.0 - 1.0).abs() < 1e-9 && !last.1);
+
+        // decades are a quarter of the four decade span apart
+        let decades: Vec<f64> = grid
+            .iter()
+            .filter(|(_, bold)| *bold)
+            .map(|(position, _)| *position)
+            .collect();
+        for pair in decades.windows(2) {
+            assert!((pair[1] - pair[0] - 0.25).abs() < 1e-9);
+        }
+        assert!(grid.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn log_grid_is_empty_without_positive_bounds() {
+        for (min, max) in [(0.0, 100.0), (-1.0, 100.0), (1.0, -10.0), (10.0, 10.0)] {
+            assert!(XScale::Logarithmic { min, max }.log_grid().is_empty());
+        }
+        assert!(XScale::Linear.log_grid().is_empty());
+    }
+
+    #[test]
+    fn reversed_log_grid_mirrors_the_grid() {
+        let grid = XScale::Logarithmic {
+            min: 0.2,
+            max: 2000.0,
+        }
+        .log_grid();
+        let reversed = XScale::Logarithmic {
+            min: 2000.0,
+            max: 0.2,
+        }
+        .log_grid();
+
+        assert_eq!(reversed.len(), grid.len());
+        for ((position, bold), (reversed_position, reversed_bold)) in grid.iter().zip(&reversed) {
+            assert!((position + reversed_position - 1.0).abs() < 1e-9);
+            assert_eq!(bold, reversed_bold);
+        }
+        // 0.2 is on the right edge
+        assert!((reversed[0].0 - 1.0).abs() < 1e-9);
+    }
+}
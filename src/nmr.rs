@@ -0,0 +1,168 @@
+//! NMR T2 distributions drawn in a spectrum track, see
+//! [`crate::plot::log_plot_context::LogPlotContext::add_spectrum_track`].
+//!
+//! ```ignore
+//! let mut spectrum_context = plot_context.add_spectrum_track(1524.0..1850.0, 160);
+//! spectrum_context
+//!     .cutoff(Some(33.0))
+//!     .display(SpectrumDisplay::Raster(ColorMap::Viridis));
+//! spectrum_context.draw("T2 DIST", &T2Spectra::new(depths, bin_times, amplitudes))?;
+//! ```
+
+use chartrs::prelude::Stroke;
+use chartrs_backend::BackendColor;
+
+use crate::image::ColorMap;
+
+/// One distribution of amplitudes per depth, all over the same T2 bins in
+/// milliseconds, e.g. 64 bins from 0.3 ms to 3000 ms.
+#[derive(Debug, Clone, Default)]
+pub struct T2Spectra {
+    pub depths: Vec<f64>,
+    /// Ascending and positive, spaced evenly on a logarithmic axis
+    pub bin_times: Vec<f64>,
+    pub amplitudes: Vec<Vec<f64>>,
+}
+
+impl T2Spectra {
+    pub fn new(depths: Vec<f64>, bin_times: Vec<f64>, amplitudes: Vec<Vec<f64>>) -> Self {
+        Self {
+            depths,
+            bin_times,
+            amplitudes,
+        }
+    }
+
+    /// Largest amplitude of all distributions, the one drawn at full scale
+    pub fn max_amplitude(&self) -> f64 {
+        self.amplitudes
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold(0.0, |max: f64, value| max.max(*value))
+    }
+
+    /// Amplitude of the distribution at `index` at `time`, linearly
+    /// interpolated between bins on the logarithmic time axis
+    pub fn amplitude_at(&self, index: usize, time: f64) -> Option<f64> {
+        let values = self.amplitudes.get(index)?;
+        let bins = values.len().min(self.bin_times.len());
+        if time <= 0.0 {
+            return None;
+        }
+
+        let next = self.bin_times[..bins].partition_point(|at| *at < time);
+        if next == bins {
+            return None;
+        }
+        if next == 0 {
+            return (self.bin_times[0] == time).then_some(values[0]);
+        }
+
+        let (t0, t1) = (self.bin_times[next - 1].ln(), self.bin_times[next].ln());
+        let (v0, v1) = (values[next - 1], values[next]);
+
+        let value = v0 + (v1 - v0) * (time.ln() - t0) / (t1 - t0);
+        value.is_finite().then_some(value)
+    }
+}
+
+/// Indices of the distributions drawn by a stacked display at the pixel rows
+/// `ys`: those within `top..=bottom`, each at least `spacing` pixels from the
+/// last one drawn.
+pub(crate) fn stacked_traces(ys: &[i32], top: i32, bottom: i32, spacing: i32) -> Vec<usize> {
+    let mut traces = vec![];
+    let mut last_y: Option<i32> = None;
+
+    for (index, y) in ys.iter().copied().enumerate() {
+        if !(top..=bottom).contains(&y) || last_y.is_some_and(|last| (y - last).abs() < spacing) {
+            continue;
+        }
+        last_y = Some(y);
+        traces.push(index);
+    }
+
+    traces
+}
+
+/// How the distributions of a spectrum track are drawn.
+#[derive(Debug, Clone)]
+pub enum SpectrumDisplay {
+    /// Amplitudes through a color map, each distribution covering the
+    /// depths halfway to its neighbours
+    Raster(ColorMap),
+    /// A small filled curve per depth, rising above its depth with the amplitude
+    Stacked {
+        stroke: Stroke,
+        fill: Option<BackendColor>,
+    },
+}
+
+impl Default for SpectrumDisplay {
+    fn default() -> Self {
+        SpectrumDisplay::Stacked {
+            stroke: Stroke::new(
+                1.,
+                BackendColor {
+                    alpha: 1.0,
+                    rgb: (0, 0, 0),
+                },
+            ),
+            fill: Some(BackendColor {
+                alpha: 1.0,
+                rgb: (120, 120, 120),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spectra() -> T2Spectra {
+        T2Spectra::new(
+            vec![1600.0, 1600.5],
+            vec![1.0, 10.0, 100.0],
+            vec![vec![0.0, 10.0, 20.0], vec![4.0, f64::NAN]],
+        )
+    }
+
+    #[test]
+    fn amplitude_is_interpolated_between_bins_on_the_log_axis() {
+        let spectra = spectra();
+
+        assert_eq!(spectra.amplitude_at(0, 10.0), Some(10.0));
+        assert!((spectra.amplitude_at(0, 10f64.sqrt()).unwrap() - 5.0).abs() < 1e-9);
+        assert!((spectra.amplitude_at(0, 1000f64.sqrt()).unwrap() - 15.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn no_amplitude_outside_the_bins() {
+        let spectra = spectra();
+
+        assert_eq!(spectra.amplitude_at(0, 1.0), Some(0.0));
+        assert_eq!(spectra.amplitude_at(0, 0.5), None);
+        assert_eq!(spectra.amplitude_at(0, 200.0), None);
+        assert_eq!(spectra.amplitude_at(0, 0.0), None);
+        assert_eq!(spectra.amplitude_at(2, 10.0), None);
+        // the second distribution has two bins, the last one missing
+        assert_eq!(spectra.amplitude_at(1, 5.0), None);
+        assert_eq!(spectra.amplitude_at(1, 50.0), None);
+    }
+
+    #[test]
+    fn max_amplitude_skips_missing_values() {
+        assert_eq!(spectra().max_amplitude(), 20.0);
+        assert_eq!(T2Spectra::default().max_amplitude(), 0.0);
+    }
+
+    #[test]
+    fn stacked_traces_are_spaced_and_within_the_track() {
+        let ys = [-5, 0, 3, 8, 10, 20, 26, 40];
+
+        assert_eq!(stacked_traces(&ys, 0, 30, 8), vec![1, 3, 5]);
+        assert_eq!(stacked_traces(&ys, 0, 30, 1), vec![1, 2, 3, 4, 5, 6]);
+        assert!(stacked_traces(&[], 0, 30, 8).is_empty());
+    }
+}
@@ -0,0 +1,366 @@
+//! Tile patterns for lithology and other interval fills.
+//!
+//! ```ignore
+//! let mut patterns = PatternLibrary::default();
+//! patterns.add(
+//!     "chert",
+//!     Pattern::from_rows("Chert", &["#...", "....", "..#.", "...."], BLACK, WHITE),
+//! );
+//! ```
+
+use chartrs::{
+    coord::Shift,
+    element::{BackendCoordOnly, CoordMapper, Drawable, PointCollection},
+    prelude::DrawingArea,
+};
+use chartrs_backend::{
+    text_anchor::{HPos, Pos, VPos},
+    BackendColor, BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind, TextStyle,
+};
+
+/// A tile repeated over an area. The tile is anchored to the backend pixel
+/// grid so neighbouring areas with the same pattern line up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    pub name: String,
+    pub foreground: BackendColor,
+    pub background: BackendColor,
+    width: usize,
+    height: usize,
+    marks: Vec<bool>,
+}
+
+impl Pattern {
+    /// A pattern from the rows of its tile, `#` marks a foreground pixel and
+    /// anything else a background pixel.
+    pub fn from_rows<N>(
+        name: N,
+        rows: &[&str],
+        foreground: BackendColor,
+        background: BackendColor,
+    ) -> Self
+    where
+        N: Into<String>,
+    {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0).max(1);
+        let height = rows.len().max(1);
+
+        let mut marks = vec![false; width * height];
+        for (y, row) in rows.iter().enumerate() {
+            for (x, c) in row.bytes().enumerate() {
+                marks[y * width + x] = c == b'#';
+            }
+        }
+
+        Self {
+            name: name.into(),
+            foreground,
+            background,
+            width,
+            height,
+            marks,
+        }
+    }
+
+    pub fn solid<N>(name: N, color: BackendColor) -> Self
+    where
+        N: Into<String>,
+    {
+        Self::from_rows(name, &["#"], color, color)
+    }
+
+    /// Color of the backend pixel `(x, y)`
+    pub fn color_at(&self, x: i32, y: i32) -> BackendColor {
+        let x = x.rem_euclid(self.width as i32) as usize;
+        let y = y.rem_euclid(self.height as i32) as usize;
+
+        if self.marks[y * self.width + x] {
+            self.foreground
+        } else {
+            self.background
+        }
+    }
+}
+
+/// Patterns by code. Codes are matched ignoring case.
+#[derive(Debug, Clone)]
+pub struct PatternLibrary {
+    patterns: Vec<(String, Pattern)>,
+}
+
+const fn rgb(r: u8, g: u8, b: u8) -> BackendColor {
+    BackendColor {
+        alpha: 1.0,
+        rgb: (r, g, b),
+    }
+}
+
+pub(crate) const BLACK: BackendColor = rgb(0, 0, 0);
+
+impl Default for PatternLibrary {
+    /// The standard lithologies
+    fn default() -> Self {
+        let mut library = Self::empty();
+
+        library
+            .add(
+                "sandstone",
+                Pattern::from_rows(
+                    "Sandstone",
+                    &[
+                        "........", ".#......", "........", "........", ".....#..", "........",
+                        "........", "........",
+                    ],
+                    BLACK,
+                    rgb(255, 235, 130),
+                ),
+            )
+            .add(
+                "siltstone",
+                Pattern::from_rows(
+                    "Siltstone",
+                    &[
+                        "..........",
+                        ".#........",
+                        "..........",
+                        ".....####.",
+                        "..........",
+                        "..........",
+                    ],
+                    BLACK,
+                    rgb(240, 220, 150),
+                ),
+            )
+            .add(
+                "shale",
+                Pattern::from_rows(
+                    "Shale",
+                    &[
+                        "............",
+                        "####..####..",
+                        "............",
+                        "............",
+                        "#..####..###",
+                        "............",
+                    ],
+                    rgb(60, 60, 60),
+                    rgb(190, 190, 190),
+                ),
+            )
+            .add(
+                "limestone",
+                Pattern::from_rows(
+                    "Limestone",
+                    &[
+                        "################",
+                        "#...............",
+                        "#...............",
+                        "#...............",
+                        "################",
+                        "........#.......",
+                        "........#.......",
+                        "........#.......",
+                    ],
+                    BLACK,
+                    rgb(160, 210, 255),
+                ),
+            )
+            .add(
+                "dolomite",
+                Pattern::from_rows(
+                    "Dolomite",
+                    &[
+                        "################",
+                        "#...............",
+                        ".#..............",
+                        "..#.............",
+                        "################",
+                        "........#.......",
+                        ".........#......",
+                        "..........#.....",
+                    ],
+                    BLACK,
+                    rgb(200, 160, 230),
+                ),
+            )
+            .add(
+                "conglomerate",
+                Pattern::from_rows(
+                    "Conglomerate",
+                    &[
+                        "..........",
+                        "...###....",
+                        "..#...#...",
+                        "..#...#...",
+                        "...###....",
+                        "..........",
+                        ".......#..",
+                        "..........",
+                    ],
+                    BLACK,
+                    rgb(255, 220, 100),
+                ),
+            )
+            .add("coal", Pattern::solid("Coal", BLACK))
+            .add(
+                "anhydrite",
+                Pattern::from_rows(
+                    "Anhydrite",
+                    &[
+                        "#......#", ".#....#.", "..#..#..", "...##...", "...##...", "..#..#..",
+                        ".#....#.", "#......#",
+                    ],
+                    BLACK,
+                    rgb(230, 190, 255),
+                ),
+            )
+            .add(
+                "salt",
+                Pattern::from_rows(
+                    "Salt",
+                    &[
+                        "........", "...#....", "..###...", "...#....", "........", "........",
+                        "........", "........",
+                    ],
+                    BLACK,
+                    rgb(200, 255, 255),
+                ),
+            );
+
+        library
+    }
+}
+
+impl PatternLibrary {
+    /// A library without any pattern
+    pub fn empty() -> Self {
+        Self { patterns: vec![] }
+    }
+
+    /// Add a pattern, replacing the one with the same code
+    pub fn add<C>(&mut self, code: C, pattern: Pattern) -> &mut Self
+    where
+        C: Into<String>,
+    {
+        let code = code.into();
+        self.patterns
+            .retain(|(existing, _)| !existing.eq_ignore_ascii_case(&code));
+        self.patterns.push((code, pattern));
+        self
+    }
+
+    pub fn get(&self, code: &str) -> Option<&Pattern> {
+        self.patterns
+            .iter()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(code.trim()))
+            .map(|(_, pattern)| pattern)
+    }
+
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.patterns.iter().map(|(code, _)| code.as_str())
+    }
+}
+
+/// Fill the backend rectangle from `upper_left` to `bottom_right` (inclusive) with `pattern`.
+pub(crate) fn fill_pattern<DB: DrawingBackend>(
+    backend: &mut DB,
+    pattern: &Pattern,
+    upper_left: BackendCoord,
+    bottom_right: BackendCoord,
+) -> Result<(), DrawingErrorKind<DB::ErrorType>> {
+    for y in upper_left.y..=bottom_right.y {
+        for x in upper_left.x..=bottom_right.x {
+            backend.draw_pixel(BackendCoord::new(x, y), pattern.color_at(x, y))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Swatches of patterns with their names, one per row.
+pub struct PatternLegend<'a, Coord> {
+    points: [Coord; 2],
+    patterns: Vec<Pattern>,
+    text_style: TextStyle<'a>,
+}
+
+impl<'a> PatternLegend<'a, BackendCoord> {
+    pub fn with_area<DB>(
+        area: &DrawingArea<DB, Shift>,
+        patterns: Vec<Pattern>,
+        text_style: TextStyle<'a>,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+    {
+        let (w, h) = area.dim_in_pixel();
+
+        Self {
+            points: [
+                BackendCoord::new(0_i32, 0_i32),
+                BackendCoord::new(w as i32, h as i32),
+            ],
+            patterns,
+            text_style,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a PatternLegend<'a, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB> Drawable<DB> for PatternLegend<'a, Coord>
+where
+    DB: DrawingBackend,
+{
+    fn draw<I>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<<DB as DrawingBackend>::ErrorType>>
+    where
+        I: Iterator<Item = <BackendCoordOnly as CoordMapper>::Output>,
+    {
+        let (top_left, bottom_right) = match (points.next(), points.next()) {
+            (Some(top_left), Some(bottom_right)) => (top_left, bottom_right),
+            _ => return Ok(()),
+        };
+
+        let row_height = self.text_style.size() as i32 + 8;
+        let swatch_width = 30.min((bottom_right.x - top_left.x) / 3);
+        let text_style = self.text_style.pos(Pos::new(HPos::Left, VPos::Center));
+
+        let mut y = top_left.y + 5;
+        for pattern in self.patterns.iter() {
+            // the rows that do not fit are left out
+            if y + row_height > bottom_right.y {
+                break;
+            }
+
+            let swatch_left = top_left.x + 5;
+            fill_pattern(
+                backend,
+                pattern,
+                BackendCoord::new(swatch_left, y + 2),
+                BackendCoord::new(swatch_left + swatch_width, y + row_height - 3),
+            )?;
+
+            backend.draw_text(
+                &pattern.name,
+                &text_style,
+                BackendCoord::new(swatch_left + swatch_width + 5, y + row_height / 2),
+            )?;
+
+            y += row_height;
+        }
+
+        Ok(())
+    }
+}
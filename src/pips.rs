@@ -0,0 +1,234 @@
+//! Integrated travel time and borehole volume marks drawn beside the depth
+//! ticks, set with [`crate::log_plot_style::LogPlotStyle::travel_time_pips`]
+//! and [`crate::log_plot_style::LogPlotStyle::borehole_volume_pips`].
+//!
+//! ```ignore
+//! plot_context
+//!     .configure_style()
+//!     // DT in us/ft on a depth in metres
+//!     .travel_time_pips(&Pips::travel_time(
+//!         curves.points("DT").ok_or("missing DT")?,
+//!         las.null_value(),
+//!         3.28084,
+//!     ))
+//!     // caliper in inches on a depth in metres, pips every 0.1 m3 and 1 m3
+//!     .borehole_volume_pips(&Pips::borehole_volume(
+//!         curves.points("CALI").ok_or("missing CALI")?,
+//!         las.null_value(),
+//!         0.00064516,
+//!     ))
+//!     .draw()?;
+//! ```
+
+use chartrs::prelude::Stroke;
+use chartrs_backend::BackendColor;
+
+use crate::curve::is_null;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipKind {
+    /// One way time from slowness, `DT × depth step`
+    TravelTime,
+    /// Hole volume from caliper, `π / 4 × CAL² × depth step`
+    BoreholeVolume,
+}
+
+/// A curve integrated over depth, marked with a small pip every `minor`
+/// and a larger one every `major` of the running total.
+#[derive(Debug, Clone)]
+pub struct Pips {
+    pub kind: PipKind,
+    /// `(value, depth)` samples, as from [`crate::curve::CurveSet::points`]
+    pub samples: Vec<(f64, f64)>,
+    /// Sentinel of missing samples; `NaN` and negative samples are always missing
+    pub null_value: Option<f64>,
+    /// Multiplies each integrated step into the unit of `minor` and `major`
+    pub factor: f64,
+    pub minor: f64,
+    /// A multiple of `minor`
+    pub major: f64,
+    pub stroke: Stroke,
+}
+
+impl Pips {
+    /// Pips every 1 ms and 10 ms of one way time from a slowness curve in
+    /// microseconds per depth unit, with `null_value` its null sentinel.
+    /// `factor` converts the slowness unit to the one of the depth: `1.0` for
+    /// us/ft on a depth in feet, `3.28084` for us/ft on a depth in metres.
+    pub fn travel_time<I>(points: I, null_value: Option<f64>, factor: f64) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Self::new(
+            PipKind::TravelTime,
+            points,
+            null_value,
+            factor,
+            1000.0,
+            10000.0,
+        )
+    }
+
+    /// Pips every `0.1` and `1.0` of hole volume from a caliper curve, with
+    /// `null_value` its null sentinel, in the unit `factor` turns a squared
+    /// caliper times a depth step into, e.g. `0.00064516` for m3 from inches
+    /// on a depth in metres.
+    pub fn borehole_volume<I>(points: I, null_value: Option<f64>, factor: f64) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let mut pips = Self::new(
+            PipKind::BoreholeVolume,
+            points,
+            null_value,
+            factor,
+            0.1,
+            1.0,
+        );
+        pips.stroke = Stroke::new(
+            1.,
+            BackendColor {
+                alpha: 1.0,
+                rgb: (0, 0, 255),
+            },
+        );
+        pips
+    }
+
+    fn new<I>(
+        kind: PipKind,
+        points: I,
+        null_value: Option<f64>,
+        factor: f64,
+        minor: f64,
+        major: f64,
+    ) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        Self {
+            kind,
+            samples: points.into_iter().collect(),
+            null_value,
+            factor,
+            minor,
+            major,
+            stroke: Stroke::new(
+                1.,
+                BackendColor {
+                    alpha: 1.0,
+                    rgb: (0, 0, 0),
+                },
+            ),
+        }
+    }
+
+    /// Running totals between two pips, small and large
+    pub fn steps(&mut self, minor: f64, major: f64) -> &mut Self {
+        self.minor = minor;
+        self.major = major;
+        self
+    }
+
+    pub fn stroke(&mut self, stroke: Stroke) -> &mut Self {
+        self.stroke = stroke;
+        self
+    }
+
+    fn integrand(&self, value: f64) -> f64 {
+        match self.kind {
+            PipKind::TravelTime => value,
+            PipKind::BoreholeVolume => std::f64::consts::FRAC_PI_4 * value * value,
+        }
+    }
+
+    /// A slowness or a caliper cannot be negative, such a sample is missing too
+    fn is_missing(&self, value: f64) -> bool {
+        is_null(value, self.null_value) || value < 0.0
+    }
+
+    /// Depths of the pips as `(depth, is major)`, the total starting from
+    /// the first sample. Steps next to a missing sample add nothing.
+    pub fn marks(&self) -> Vec<(f64, bool)> {
+        if self.minor.is_nan() || self.minor <= 0.0 {
+            return vec![];
+        }
+        let per_major = ((self.major / self.minor).round() as usize).max(1);
+
+        let mut marks = vec![];
+        let (mut total, mut count) = (0.0, 0);
+
+        for pair in self.samples.windows(2) {
+            let ((v0, d0), (v1, d1)) = (pair[0], pair[1]);
+            if self.is_missing(v0) || self.is_missing(v1) || d0.is_nan() || d1.is_nan() {
+                continue;
+            }
+
+            // trapezoid of the step
+            let step =
+                (self.integrand(v0) + self.integrand(v1)) / 2.0 * (d1 - d0).abs() * self.factor;
+            if step.is_nan() || step <= 0.0 {
+                continue;
+            }
+
+            while (count + 1) as f64 * self.minor <= total + step {
+                count += 1;
+                let fraction = (count as f64 * self.minor - total) / step;
+                marks.push((d0 + (d1 - d0) * fraction, count % per_major == 0));
+            }
+            total += step;
+        }
+
+        marks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn marks_every_millisecond_and_every_ten() {
+        // 100 us/ft over 100 ft is 10 ms, a pip every 10 ft
+        let samples = (0..=10).map(|i| (100.0, i as f64 * 10.0));
+        let marks = Pips::travel_time(samples, None, 1.0).marks();
+
+        assert_eq!(marks.len(), 10);
+        for (i, (depth, major)) in marks.iter().enumerate() {
+            assert!((depth - (i + 1) as f64 * 10.0).abs() < 1e-9);
+            assert_eq!(*major, i == 9);
+        }
+    }
+
+    #[test]
+    fn marks_skip_missing_samples() {
+        let samples = vec![
+            (100.0, 0.0),
+            (100.0, 10.0),
+            (-999.25, 20.0),
+            (100.0, 30.0),
+            (f64::NAN, 40.0),
+            (100.0, 50.0),
+            (100.0, 60.0),
+        ];
+        let marks = Pips::travel_time(samples, Some(-999.25), 1.0).marks();
+
+        // only the steps 0..10 and 50..60 add time
+        assert_eq!(marks, vec![(10.0, false), (60.0, false)]);
+    }
+
+    #[test]
+    fn marks_skip_negative_calipers() {
+        // 4 in over 1 m with no null value set is about 0.0081 m3 per metre
+        let mut samples = vec![(4.0, 0.0), (-4.0, 1.0)];
+        samples.extend((2..=20).map(|i| (4.0, i as f64)));
+        let pips = Pips::borehole_volume(samples, None, 0.00064516);
+        let per_metre = std::f64::consts::FRAC_PI_4 * 16.0 * 0.00064516;
+        let marks = pips.marks();
+
+        assert_eq!(marks.len(), 1);
+        let (depth, major) = marks[0];
+        assert!((depth - (2.0 + 0.1 / per_metre)).abs() < 1e-9);
+        assert!(!major);
+    }
+}
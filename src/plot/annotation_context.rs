@@ -0,0 +1,35 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::DrawingArea,
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::builder::LogPlotBuilder;
+
+/// A narrow track of comments on depth intervals.
+pub struct AnnotationContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> AnnotationContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+}
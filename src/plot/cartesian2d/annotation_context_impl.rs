@@ -0,0 +1,148 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{
+    stroke::Stroke,
+    text_anchor::{HPos, Pos, VPos},
+    BackendColor, BackendCoord, BackendTextStyle, DrawingErrorKind,
+};
+
+use crate::{
+    annotation::{stack_labels, wrap_text, Annotation},
+    plot::{annotation_context::AnnotationContext, draw_title},
+};
+
+/// Where the bracket of an interval is drawn, from the left edge of the track
+const BRACKET_X: i32 = 6;
+/// Length of the ticks closing a bracket
+const BRACKET_TICK: i32 = 4;
+/// Where the comments start, from the left edge of the track
+const TEXT_X: i32 = 14;
+/// Least space between two comments
+const TEXT_GAP: i32 = 4;
+
+impl<'a, DB, Y> AnnotationContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    pub fn draw_head(&mut self, title: &str) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Draw each comment word wrapped to the width of the track, next to a
+    /// bracket spanning its interval. Comments that would overlap are moved
+    /// apart and joined to their bracket by a leader line.
+    pub fn draw_annotations(
+        &mut self,
+        annotations: &[Annotation],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_y = |depth: f64| coord.logic_y().map(&depth, coord.back_y());
+
+        let text_style = self
+            .builder
+            .detail_text_style
+            .font_pct(0.8)
+            .pos(Pos::new(HPos::Left, VPos::Top));
+        let line_height = text_style.size() as i32 + 2;
+        let stroke = Stroke::new(
+            1.,
+            BackendColor {
+                alpha: 1.0,
+                rgb: (0, 0, 0),
+            },
+        );
+
+        // the brackets within the track, top to base in pixels
+        let brackets: Vec<(&Annotation, i32, i32)> = annotations
+            .iter()
+            .filter(|annotation| !annotation.top.is_nan() && !annotation.base.is_nan())
+            .map(|annotation| {
+                let (y0, y1) = (to_y(annotation.top), to_y(annotation.base));
+                (annotation, y0.min(y1), y0.max(y1))
+            })
+            .filter(|(_, y0, y1)| *y1 >= top && *y0 <= bottom)
+            .map(|(annotation, y0, y1)| (annotation, y0.max(top), y1.min(bottom)))
+            .collect();
+
+        self.body.backend_ops(|drawing_backend| {
+            let mut blocks = vec![];
+            for (annotation, _, _) in brackets.iter() {
+                let lines = wrap_text(&annotation.text, x1 - x0 - TEXT_X - 2, |text| {
+                    text_style
+                        .layout_box(text)
+                        .map(|((min_x, _), (max_x, _))| max_x - min_x)
+                        .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))
+                })?;
+                blocks.push(lines);
+            }
+
+            // comments centered on their brackets, then moved apart
+            let wanted: Vec<(i32, i32)> = brackets
+                .iter()
+                .zip(blocks.iter())
+                .map(|((_, y0, y1), lines)| {
+                    let height = lines.len() as i32 * line_height;
+                    ((y0 + y1) / 2 - height / 2, height)
+                })
+                .collect();
+            let tops = stack_labels(&wanted, TEXT_GAP, top, bottom);
+
+            for (i, (_, y0, y1)) in brackets.iter().copied().enumerate() {
+                // bracket
+                let x = x0 + BRACKET_X;
+                drawing_backend.draw_line(
+                    BackendCoord::new(x, y0),
+                    BackendCoord::new(x, y1),
+                    &stroke,
+                )?;
+                for y in [y0, y1] {
+                    drawing_backend.draw_line(
+                        BackendCoord::new(x, y),
+                        BackendCoord::new(x + BRACKET_TICK, y),
+                        &stroke,
+                    )?;
+                }
+
+                // leader from the bracket to a comment that had to move
+                let (middle, height) = ((y0 + y1) / 2, wanted[i].1);
+                let text_middle = tops[i] + height / 2;
+                if tops[i] != wanted[i].0 {
+                    drawing_backend.draw_line(
+                        BackendCoord::new(x, middle),
+                        BackendCoord::new(x0 + TEXT_X - 2, text_middle),
+                        &stroke,
+                    )?;
+                }
+
+                // comment
+                for (row, line) in blocks[i].iter().enumerate() {
+                    drawing_backend.draw_text(
+                        line,
+                        &text_style,
+                        BackendCoord::new(x0 + TEXT_X, tops[i] + row as i32 * line_height),
+                    )?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, the comments and the track outline.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        annotations: &[Annotation],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_head(title)?;
+        self.draw_annotations(annotations)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
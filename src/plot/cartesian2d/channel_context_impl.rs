@@ -8,8 +8,12 @@ use chartrs::{
 use chartrs_backend::{stroke::Stroke, BackendCoord};
 
 use crate::{
-    mesh::ChannelContextMeshStyle,
-    plot::channel_context::ChannelContext,
+    dip::{Dip, TadpoleStyles},
+    fill::{crossover_areas, CrossoverFill, FillBaseline},
+    image::{BoreholeImage, ColorBar, ColorMap, Normalization},
+    marker::{draw_marker, CorePoint},
+    mesh::{ChannelContextMeshStyle, XScale},
+    plot::{channel_context::ChannelContext, draw_title},
     plot_legend::{PlotCurve, PlotLegend},
 };
 
@@ -23,24 +27,20 @@ where
         title: &str,
         legends: Vec<PlotCurve>,
     ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
-        let (title_area, detail_area) = self.head.split_vertically(self.builder.title_height);
+        let detail_area = draw_title(&self.head, self.builder, title)?;
 
-        // title
-        let (x, y) = title_area
-            .area_center_of_text(title, &self.builder.head_text_style)
-            .unwrap_or((0, 0));
-
-        title_area.draw_text(
-            title,
-            &self.builder.head_text_style,
-            BackendCoord::new(x as i32, y as i32),
-        )?;
-
-        title_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)?;
-
-        // legend
+        // legend, in the units the curves are drawn in
         let detail_text_style = &self.builder.detail_text_style;
 
+        let legends = legends
+            .iter()
+            .map(|curve| {
+                curve
+                    .to_display(&self.builder.units, self.builder.unit_system)
+                    .0
+            })
+            .collect();
+
         let legend = PlotLegend::with_area(
             &detail_area,
             legends,
@@ -78,7 +78,9 @@ where
         self.body
             .draw_mesh(y_keypoints, x_keypoints, |drawing_backend, mesh_line| {
                 let draw = match mesh_line {
-                    MeshLine::XMesh(_coord, _, _) => mesh_style.draw_x_mesh,
+                    MeshLine::XMesh(_coord, _, _) => {
+                        mesh_style.draw_x_mesh && mesh_style.x_scale == XScale::Linear
+                    }
                     MeshLine::YMesh(_coord, _, _) => mesh_style.draw_y_mesh,
                 };
 
@@ -91,4 +93,424 @@ where
 
         Ok(())
     }
+
+    /// Draw vertical grid lines at `(position across the channel, is bold)`
+    pub fn draw_x_grid(
+        &mut self,
+        lines: &[(f64, bool)],
+        light_line_stroke: &Stroke,
+        bold_line_stroke: &Stroke,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (x0, x1) = self.body.as_coord_spec().back_x();
+        let (y0, y1) = self.body.as_coord_spec().back_y();
+
+        self.body.backend_ops(|drawing_backend| {
+            for (position, bold) in lines {
+                let x = x0 + ((x1 - x0) as f64 * position).round() as i32;
+                let stroke = if *bold {
+                    bold_line_stroke
+                } else {
+                    light_line_stroke
+                };
+
+                drawing_backend.draw_line(
+                    BackendCoord::new(x, y0),
+                    BackendCoord::new(x, y1),
+                    stroke,
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<'a, DB, Y> ChannelContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Shade between the `(value, depth)` points of `curve` and the baseline
+    /// of its [`PlotCurve::fill`], one pixel row at a time. Draw the fill
+    /// before the curve itself so that the line stays on top.
+    pub fn draw_curve_fill<I>(
+        &mut self,
+        curve: &PlotCurve,
+        points: I,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let fill = match &curve.fill {
+            Some(fill) => fill,
+            None => return Ok(()),
+        };
+
+        let (display, conversion) = curve.to_display(&self.builder.units, self.builder.unit_system);
+        let baseline = match fill.baseline {
+            FillBaseline::Value(value) => display.normalize(conversion.apply(value)).unwrap_or(0.0),
+            FillBaseline::LeftEdge => 0.0,
+            FillBaseline::RightEdge => 1.0,
+        };
+
+        let (top, rows) = self.row_positions(curve, points);
+        let to_x = self.x_mapper();
+
+        self.body.backend_ops(|drawing_backend| {
+            for (y, position) in (top..).zip(rows) {
+                let position = match position {
+                    Some(position) => position,
+                    None => continue,
+                };
+                let color = fill.color_at(position, display.denormalize(position));
+
+                drawing_backend.draw_line(
+                    BackendCoord::new(to_x(baseline), y),
+                    BackendCoord::new(to_x(position), y),
+                    &Stroke::new(1., color),
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Shade between two curves, each placed on its own range: with
+    /// `fill.left_color` where `curve_a` is left of `curve_b` and
+    /// `fill.right_color` where it is right of it. The colors switch at the
+    /// depth where the curves cross, interpolated between the samples.
+    pub fn draw_crossover_fill<IA, IB>(
+        &mut self,
+        curve_a: &PlotCurve,
+        points_a: IA,
+        curve_b: &PlotCurve,
+        points_b: IB,
+        fill: &CrossoverFill,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        IA: IntoIterator<Item = (f64, f64)>,
+        IB: IntoIterator<Item = (f64, f64)>,
+    {
+        let coord = self.body.as_coord_spec();
+        let depths = coord.logic_y().range();
+        let areas = crossover_areas(
+            &self.sample_positions(curve_a, points_a),
+            &self.sample_positions(curve_b, points_b),
+            (depths.start, depths.end),
+            fill,
+        );
+        let to_x = self.x_mapper();
+
+        self.body.backend_ops(|drawing_backend| {
+            for (corners, color) in areas {
+                let corners = corners.into_iter().map(|(position, depth)| {
+                    BackendCoord::new(to_x(position), coord.logic_y().map(&depth, coord.back_y()))
+                });
+
+                drawing_backend.fill_polygon(corners, &Stroke::new(1., color))?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw `dips` as tadpoles: a marker at the dip angle on a 0 - 90° scale
+    /// across the channel, see [`ChannelContextMeshStyle::dip_scale`], with a
+    /// tail pointing in the dip azimuth, north being up. Dips outside the
+    /// depth range of the channel are left out.
+    pub fn draw_tadpoles(
+        &mut self,
+        dips: &[Dip],
+        styles: &TadpoleStyles,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.body.as_coord_spec();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_x = self.x_mapper();
+
+        self.body.backend_ops(|drawing_backend| {
+            for dip in dips {
+                if dip.depth.is_nan() || dip.angle.is_nan() {
+                    continue;
+                }
+                let y = coord.logic_y().map(&dip.depth, coord.back_y());
+                if !(top..=bottom).contains(&y) {
+                    continue;
+                }
+
+                let style = styles.get(dip.dip_type);
+                let stroke = Stroke::new(1., style.marker.color);
+                let (x, size) = (to_x(dip.angle / 90.0), style.marker.size);
+
+                draw_marker(drawing_backend, BackendCoord::new(x, y), &style.marker)?;
+
+                // tail
+                if !dip.azimuth.is_nan() {
+                    let (dx, dy) = (
+                        dip.azimuth.to_radians().sin(),
+                        -dip.azimuth.to_radians().cos(),
+                    );
+                    let at = |length: f64| {
+                        BackendCoord::new(
+                            x + (dx * length).round() as i32,
+                            y + (dy * length).round() as i32,
+                        )
+                    };
+
+                    drawing_backend.draw_line(
+                        at(size as f64),
+                        at((size + style.tail_length) as f64),
+                        &stroke,
+                    )?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw `points` with the marker of `curve`, placed across the channel by
+    /// its range and scale like [`ChannelContext::draw_curve`] places a curve,
+    /// with bars spanning the value and depth errors of each point. Points
+    /// outside the range of the channel are left out; their error bars are
+    /// cut at its edges.
+    pub fn draw_core_points(
+        &mut self,
+        curve: &PlotCurve,
+        points: &[CorePoint],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (display, conversion) = curve.to_display(&self.builder.units, self.builder.unit_system);
+        let marker = curve.marker.unwrap_or_default();
+        let stroke = Stroke::new(1., marker.color);
+        let cap = marker.size as i32;
+
+        let coord = self.body.as_coord_spec();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_y = |depth: f64| coord.logic_y().map(&depth, coord.back_y());
+        let to_x = self.x_mapper();
+        let position = |value: f64| display.normalize(conversion.apply(value));
+
+        self.body.backend_ops(|drawing_backend| {
+            for point in points {
+                if curve.is_null(point.value) || point.depth.is_nan() {
+                    continue;
+                }
+                let (x, y) = match position(point.value) {
+                    Some(p) if (0.0..=1.0).contains(&p) => (to_x(p), to_y(point.depth)),
+                    _ => continue,
+                };
+                if !(top..=bottom).contains(&y) {
+                    continue;
+                }
+
+                // value error, across
+                if let Some(error) = point.value_error {
+                    let ends = (position(point.value - error), position(point.value + error));
+                    if let (Some(left), Some(right)) = ends {
+                        let (left, right) = (to_x(left), to_x(right));
+                        drawing_backend.draw_line(
+                            BackendCoord::new(left, y),
+                            BackendCoord::new(right, y),
+                            &stroke,
+                        )?;
+                        for x in [left, right] {
+                            drawing_backend.draw_line(
+                                BackendCoord::new(x, y - cap),
+                                BackendCoord::new(x, y + cap),
+                                &stroke,
+                            )?;
+                        }
+                    }
+                }
+
+                // depth error, down
+                if let Some(error) = point.depth_error {
+                    let ends = (to_y(point.depth - error), to_y(point.depth + error));
+                    let (upper, lower) =
+                        (ends.0.min(ends.1).max(top), ends.0.max(ends.1).min(bottom));
+                    drawing_backend.draw_line(
+                        BackendCoord::new(x, upper),
+                        BackendCoord::new(x, lower),
+                        &stroke,
+                    )?;
+                    for y in [upper, lower] {
+                        drawing_backend.draw_line(
+                            BackendCoord::new(x - cap, y),
+                            BackendCoord::new(x + cap, y),
+                            &stroke,
+                        )?;
+                    }
+                }
+
+                draw_marker(drawing_backend, BackendCoord::new(x, y), &marker)?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the title and a color bar of `color_map` in the head of an image
+    /// channel, labelled with the range of a static normalization in `unit`
+    /// or the window of a dynamic one.
+    pub fn draw_image_head(
+        &mut self,
+        title: &str,
+        color_map: &ColorMap,
+        normalization: &Normalization,
+        unit: &str,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        let labels = match normalization {
+            Normalization::Static { min, max } => {
+                [min.to_string(), unit.to_string(), max.to_string()]
+            }
+            Normalization::Dynamic { window } => [
+                "LOW".to_string(),
+                format!("DYNAMIC {window}"),
+                "HIGH".to_string(),
+            ],
+        };
+
+        let color_bar = ColorBar::with_area(
+            &detail_area,
+            color_map.clone(),
+            labels,
+            self.builder.detail_text_style.font_pct(0.8),
+        );
+
+        detail_area.draw(&color_bar)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Draw `image` as a raster across the channel, its columns from 0° at
+    /// the left edge to 360° at the right one. Each row of samples covers
+    /// the pixel rows halfway to its neighbours on the channel's depth scale;
+    /// missing samples are left blank.
+    pub fn draw_image(
+        &mut self,
+        image: &BoreholeImage,
+        normalization: &Normalization,
+        color_map: &ColorMap,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let normalized = image.normalized(normalization);
+
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (x0, x1) = (x0.min(x1), x0.max(x1));
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        let ys: Vec<i32> = image
+            .depths
+            .iter()
+            .map(|depth| coord.logic_y().map(depth, coord.back_y()))
+            .collect();
+
+        self.body.backend_ops(|drawing_backend| {
+            // rows without a depth, or depths without a row, are left out
+            for (row, (samples, &y)) in normalized.iter().zip(&ys).enumerate() {
+                if samples.is_empty() {
+                    continue;
+                }
+
+                // halfway to the neighbouring rows, mirrored at the ends
+                let previous = row.checked_sub(1).and_then(|i| ys.get(i)).copied();
+                let next = ys.get(row + 1).copied();
+                let (previous, next) = match (previous, next) {
+                    (Some(previous), Some(next)) => (previous, next),
+                    (Some(previous), None) => (previous, 2 * y - previous),
+                    (None, Some(next)) => (2 * y - next, next),
+                    (None, None) => (y, y),
+                };
+                let (from, to) = ((previous + y) / 2, (y + next) / 2);
+                let (from, to) = (from.min(to).max(top), from.max(to).min(bottom));
+
+                for x in x0..=x1 {
+                    let column = ((x - x0) as usize * samples.len() / (x1 - x0 + 1) as usize)
+                        .min(samples.len() - 1);
+                    let color = match samples[column] {
+                        Some(fraction) => color_map.color_at(fraction),
+                        None => continue,
+                    };
+
+                    for y in from..=to {
+                        drawing_backend.draw_pixel(BackendCoord::new(x, y), color)?;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Pixel x of a position across the channel, clamped to the channel
+    fn x_mapper(&self) -> impl Fn(f64) -> i32 {
+        let (x0, x1) = self.body.as_coord_spec().back_x();
+        move |position: f64| x0 + ((x1 - x0) as f64 * position.clamp(0.0, 1.0)).round() as i32
+    }
+
+    /// Position of `curve` across the channel and depth of every sample,
+    /// `None` where the sample is missing.
+    fn sample_positions<I>(&self, curve: &PlotCurve, points: I) -> Vec<Option<(f64, f64)>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let (display, conversion) = curve.to_display(&self.builder.units, self.builder.unit_system);
+
+        points
+            .into_iter()
+            .map(|(value, depth)| {
+                if curve.is_null(value) || depth.is_nan() {
+                    None
+                } else {
+                    display
+                        .normalize(conversion.apply(value))
+                        .map(|position| (position, depth))
+                }
+            })
+            .collect()
+    }
+
+    /// Position of `curve` across the channel on every pixel row of the body,
+    /// linearly interpolated between the samples and `None` where a sample is
+    /// missing. Returns the first row and the positions from there down.
+    fn row_positions<I>(&self, curve: &PlotCurve, points: I) -> (i32, Vec<Option<f64>>)
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let coord = self.body.as_coord_spec();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        let mut rows = vec![None; (bottom - top + 1).max(0) as usize];
+        let mut last: Option<(f64, i32)> = None;
+
+        for sample in self.sample_positions(curve, points) {
+            let sample = sample
+                .map(|(position, depth)| (position, coord.logic_y().map(&depth, coord.back_y())));
+
+            match (last, sample) {
+                (Some((p0, y0)), Some((p1, y1))) => {
+                    for y in y0.min(y1).max(top)..=y0.max(y1).min(bottom) {
+                        let t = if y1 == y0 {
+                            1.0
+                        } else {
+                            (y - y0) as f64 / (y1 - y0) as f64
+                        };
+                        rows[(y - top) as usize] = Some(p0 + (p1 - p0) * t);
+                    }
+                }
+                (None, Some((position, y))) if (top..=bottom).contains(&y) => {
+                    rows[(y - top) as usize] = Some(position);
+                }
+                _ => {}
+            }
+
+            last = sample;
+        }
+
+        (top, rows)
+    }
 }
@@ -0,0 +1,163 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{stroke::Stroke, BackendCoord};
+
+use crate::{
+    completion::{Completion, ComponentKind},
+    pattern::{Pattern, PatternLegend},
+    plot::{completion_context::CompletionContext, draw_title},
+};
+
+/// Space left between the largest diameter and the track edges, in pixels
+const SIDE_MARGIN: i32 = 8;
+/// Length of the perforation shots past the outer diameter, and of the casing shoes
+const SHOT_LENGTH: i32 = 6;
+/// Distance between two perforation shots
+const SHOT_SPACING: i32 = 4;
+
+impl<'a, DB, Y> CompletionContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the title and a swatch for each kind of component of `completion`
+    pub fn draw_head(
+        &mut self,
+        title: &str,
+        completion: &Completion,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        // legend
+        let swatches = completion
+            .kinds()
+            .iter()
+            .map(|kind| Pattern::solid(kind.name(), kind.color()))
+            .collect();
+
+        let legend = PatternLegend::with_area(
+            &detail_area,
+            swatches,
+            self.builder.detail_text_style.font_pct(0.8),
+        );
+
+        detail_area.draw(&legend)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Draw the components of `completion` at their depths, mirrored about
+    /// the center of the track with the largest diameter scaled to nearly its
+    /// full width: cement first, then casing, liners and tubing walls,
+    /// packers and the perforation shots on top.
+    pub fn draw_completion(
+        &mut self,
+        completion: &Completion,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let center = (x0 + x1) / 2;
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_y = |depth: f64| coord.logic_y().map(&depth, coord.back_y());
+
+        let radius = match completion.radius_mapper((x1 - x0) / 2 - SIDE_MARGIN) {
+            Some(radius) => radius,
+            None => return Ok(()),
+        };
+
+        let mut components = completion.components.clone();
+        components.sort_by_key(|component| component.kind.layer());
+
+        self.body.backend_ops(|drawing_backend| {
+            for component in components.iter() {
+                if component.top.is_nan() || component.base.is_nan() {
+                    continue;
+                }
+                let (y0, y1) = (to_y(component.top), to_y(component.base));
+                let (y0, y1) = (y0.min(y1), y0.max(y1));
+                if y1 < top || y0 > bottom {
+                    continue;
+                }
+                let (y0, y1) = (y0.max(top), y1.min(bottom));
+
+                let stroke = Stroke::new(1., component.kind.color());
+                let (outer, inner) = (
+                    radius(component.outer_diameter),
+                    radius(component.inner_diameter),
+                );
+
+                match component.kind {
+                    ComponentKind::Perforation => {
+                        for y in (y0..=y1).step_by(SHOT_SPACING as usize) {
+                            for (from, to) in [
+                                (center - outer, center - outer - SHOT_LENGTH),
+                                (center + outer, center + outer + SHOT_LENGTH),
+                            ] {
+                                drawing_backend.draw_line(
+                                    BackendCoord::new(from, y),
+                                    BackendCoord::new(to, y),
+                                    &stroke,
+                                )?;
+                            }
+                        }
+                    }
+                    _ => {
+                        // the wall or annulus on either side of the center line
+                        for (from, to) in [
+                            (center - outer, center - inner),
+                            (center + inner, center + outer),
+                        ] {
+                            drawing_backend.draw_rect(
+                                BackendCoord::new(from.min(to), y0),
+                                BackendCoord::new(from.max(to), y1),
+                                &stroke,
+                                true,
+                            )?;
+                        }
+
+                        // shoe at the base of the string
+                        if matches!(component.kind, ComponentKind::Casing | ComponentKind::Liner)
+                            && to_y(component.base) <= bottom
+                        {
+                            for side in [-1, 1] {
+                                let wall = center + side * outer;
+                                drawing_backend.fill_polygon(
+                                    [
+                                        BackendCoord::new(wall, y1 - SHOT_LENGTH),
+                                        BackendCoord::new(wall, y1),
+                                        BackendCoord::new(wall + side * SHOT_LENGTH, y1),
+                                    ],
+                                    &stroke,
+                                )?;
+                            }
+                        }
+
+                        // hanger at the top of a liner
+                        if component.kind == ComponentKind::Liner && to_y(component.top) >= top {
+                            drawing_backend.draw_line(
+                                BackendCoord::new(center - outer - SHOT_LENGTH, y0),
+                                BackendCoord::new(center + outer + SHOT_LENGTH, y0),
+                                &Stroke::new(2., component.kind.color()),
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, the schematic of `completion` and the track outline.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        completion: &Completion,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_head(title, completion)?;
+        self.draw_completion(completion)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
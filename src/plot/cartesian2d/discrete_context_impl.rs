@@ -0,0 +1,106 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{stroke::Stroke, BackendCoord};
+
+use crate::{
+    discrete::{discrete_blocks, DiscreteBlock},
+    pattern::{Pattern, PatternLegend},
+    plot::{discrete_context::DiscreteContext, draw_title},
+};
+
+impl<'a, DB, Y> DiscreteContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the title and a swatch and label for each of `codes`; codes
+    /// missing from the table are left out of the legend.
+    pub fn draw_head(
+        &mut self,
+        title: &str,
+        codes: &[i64],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        // code legend
+        let swatches = codes
+            .iter()
+            .filter_map(|code| self.table.get(*code))
+            .map(|entry| Pattern::solid(entry.label.clone(), entry.color))
+            .collect();
+
+        let legend = PatternLegend::with_area(
+            &detail_area,
+            swatches,
+            self.builder.detail_text_style.font_pct(0.8),
+        );
+
+        detail_area.draw(&legend)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Fill each block across the track with the color of its code. Blocks
+    /// of codes missing from the table are left blank.
+    pub fn draw_blocks(
+        &mut self,
+        blocks: &[DiscreteBlock],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_y = |depth: f64| coord.logic_y().map(&depth, coord.back_y());
+
+        let table = &self.table;
+
+        self.body.backend_ops(|drawing_backend| {
+            for block in blocks {
+                let entry = match table.get(block.code) {
+                    Some(entry) => entry,
+                    None => continue,
+                };
+
+                let (y0, y1) = (to_y(block.top), to_y(block.base));
+                let (y0, y1) = (y0.min(y1), y0.max(y1));
+                if y1 < top || y0 > bottom {
+                    continue;
+                }
+
+                drawing_backend.draw_rect(
+                    BackendCoord::new(x0, y0.max(top)),
+                    BackendCoord::new(x1, y1.min(bottom)),
+                    &Stroke::new(1., entry.color),
+                    true,
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, with a legend of the codes found in the `(value, depth)`
+    /// points in the order they first appear, the blocks and the track outline.
+    pub fn draw<I>(
+        &mut self,
+        title: &str,
+        points: I,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let blocks = discrete_blocks(points, self.null_value);
+
+        let mut codes: Vec<i64> = vec![];
+        for block in blocks.iter() {
+            if !codes.contains(&block.code) {
+                codes.push(block.code);
+            }
+        }
+
+        self.draw_head(title, &codes)?;
+        self.draw_blocks(&blocks)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
@@ -0,0 +1,121 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{stroke::Stroke, BackendColor, BackendCoord};
+
+use crate::{
+    pattern::{fill_pattern, PatternLegend},
+    plot::{
+        draw_title,
+        lithology_context::{LithologyContext, LithologyInterval},
+    },
+};
+
+impl<'a, DB, Y> LithologyContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the title and a swatch for each of `codes`; codes missing from
+    /// the pattern library are left out of the legend.
+    pub fn draw_head(
+        &mut self,
+        title: &str,
+        codes: &[&str],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        // legend
+        let patterns = codes
+            .iter()
+            .filter_map(|code| self.patterns.get(code))
+            .cloned()
+            .collect();
+
+        let legend = PatternLegend::with_area(
+            &detail_area,
+            patterns,
+            self.builder.detail_text_style.font_pct(0.8),
+        );
+
+        detail_area.draw(&legend)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)?;
+
+        Ok(())
+    }
+
+    /// Fill each interval with the pattern of its code and mark its top and
+    /// base. Intervals with an unknown code only get their boundaries.
+    pub fn draw_intervals(
+        &mut self,
+        intervals: &[LithologyInterval],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+        let to_y = |depth: f64| coord.logic_y().map(&depth, coord.back_y());
+
+        let patterns = &self.patterns;
+        let boundary = Stroke::new(
+            1.,
+            BackendColor {
+                alpha: 1.0,
+                rgb: (0, 0, 0),
+            },
+        );
+
+        self.body.backend_ops(|drawing_backend| {
+            for interval in intervals {
+                let (y0, y1) = (to_y(interval.top), to_y(interval.base));
+                let (y0, y1) = (y0.min(y1), y0.max(y1));
+                if y1 < top || y0 > bottom {
+                    continue;
+                }
+                let (y0, y1) = (y0.max(top), y1.min(bottom));
+
+                if let Some(pattern) = patterns.get(&interval.code) {
+                    fill_pattern(
+                        drawing_backend,
+                        pattern,
+                        BackendCoord::new(x0, y0),
+                        BackendCoord::new(x1, y1),
+                    )?;
+                }
+
+                for y in [y0, y1] {
+                    drawing_backend.draw_line(
+                        BackendCoord::new(x0, y),
+                        BackendCoord::new(x1, y),
+                        &boundary,
+                    )?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, with a legend of the codes in `intervals` in the order
+    /// they first appear, the intervals and the track outline.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        intervals: &[LithologyInterval],
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let mut codes: Vec<&str> = vec![];
+        for interval in intervals {
+            if !codes
+                .iter()
+                .any(|code| code.eq_ignore_ascii_case(&interval.code))
+            {
+                codes.push(&interval.code);
+            }
+        }
+
+        self.draw_head(title, &codes)?;
+        self.draw_intervals(intervals)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
@@ -1,17 +1,25 @@
+use std::any::Any;
+
 use chartrs::{
     coord::{
         cartesian::{Cartesian2dY, HorizontalLine},
-        ranged1d::KeyPointHint,
+        ranged1d::{KeyPointHint, ValueFormatter},
+        types::RangedCoordf64,
     },
     prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
 };
 use chartrs_backend::{
     stroke::Stroke,
     text_anchor::{HPos, Pos, VPos},
-    BackendCoord, TextStyle,
+    BackendCoord, BackendTextStyle, TextStyle,
 };
 
-use crate::{cartesian_impl::LogPlotDrawingAreaFunc, plot::log_plot_context::LogPlotContext};
+use crate::{
+    cartesian_impl::LogPlotCatesian2dYFunc,
+    plot::log_plot_context::LogPlotContext,
+    tops::{label_tops, FormationTop, TopsLabelTrack},
+    unit::Conversion,
+};
 
 impl<'a, DB, Y> LogPlotContext<'a, DB, Cartesian2dY<Y>>
 where
@@ -60,16 +68,34 @@ where
         YH: KeyPointHint,
         FmtLabel: Fn(&Y, &HorizontalLine<Y>) -> Option<String>,
     {
+        let coord = self.depth_body.as_coord_spec();
+        let y_range = coord.y_spec();
+
+        let mut ticks = vec![];
+        coord.draw_plot_horizontal(y_keypoints, |mesh_line, is_dark| {
+            let label = if is_dark { fmt_label(y_range, &mesh_line) } else { None };
+            ticks.push((mesh_line.0, is_dark, label));
+            Ok::<_, DrawingAreaErrorKind<DB::ErrorType>>(())
+        })?;
+
+        self.draw_depth_ticks(ticks, light_axis_style, dark_axis_style, label_style, tick_size)
+    }
+
+    /// Draw ticks at the right edge of the depth area, `(left end, is dark, label)`
+    pub(crate) fn draw_depth_ticks(
+        &self,
+        ticks: Vec<(BackendCoord, bool, Option<String>)>,
+        light_axis_style: &Stroke,
+        dark_axis_style: &Stroke,
+        label_style: &TextStyle,
+        tick_size: i32,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
         let label_dist = tick_size.abs();
         let (area_width, _) = self.depth_body.dim_in_pixel();
         let area_width = area_width as i32;
 
-        let y_range = self.depth_body.as_coord_spec().y_spec();
-
-        self.depth_body
-            .draw_plot_horizontal(y_keypoints, |drawing_backend, mesh_line, is_dark| {
-                let coord = mesh_line.0;
-
+        self.depth_body.backend_ops(|drawing_backend| {
+            for (coord, is_dark, label) in ticks {
                 // draw dark tick and label
                 if is_dark {
                     let tick_size = tick_size + 5;
@@ -86,7 +112,7 @@ where
                         dark_axis_style,
                     )?;
 
-                    if let Some(label_text) = fmt_label(y_range, &mesh_line) {
+                    if let Some(label_text) = label {
                         drawing_backend.draw_text(
                             label_text.as_str(),
                             &label_style.pos(Pos::new(HPos::Right, VPos::Center)),
@@ -101,10 +127,191 @@ where
                         light_axis_style,
                     )?;
                 }
+            }
 
-                Ok(())
-            })?;
+            Ok(())
+        })
+    }
 
-        Ok(())
+    /// Draw a pip at each of `marks`, `(depth, is major)`, pointing right
+    /// from `offset` pixels past the left edge of the depth area.
+    pub fn draw_depth_area_pips(
+        &mut self,
+        marks: &[(Y::ValueType, bool)],
+        offset: i32,
+        stroke: &Stroke,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.depth_body.as_coord_spec();
+        let (left, _) = coord.back_x();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        self.depth_body.backend_ops(|drawing_backend| {
+            for (depth, is_major) in marks {
+                let y = coord.logic_y().map(depth, coord.back_y());
+                if !(top..=bottom).contains(&y) {
+                    continue;
+                }
+
+                let length = if *is_major {
+                    MAJOR_PIP_LENGTH
+                } else {
+                    MINOR_PIP_LENGTH
+                };
+                drawing_backend.draw_line(
+                    BackendCoord::new(left + offset, y),
+                    BackendCoord::new(left + offset + length, y),
+                    stroke,
+                )?;
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Ticks for [`Self::draw_depth_ticks`] at round values of the depth
+    /// converted by `conversion`, the unit the labels are shown in, rather
+    /// than at round values of the depth itself. `None` unless the depth is
+    /// an `f64`, the only one a conversion applies to.
+    pub(crate) fn converted_depth_ticks<YH>(
+        &self,
+        y_keypoints: YH,
+        conversion: Conversion,
+        fmt_label: Option<&dyn Fn(&Y::ValueType) -> String>,
+    ) -> Option<Vec<(BackendCoord, bool, Option<String>)>>
+    where
+        YH: KeyPointHint,
+        Y::ValueType: 'static,
+    {
+        let coord = self.depth_body.as_coord_spec();
+        let (left, _) = coord.back_x();
+
+        let range = coord.logic_y().range();
+        let (start, end) = (as_f64(&range.start)?, as_f64(&range.end)?);
+        let (start, end) = (conversion.apply(start), conversion.apply(end));
+        let display_range: RangedCoordf64 = (start.min(end)..start.max(end)).into();
+
+        // every k-th tick is a dark one, as on the unconverted scale
+        let k = (y_keypoints.max_points() as f32 / y_keypoints.bold_points() as f32).round() as usize + 1;
+        let inverse = conversion.inverse();
+
+        display_range
+            .key_points(y_keypoints)
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let y = coord.logic_y().map(from_f64(&inverse.apply(value))?, coord.back_y());
+                let is_dark = i % k == 0;
+                let label = match (is_dark, fmt_label) {
+                    (false, _) => None,
+                    (true, Some(fmt_label)) => Some(fmt_label(from_f64(&value)?)),
+                    (true, None) => Some(display_range.format_ext(&value)),
+                };
+                Some((BackendCoord::new(left, y), is_dark, label))
+            })
+            .collect()
+    }
+}
+
+/// `value` when `T` is `f64`
+fn as_f64<T: 'static>(value: &T) -> Option<f64> {
+    (value as &dyn Any).downcast_ref::<f64>().copied()
+}
+
+/// `value` as a `T` when `T` is `f64`
+fn from_f64<T: 'static>(value: &f64) -> Option<&T> {
+    (value as &dyn Any).downcast_ref::<T>()
+}
+
+/// Length of the pips of integrated curves, in pixels
+const MINOR_PIP_LENGTH: i32 = 4;
+const MAJOR_PIP_LENGTH: i32 = 10;
+
+impl<'a, DB, Y> LogPlotContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw a line across the depth area and every track at the depth of each
+    /// top, with its name above the line in `label_track`. Names that would
+    /// overlap are moved apart and joined to their line by a leader. Call it
+    /// once all tracks are added and drawn so that the lines stay on top.
+    pub fn draw_tops(
+        &mut self,
+        tops: &[FormationTop],
+        label_track: TopsLabelTrack,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let coord = self.depth_body.as_coord_spec();
+        let (left, depth_right) = coord.back_x();
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        let right = self
+            .track_spans
+            .iter()
+            .map(|span| span.end)
+            .max()
+            .unwrap_or(depth_right);
+        let label_left = match label_track {
+            TopsLabelTrack::Track(i) => self.track_spans.get(i).map_or(left, |span| span.start),
+            TopsLabelTrack::Depth => left,
+        };
+
+        let text_style = self
+            .builder
+            .detail_text_style
+            .font_pct(0.8)
+            .pos(Pos::new(HPos::Left, VPos::Top));
+        let text_height = text_style.size() as i32 + 2;
+
+        let visible: Vec<(&FormationTop, i32)> = tops
+            .iter()
+            .filter(|formation_top| !formation_top.depth.is_nan())
+            .map(|formation_top| {
+                (
+                    formation_top,
+                    coord.logic_y().map(&formation_top.depth, coord.back_y()),
+                )
+            })
+            .filter(|(_, y)| (top..=bottom).contains(y))
+            .collect();
+
+        let lines: Vec<i32> = visible.iter().map(|(_, y)| *y).collect();
+        let labels = label_tops(&lines, text_height, top, bottom);
+
+        self.depth_body.backend_ops(|drawing_backend| {
+            for (i, (formation_top, y)) in visible.iter().enumerate() {
+                drawing_backend.draw_line(
+                    BackendCoord::new(left, *y),
+                    BackendCoord::new(right, *y),
+                    &formation_top.stroke,
+                )?;
+
+                // leader from the line to a name that had to move
+                let (label_top, moved) = labels[i];
+                if moved {
+                    let leader = &formation_top.stroke;
+                    let middle = label_top + text_height / 2;
+                    drawing_backend.draw_line(
+                        BackendCoord::new(label_left + 2, *y),
+                        BackendCoord::new(label_left + 2, middle),
+                        leader,
+                    )?;
+                    drawing_backend.draw_line(
+                        BackendCoord::new(label_left + 2, middle),
+                        BackendCoord::new(label_left + 4, middle),
+                        leader,
+                    )?;
+                }
+
+                drawing_backend.draw_text(
+                    &formation_top.name,
+                    &text_style,
+                    BackendCoord::new(label_left + 5, label_top),
+                )?;
+            }
+
+            Ok(())
+        })
     }
 }
@@ -11,8 +11,14 @@ use crate::{
 
 use crate::plot::log_plot_context::LogPlotContext;
 
+pub(crate) mod annotation_context_impl;
 pub(crate) mod channel_context_impl;
+pub(crate) mod completion_context_impl;
+pub(crate) mod discrete_context_impl;
+pub(crate) mod lithology_context_impl;
 pub(crate) mod log_plot_context_impl;
+pub(crate) mod spectrum_context_impl;
+pub(crate) mod waveform_context_impl;
 
 impl<'a, DB, YT, Y> LogPlotContext<'a, DB, Cartesian2dY<Y>>
 where
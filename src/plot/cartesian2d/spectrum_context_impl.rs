@@ -0,0 +1,218 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{stroke::Stroke, BackendCoord};
+
+use crate::{
+    image::ColorBar,
+    nmr::{stacked_traces, SpectrumDisplay, T2Spectra},
+    pattern::BLACK,
+    plot::{draw_title, spectrum_context::SpectrumContext},
+    plot_legend::{CurveScale, PlotCurve, PlotLegend},
+};
+
+impl<'a, DB, Y> SpectrumContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the title and the logarithmic T2 time scale of the track, in
+    /// milliseconds, with the cutoff; a raster display adds its color bar.
+    pub fn draw_head(&mut self, title: &str) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        // time scale
+        let name = match self.cutoff {
+            Some(cutoff) => format!("T2  CUTOFF {cutoff}"),
+            None => "T2".to_string(),
+        };
+
+        let mut time = PlotCurve::default();
+        time.name(name)
+            .range(self.time_range.0 as f32, self.time_range.1 as f32, "ms")
+            .scale(CurveScale::Logarithmic)
+            .stroke(Stroke::new(1., BLACK));
+
+        let (scale_area, color_area) = match &self.display {
+            SpectrumDisplay::Raster(_) => {
+                let (scale_area, color_area) =
+                    detail_area.split_vertically(detail_area.dim_in_pixel().1 as i32 / 2);
+                (scale_area, Some(color_area))
+            }
+            SpectrumDisplay::Stacked { .. } => (detail_area.clone(), None),
+        };
+
+        let detail_text_style = &self.builder.detail_text_style;
+        let legend = PlotLegend::with_area(
+            &scale_area,
+            vec![time],
+            detail_text_style.clone(),
+            detail_text_style.font_pct(0.8),
+        );
+
+        scale_area.draw(&legend)?;
+
+        // color bar
+        if let (SpectrumDisplay::Raster(color_map), Some(color_area)) = (&self.display, color_area)
+        {
+            let max = match self.max_amplitude {
+                Some(max) => max.to_string(),
+                None => "MAX".to_string(),
+            };
+
+            let color_bar = ColorBar::with_area(
+                &color_area,
+                color_map.clone(),
+                ["0".to_string(), "AMPLITUDE".to_string(), max],
+                detail_text_style.font_pct(0.8),
+            );
+
+            color_area.draw(&color_bar)?;
+        }
+
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Draw the distributions of `spectra` at their depths, as set by
+    /// [`SpectrumContext::display`], then the cutoff line. Bins outside the
+    /// time range are left out.
+    pub fn draw_spectra(
+        &mut self,
+        spectra: &T2Spectra,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (start, end) = self.time_range;
+        if start <= 0.0 || end <= 0.0 || start == end {
+            return Ok(());
+        }
+
+        let max_amplitude = self
+            .max_amplitude
+            .unwrap_or_else(|| spectra.max_amplitude());
+
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (x0, x1) = (x0.min(x1), x0.max(x1));
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        let ys: Vec<i32> = spectra
+            .depths
+            .iter()
+            .map(|depth| coord.logic_y().map(depth, coord.back_y()))
+            .collect();
+
+        // logarithmic time axis
+        let span = end.ln() - start.ln();
+        let width = (x1 - x0).max(1) as f64;
+        let time_at = |x: i32| (start.ln() + span * (x - x0) as f64 / width).exp();
+        let x_of = |time: f64| x0 + ((time.ln() - start.ln()) / span * width).round() as i32;
+
+        let (display, cutoff, cutoff_stroke, trace_spacing) = (
+            &self.display,
+            self.cutoff,
+            self.cutoff_stroke,
+            self.trace_spacing,
+        );
+
+        self.body.backend_ops(|drawing_backend| {
+            if max_amplitude > 0.0 {
+                match display {
+                    SpectrumDisplay::Raster(color_map) => {
+                        for (index, y) in ys.iter().copied().enumerate() {
+                            // halfway to the neighbouring distributions, mirrored at the ends
+                            let previous = index.checked_sub(1).and_then(|i| ys.get(i)).copied();
+                            let next = ys.get(index + 1).copied();
+                            let (previous, next) = match (previous, next) {
+                                (Some(previous), Some(next)) => (previous, next),
+                                (Some(previous), None) => (previous, 2 * y - previous),
+                                (None, Some(next)) => (2 * y - next, next),
+                                (None, None) => (y, y),
+                            };
+                            let (from, to) = ((previous + y) / 2, (y + next) / 2);
+                            let (from, to) = (from.min(to).max(top), from.max(to).min(bottom));
+
+                            for x in x0..=x1 {
+                                let amplitude = match spectra.amplitude_at(index, time_at(x)) {
+                                    Some(amplitude) => amplitude,
+                                    None => continue,
+                                };
+                                let color = color_map.color_at(amplitude / max_amplitude);
+
+                                for y in from..=to {
+                                    drawing_backend.draw_pixel(BackendCoord::new(x, y), color)?;
+                                }
+                            }
+                        }
+                    }
+                    SpectrumDisplay::Stacked { stroke, fill } => {
+                        let scale = trace_spacing as f64 / max_amplitude;
+
+                        for index in stacked_traces(&ys, top, bottom, trace_spacing) {
+                            let y = ys[index];
+
+                            // cut at the top of the track rather than rising into the head
+                            let height = |x: i32| {
+                                spectra.amplitude_at(index, time_at(x)).map(|amplitude| {
+                                    ((amplitude.max(0.0) * scale).round() as i32).min(y - top)
+                                })
+                            };
+
+                            if let Some(fill) = fill {
+                                for x in x0..=x1 {
+                                    match height(x) {
+                                        Some(dy) if dy > 0 => drawing_backend.draw_line(
+                                            BackendCoord::new(x, y),
+                                            BackendCoord::new(x, y - dy),
+                                            &Stroke::new(1., *fill),
+                                        )?,
+                                        _ => {}
+                                    }
+                                }
+                            }
+
+                            // the distribution, broken where there is no bin
+                            let mut line: Vec<BackendCoord> = vec![];
+                            for x in x0..=x1 {
+                                match height(x) {
+                                    Some(dy) => line.push(BackendCoord::new(x, y - dy)),
+                                    None if !line.is_empty() => {
+                                        drawing_backend.draw_path(line.drain(..), stroke)?
+                                    }
+                                    None => {}
+                                }
+                            }
+                            if !line.is_empty() {
+                                drawing_backend.draw_path(line, stroke)?;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if let Some(cutoff) = cutoff.filter(|cutoff| *cutoff > 0.0) {
+                let x = x_of(cutoff);
+                if (x0..=x1).contains(&x) {
+                    drawing_backend.draw_line(
+                        BackendCoord::new(x, top),
+                        BackendCoord::new(x, bottom),
+                        &cutoff_stroke,
+                    )?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, the distributions of `spectra` and the track outline.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        spectra: &T2Spectra,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_head(title)?;
+        self.draw_spectra(spectra)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
@@ -0,0 +1,164 @@
+use chartrs::{
+    coord::cartesian::Cartesian2dY,
+    prelude::{DrawingAreaErrorKind, DrawingBackend, Ranged},
+};
+use chartrs_backend::{stroke::Stroke, BackendCoord};
+
+use crate::{
+    image::ColorMap,
+    pattern::BLACK,
+    plot::{draw_title, waveform_context::WaveformContext},
+    plot_legend::{PlotCurve, PlotLegend},
+    waveform::{WaveformDisplay, Waveforms},
+};
+
+impl<'a, DB, Y> WaveformContext<'a, DB, Cartesian2dY<Y>>
+where
+    DB: DrawingBackend,
+    Y: Ranged<ValueType = f64>,
+{
+    /// Draw the title and the time scale of the track, in microseconds
+    pub fn draw_head(&mut self, title: &str) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let detail_area = draw_title(&self.head, self.builder, title)?;
+
+        // time scale
+        let mut time = PlotCurve::default();
+        time.name("TIME")
+            .range(self.time_range.0 as f32, self.time_range.1 as f32, "us")
+            .stroke(Stroke::new(1., BLACK));
+
+        let detail_text_style = &self.builder.detail_text_style;
+        let legend = PlotLegend::with_area(
+            &detail_area,
+            vec![time],
+            detail_text_style.clone(),
+            detail_text_style.font_pct(0.8),
+        );
+
+        detail_area.draw(&legend)?;
+        detail_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+
+    /// Draw the traces of `waveforms` at their depths, as set by
+    /// [`WaveformContext::display`]. Samples outside the time range are left out.
+    pub fn draw_waveforms(
+        &mut self,
+        waveforms: &Waveforms,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let max_amplitude = waveforms.max_amplitude();
+        if max_amplitude == 0.0 {
+            return Ok(());
+        }
+
+        let coord = self.body.as_coord_spec();
+        let (x0, x1) = coord.back_x();
+        let (x0, x1) = (x0.min(x1), x0.max(x1));
+        let (top, bottom) = coord.back_y();
+        let (top, bottom) = (top.min(bottom), top.max(bottom));
+
+        let ys: Vec<i32> = waveforms
+            .depths
+            .iter()
+            .map(|depth| coord.logic_y().map(depth, coord.back_y()))
+            .collect();
+
+        let (start, end) = self.time_range;
+        let time_at = |x: i32| start + (end - start) * (x - x0) as f64 / (x1 - x0).max(1) as f64;
+
+        let (display, gain, trace_spacing) = (self.display, self.gain, self.trace_spacing);
+
+        self.body.backend_ops(|drawing_backend| {
+            match display {
+                WaveformDisplay::Wiggle { stroke, fill } => {
+                    let scale = gain * trace_spacing as f64 / max_amplitude;
+                    let mut last_y: Option<i32> = None;
+
+                    for (trace, y) in ys.iter().copied().enumerate() {
+                        if !(top..=bottom).contains(&y)
+                            || last_y.is_some_and(|last| (y - last).abs() < trace_spacing)
+                        {
+                            continue;
+                        }
+                        last_y = Some(y);
+
+                        let deflection = |x: i32| {
+                            waveforms
+                                .amplitude_at(trace, time_at(x))
+                                .map(|amplitude| (amplitude * scale).round() as i32)
+                        };
+
+                        // positive lobes
+                        if let Some(fill) = fill {
+                            for x in x0..=x1 {
+                                match deflection(x) {
+                                    Some(dy) if dy > 0 => drawing_backend.draw_line(
+                                        BackendCoord::new(x, y),
+                                        BackendCoord::new(x, y - dy),
+                                        &Stroke::new(1., fill),
+                                    )?,
+                                    _ => {}
+                                }
+                            }
+                        }
+
+                        // the trace, broken where there is no sample
+                        let mut line: Vec<BackendCoord> = vec![];
+                        for x in x0..=x1 {
+                            match deflection(x) {
+                                Some(dy) => line.push(BackendCoord::new(x, y - dy)),
+                                None if !line.is_empty() => {
+                                    drawing_backend.draw_path(line.drain(..), &stroke)?
+                                }
+                                None => {}
+                            }
+                        }
+                        if !line.is_empty() {
+                            drawing_backend.draw_path(line, &stroke)?;
+                        }
+                    }
+                }
+                WaveformDisplay::VariableDensity => {
+                    for (trace, y) in ys.iter().copied().enumerate() {
+                        // halfway to the neighbouring traces, mirrored at the ends
+                        let previous = trace.checked_sub(1).and_then(|i| ys.get(i)).copied();
+                        let next = ys.get(trace + 1).copied();
+                        let (previous, next) = match (previous, next) {
+                            (Some(previous), Some(next)) => (previous, next),
+                            (Some(previous), None) => (previous, 2 * y - previous),
+                            (None, Some(next)) => (2 * y - next, next),
+                            (None, None) => (y, y),
+                        };
+                        let (from, to) = ((previous + y) / 2, (y + next) / 2);
+                        let (from, to) = (from.min(to).max(top), from.max(to).min(bottom));
+
+                        for x in x0..=x1 {
+                            let amplitude = match waveforms.amplitude_at(trace, time_at(x)) {
+                                Some(amplitude) => amplitude,
+                                None => continue,
+                            };
+                            let color = ColorMap::Grayscale
+                                .color_at((1.0 - amplitude / max_amplitude) / 2.0);
+
+                            for y in from..=to {
+                                drawing_backend.draw_pixel(BackendCoord::new(x, y), color)?;
+                            }
+                        }
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Draw the head, the traces of `waveforms` and the track outline.
+    pub fn draw(
+        &mut self,
+        title: &str,
+        waveforms: &Waveforms,
+    ) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        self.draw_head(title)?;
+        self.draw_waveforms(waveforms)?;
+        self.body.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
+}
@@ -1,11 +1,16 @@
-use std::borrow::Borrow;
+use std::{borrow::Borrow, ops::Range};
 
-use crate::{builder::LogPlotBuilder, plot_legend::PlotLegend, series::LogGraphSeries};
+use crate::{
+    builder::LogPlotBuilder,
+    plot_legend::{PlotCurve, PlotLegend},
+    series::{clip_to_range, split_at_gaps, LogGraphSeries},
+};
 
 use chartrs::{
     coord::{ranged1d::AsRangedCoord, CoordTranslate, Shift},
     element::{CoordMapper, Drawable, PointCollection},
-    prelude::{Cartesian2d, DrawingArea, DrawingAreaErrorKind},
+    prelude::{Cartesian2d, DrawingArea, DrawingAreaErrorKind, Stroke},
+    series::LineSeries,
 };
 use chartrs_backend::{BackendCoord, DrawingBackend};
 
@@ -85,6 +90,9 @@ where
         Ok(self.alloc_series_anno())
     }
 
+    /// Draws a data series on the body with its own coordinates, `x_spec`
+    /// across the channel and `y_spec` down it. A range may run backwards,
+    /// e.g. `0.45..-0.15`, to draw a reversed axis.
     pub fn draw_series_with_range<Y, B, E, R, S>(
         &mut self,
         x_spec: Y,
@@ -121,4 +129,74 @@ where
 
         Ok(self.alloc_series_anno())
     }
+
+    /// Draws the `(value, depth)` points of `curve` within its legend range.
+    ///
+    /// The line is broken at missing samples (`NaN` or the curve's
+    /// `null_value`) rather than drawn to the sentinel, unless the gap is
+    /// shorter than the curve's `gap_interpolation` length. Values are
+    /// converted to the unit the curve is displayed in, see
+    /// [`LogPlotBuilder::unit_system`], and placed according to the curve's
+    /// scale; values a logarithmic scale cannot show are treated as missing.
+    /// Values beyond `max` wrap onto the curve's backup scale, if any, once:
+    /// see [`PlotCurve::backup_scale`].
+    pub fn draw_curve<I>(
+        &mut self,
+        y_spec: Range<f64>,
+        curve: &PlotCurve,
+        points: I,
+    ) -> Result<&mut LogGraphSeries<'a, DB>, DrawingAreaErrorKind<DB::ErrorType>>
+    where
+        I: IntoIterator<Item = (f64, f64)>,
+    {
+        let (display, conversion) = curve.to_display(&self.builder.units, self.builder.unit_system);
+
+        // position across the channel, NaN when missing
+        let points = points.into_iter().map(|(value, depth)| {
+            let position = if curve.is_null(value) {
+                None
+            } else {
+                display.normalize(conversion.apply(value))
+            };
+            (position.unwrap_or(f64::NAN), depth)
+        });
+
+        let segments = split_at_gaps(points, f64::is_nan, curve.gap_interpolation);
+
+        // with a backup scale the line stops at the right edge and the excess
+        // wraps around, one track width further along the range
+        let lines: Vec<(Vec<(f64, f64)>, Stroke)> = match display.backup_curve() {
+            None => segments
+                .into_iter()
+                .map(|segment| (segment, display.stroke))
+                .collect(),
+            Some(backup) => segments
+                .iter()
+                .flat_map(|segment| {
+                    let wrapped: Vec<(f64, f64)> = segment
+                        .iter()
+                        .map(|(position, depth)| (position - 1.0, *depth))
+                        .collect();
+
+                    clip_to_range(segment, f64::NEG_INFINITY, 1.0)
+                        .into_iter()
+                        .map(|part| (part, display.stroke))
+                        .chain(
+                            clip_to_range(&wrapped, 0.0, 1.0)
+                                .into_iter()
+                                .map(|part| (part, backup.stroke)),
+                        )
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+        };
+
+        self.draw_series_with_range(
+            0.0..1.0,
+            y_spec,
+            lines
+                .into_iter()
+                .flat_map(|(line, stroke)| LineSeries::new(line, stroke)),
+        )
+    }
 }
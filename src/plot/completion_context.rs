@@ -0,0 +1,35 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::DrawingArea,
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::builder::LogPlotBuilder;
+
+/// A completion schematic track, drawn symmetric about its center line.
+pub struct CompletionContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> CompletionContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+}
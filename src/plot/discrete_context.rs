@@ -0,0 +1,49 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::DrawingArea,
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::{builder::LogPlotBuilder, discrete::DiscreteTable};
+
+/// A discrete track: a curve of codes drawn as colored blocks looked up in `table`.
+pub struct DiscreteContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub table: DiscreteTable,
+    /// Sentinel of missing samples, e.g. `-999.25`. `NaN` is always missing.
+    pub null_value: Option<f64>,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> DiscreteContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+
+    pub fn table(&mut self, table: DiscreteTable) -> &mut Self {
+        self.table = table;
+        self
+    }
+
+    pub fn null_value(&mut self, null_value: Option<f64>) -> &mut Self {
+        self.null_value = null_value;
+        self
+    }
+}
@@ -0,0 +1,64 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::DrawingArea,
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::{builder::LogPlotBuilder, pattern::PatternLibrary};
+
+/// A depth interval of one rock type, `code` being looked up in the
+/// track's [`PatternLibrary`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LithologyInterval {
+    pub top: f64,
+    pub base: f64,
+    pub code: String,
+}
+
+impl LithologyInterval {
+    pub fn new<C>(top: f64, base: f64, code: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self {
+            top,
+            base,
+            code: code.into(),
+        }
+    }
+}
+
+/// A lithology column: intervals filled with geological patterns instead of curves.
+pub struct LithologyContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub patterns: PatternLibrary,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> LithologyContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+
+    /// The patterns intervals are drawn with, to add user defined ones
+    pub fn patterns_mut(&mut self) -> &mut PatternLibrary {
+        &mut self.patterns
+    }
+}
@@ -1,11 +1,25 @@
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Range};
 
 use chartrs::{
     coord::{cartesian::Cartesian2dY, ranged1d::AsRangedCoord, CoordTranslate, Shift},
-    prelude::{DrawingArea, DrawingBackend},
+    prelude::{DrawingArea, DrawingAreaErrorKind, DrawingBackend, Stroke},
 };
+use chartrs_backend::BackendColor;
 
-use crate::{builder::LogPlotBuilder, plot::channel_context::ChannelContext};
+use crate::{
+    builder::LogPlotBuilder,
+    discrete::DiscreteTable,
+    nmr::SpectrumDisplay,
+    pattern::PatternLibrary,
+    plot::{
+        annotation_context::AnnotationContext, channel_context::ChannelContext,
+        completion_context::CompletionContext, discrete_context::DiscreteContext,
+        lithology_context::LithologyContext, spectrum_context::SpectrumContext,
+        waveform_context::WaveformContext,
+    },
+    waveform::WaveformDisplay,
+    well_header::WellHeader,
+};
 
 pub struct LogPlotContext<'a, DB, CT>
 where
@@ -16,6 +30,10 @@ where
     pub(crate) depth_body: DrawingArea<DB, CT>,
 
     pub(crate) drawing_area: DrawingArea<DB, Shift>,
+    /// Pixel columns of the tracks added so far, in order
+    pub(crate) track_spans: Vec<Range<i32>>,
+
+    pub(crate) well_header: Option<DrawingArea<DB, Shift>>,
 
     pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
 }
@@ -32,9 +50,7 @@ where
     where
         Y: AsRangedCoord + Clone + Debug,
     {
-        let (left, right) = self.drawing_area.split_horizontally(200);
-        self.drawing_area = right;
-        let (head, body) = left.split_vertically(self.builder.head_height);
+        let (head, body) = self.split_track(200);
 
         ChannelContext {
             head,
@@ -44,4 +60,161 @@ where
             plot_legend: None,
         }
     }
+
+    /// Add a lithology column `width` pixels wide, filled with the standard
+    /// patterns of [`PatternLibrary::default`].
+    pub fn add_lithology_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> LithologyContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        LithologyContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            patterns: PatternLibrary::default(),
+            builder: self.builder,
+        }
+    }
+
+    /// Add an annotation track `width` pixels wide, for comments on depth intervals.
+    pub fn add_annotation_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> AnnotationContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        AnnotationContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            builder: self.builder,
+        }
+    }
+
+    /// Add a completion schematic track `width` pixels wide.
+    pub fn add_completion_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> CompletionContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        CompletionContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            builder: self.builder,
+        }
+    }
+
+    /// Add a discrete track `width` pixels wide; its codes are looked up in
+    /// [`DiscreteContext::table`], empty until filled.
+    pub fn add_discrete_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> DiscreteContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        DiscreteContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            table: DiscreteTable::default(),
+            null_value: None,
+            builder: self.builder,
+        }
+    }
+
+    /// Add a waveform track `width` pixels wide, drawing wiggle traces over
+    /// 0 - 1000 us until configured otherwise.
+    pub fn add_waveform_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> WaveformContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        WaveformContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            display: WaveformDisplay::default(),
+            time_range: (0.0, 1000.0),
+            gain: 1.0,
+            trace_spacing: 6,
+            builder: self.builder,
+        }
+    }
+
+    /// Add a spectrum track `width` pixels wide for NMR T2 distributions,
+    /// from 0.3 ms to 3000 ms with a 33 ms cutoff.
+    pub fn add_spectrum_track<Y>(
+        &mut self,
+        y_spec: Y,
+        width: i32,
+    ) -> SpectrumContext<'a, DB, Cartesian2dY<Y::CoordDescType>>
+    where
+        Y: AsRangedCoord + Clone + Debug,
+    {
+        let (head, body) = self.split_track(width);
+
+        SpectrumContext {
+            head,
+            body: body.apply_coord_spec(Cartesian2dY::new(y_spec.clone(), body.get_pixel_range())),
+            display: SpectrumDisplay::default(),
+            time_range: (0.3, 3000.0),
+            cutoff: Some(33.0),
+            cutoff_stroke: Stroke::new(
+                2.,
+                BackendColor {
+                    alpha: 1.0,
+                    rgb: (255, 0, 0),
+                },
+            ),
+            max_amplitude: None,
+            trace_spacing: 6,
+            builder: self.builder,
+        }
+    }
+
+    /// Take the next `width` pixels of the plot as the head and body of a track
+    fn split_track(&mut self, width: i32) -> (DrawingArea<DB, Shift>, DrawingArea<DB, Shift>) {
+        let (left, right) = self.drawing_area.split_horizontally(width);
+        self.drawing_area = right;
+        self.track_spans.push(left.get_pixel_range().0);
+        left.split_vertically(self.builder.head_height)
+    }
+
+    /// Draw the log heading reserved with [`LogPlotBuilder::well_header`], if any.
+    pub fn draw_well_header(&self) -> Result<(), DrawingAreaErrorKind<DB::ErrorType>> {
+        let (area, info) = match (&self.well_header, &self.builder.well_info) {
+            (Some(area), Some(info)) => (area, info),
+            _ => return Ok(()),
+        };
+
+        let header = WellHeader::with_area(
+            area,
+            info.clone(),
+            self.builder.head_text_style.clone(),
+            self.builder.detail_text_style.clone(),
+        );
+
+        area.draw(&header)?;
+        area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)
+    }
 }
@@ -1,3 +1,41 @@
+use chartrs::{
+    coord::Shift,
+    prelude::{DrawingArea, DrawingAreaErrorKind},
+};
+use chartrs_backend::{stroke::Stroke, BackendCoord, DrawingBackend};
+
+use crate::builder::LogPlotBuilder;
+
+pub mod annotation_context;
 pub mod cartesian2d;
 pub mod channel_context;
+pub mod completion_context;
+pub mod discrete_context;
+pub mod lithology_context;
 pub mod log_plot_context;
+pub mod spectrum_context;
+pub mod waveform_context;
+
+/// Draw `title` in the title row of a track `head` and return the area under
+/// it, the one the track draws its legend in.
+pub(crate) fn draw_title<DB: DrawingBackend>(
+    head: &DrawingArea<DB, Shift>,
+    builder: &LogPlotBuilder<DB>,
+    title: &str,
+) -> Result<DrawingArea<DB, Shift>, DrawingAreaErrorKind<DB::ErrorType>> {
+    let (title_area, detail_area) = head.split_vertically(builder.title_height);
+
+    let (x, y) = title_area
+        .area_center_of_text(title, &builder.head_text_style)
+        .unwrap_or((0, 0));
+
+    title_area.draw_text(
+        title,
+        &builder.head_text_style,
+        BackendCoord::new(x as i32, y as i32),
+    )?;
+
+    title_area.draw_outline(&Stroke::WIDTH2_BLACK_LINE)?;
+
+    Ok(detail_area)
+}
@@ -0,0 +1,83 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::{DrawingArea, Stroke},
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::{builder::LogPlotBuilder, nmr::SpectrumDisplay};
+
+/// A spectrum track: one T2 distribution per depth, T2 time running across
+/// the track on a logarithmic scale and depth down it.
+pub struct SpectrumContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub(crate) display: SpectrumDisplay,
+    /// T2 times at the left and right edges, in milliseconds
+    pub(crate) time_range: (f64, f64),
+    pub(crate) cutoff: Option<f64>,
+    pub(crate) cutoff_stroke: Stroke,
+    /// Amplitude drawn at full scale, the largest one of the data when `None`
+    pub(crate) max_amplitude: Option<f64>,
+    pub(crate) trace_spacing: i32,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> SpectrumContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+
+    pub fn display(&mut self, display: SpectrumDisplay) -> &mut Self {
+        self.display = display;
+        self
+    }
+
+    /// The T2 times at the left and right edges of the track, in
+    /// milliseconds; both must be positive.
+    pub fn time_range(&mut self, start: f64, end: f64) -> &mut Self {
+        self.time_range = (start, end);
+        self
+    }
+
+    /// T2 cutoff between bound and free fluid, in milliseconds, drawn as a
+    /// vertical line; `None` leaves it out.
+    pub fn cutoff(&mut self, cutoff: Option<f64>) -> &mut Self {
+        self.cutoff = cutoff;
+        self
+    }
+
+    pub fn cutoff_stroke(&mut self, stroke: Stroke) -> &mut Self {
+        self.cutoff_stroke = stroke;
+        self
+    }
+
+    /// Amplitude drawn at full scale, the largest one of the data when `None`
+    pub fn max_amplitude(&mut self, max_amplitude: Option<f64>) -> &mut Self {
+        self.max_amplitude = max_amplitude;
+        self
+    }
+
+    /// Height in pixels of a full scale stacked distribution, and the least
+    /// number of pixels between two of them; ones closer to the last drawn
+    /// one are skipped.
+    pub fn trace_spacing(&mut self, trace_spacing: i32) -> &mut Self {
+        self.trace_spacing = trace_spacing.max(1);
+        self
+    }
+}
@@ -0,0 +1,67 @@
+use chartrs::{
+    coord::{CoordTranslate, Shift},
+    prelude::DrawingArea,
+};
+use chartrs_backend::DrawingBackend;
+
+use crate::{builder::LogPlotBuilder, waveform::WaveformDisplay};
+
+/// A waveform track: one time series per depth, time running across the
+/// track and depth down it.
+pub struct WaveformContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    pub head: DrawingArea<DB, Shift>,
+    pub body: DrawingArea<DB, CT>,
+
+    pub(crate) display: WaveformDisplay,
+    /// Times at the left and right edges, in microseconds
+    pub(crate) time_range: (f64, f64),
+    pub(crate) gain: f64,
+    pub(crate) trace_spacing: i32,
+
+    pub(crate) builder: &'a LogPlotBuilder<'a, DB>,
+}
+
+impl<'a, DB, CT> WaveformContext<'a, DB, CT>
+where
+    DB: DrawingBackend,
+    CT: CoordTranslate,
+{
+    /// Get a reference of underlying plotting area
+    pub fn head_area(&self) -> &DrawingArea<DB, Shift> {
+        &self.head
+    }
+
+    /// Get a reference of underlying plotting area
+    pub fn body_area(&self) -> &DrawingArea<DB, CT> {
+        &self.body
+    }
+
+    pub fn display(&mut self, display: WaveformDisplay) -> &mut Self {
+        self.display = display;
+        self
+    }
+
+    /// The times at the left and right edges of the track, in microseconds
+    pub fn time_range(&mut self, start: f64, end: f64) -> &mut Self {
+        self.time_range = (start, end);
+        self
+    }
+
+    /// Scale of the wiggles: at `1.0` the largest amplitude deflects a trace
+    /// by the trace spacing.
+    pub fn gain(&mut self, gain: f64) -> &mut Self {
+        self.gain = gain;
+        self
+    }
+
+    /// Least number of pixels between two wiggle traces; traces closer to
+    /// the last drawn one are skipped.
+    pub fn trace_spacing(&mut self, trace_spacing: i32) -> &mut Self {
+        self.trace_spacing = trace_spacing.max(1);
+        self
+    }
+}
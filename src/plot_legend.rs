@@ -7,13 +7,49 @@ use chartrs_backend::{
     BackendCoord, BackendTextStyle, DrawingBackend, DrawingErrorKind, TextStyle,
 };
 
+use crate::{
+    curve::is_null,
+    fill::{CurveFill, FillBaseline, FillStyle},
+    marker::{draw_marker, PointMarker},
+    mesh::XScale,
+    unit::{Conversion, UnitRegistry, UnitSystem},
+};
+
+/// How curve values map onto the width of a channel.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CurveScale {
+    #[default]
+    Linear,
+    /// Equal widths per decade, e.g. resistivity on 0.2 - 2000 ohm.m
+    Logarithmic,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PlotCurve {
     pub name: String,
+    /// Value at the left edge of the channel
     pub min: f32,
+    /// Value at the right edge of the channel
     pub max: f32,
     pub unit: String,
     pub stroke: Stroke,
+    pub scale: CurveScale,
+
+    /// Sentinel of missing samples, e.g. `-999.25`. `NaN` is always missing.
+    pub null_value: Option<f64>,
+    /// Longest depth gap of missing samples bridged by a straight line
+    pub gap_interpolation: Option<f64>,
+
+    /// Unit to draw the curve in instead of the one of the plot's unit system
+    pub display_unit: Option<String>,
+
+    pub fill: Option<CurveFill>,
+
+    /// Style of the backup scale the curve wraps onto beyond `max`
+    pub backup: Option<Stroke>,
+
+    /// Marker of point data, shown in the legend instead of the line
+    pub marker: Option<PointMarker>,
 }
 
 impl PlotCurve {
@@ -25,6 +61,8 @@ impl PlotCurve {
         self
     }
 
+    /// The values at the left (`min`) and right (`max`) edges of the channel.
+    /// `min > max` draws a reversed scale, e.g. neutron porosity `0.45` to `-0.15`.
     pub fn range<I>(&mut self, min: f32, max: f32, unit: I) -> &mut Self
     where
         I: Into<String>,
@@ -35,12 +73,165 @@ impl PlotCurve {
         self
     }
 
+    /// Swap the ends of the range so that it runs from right to left
+    pub fn reversed(&mut self) -> &mut Self {
+        std::mem::swap(&mut self.min, &mut self.max);
+        self
+    }
+
     pub fn stroke(&mut self, stroke: Stroke) -> &mut Self {
         self.stroke = stroke;
         self
     }
+
+    pub fn scale(&mut self, scale: CurveScale) -> &mut Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Position of `value` across the channel, `0.0` at `min` and `1.0` at
+    /// `max`. `None` for values that cannot be placed, such as non-positive
+    /// values on a logarithmic scale.
+    pub fn normalize(&self, value: f64) -> Option<f64> {
+        let (min, max) = (self.min as f64, self.max as f64);
+
+        let fraction = match self.scale {
+            CurveScale::Linear => (value - min) / (max - min),
+            CurveScale::Logarithmic => {
+                if value <= 0.0 || min <= 0.0 || max <= 0.0 {
+                    return None;
+                }
+                (value.ln() - min.ln()) / (max.ln() - min.ln())
+            }
+        };
+
+        fraction.is_finite().then_some(fraction)
+    }
+
+    /// Inverse of [`PlotCurve::normalize`]
+    pub fn denormalize(&self, position: f64) -> f64 {
+        let (min, max) = (self.min as f64, self.max as f64);
+
+        match self.scale {
+            CurveScale::Linear => min + (max - min) * position,
+            CurveScale::Logarithmic => (min.ln() + (max.ln() - min.ln()) * position).exp(),
+        }
+    }
+
+    /// Shade the area between the curve and `baseline`, drawn by
+    /// [`crate::plot::channel_context::ChannelContext::draw_curve_fill`].
+    pub fn fill(&mut self, baseline: FillBaseline, style: FillStyle) -> &mut Self {
+        self.fill = Some(CurveFill::new(baseline, style));
+        self
+    }
+
+    /// Wrap the curve around where it goes beyond `max`, redrawing the excess
+    /// with `stroke` on a backup scale that continues the range for another
+    /// track width, e.g. 150 - 300 after 0 - 150 (or a further `max / min`
+    /// on a logarithmic scale).
+    ///
+    /// There is only the one backup scale, the one shown in the legend: the
+    /// curve does not wrap a second time, values beyond the backup's `max`
+    /// are cut at the right edge of the track.
+    pub fn backup_scale(&mut self, stroke: Stroke) -> &mut Self {
+        self.backup = Some(stroke);
+        self
+    }
+
+    /// The backup scale as a curve of its own, see [`PlotCurve::backup_scale`]
+    pub fn backup_curve(&self) -> Option<PlotCurve> {
+        let stroke = self.backup?;
+        let max = match self.scale {
+            CurveScale::Linear => self.max + (self.max - self.min),
+            CurveScale::Logarithmic => self.max * (self.max / self.min),
+        };
+
+        Some(PlotCurve {
+            min: self.max,
+            max,
+            stroke,
+            fill: None,
+            backup: None,
+            ..self.clone()
+        })
+    }
+
+    /// Draw point data such as core measurements with `marker`, see
+    /// [`crate::plot::channel_context::ChannelContext::draw_core_points`].
+    pub fn marker(&mut self, marker: PointMarker) -> &mut Self {
+        self.marker = Some(marker);
+        self
+    }
+
+    pub fn null_value(&mut self, null_value: Option<f64>) -> &mut Self {
+        self.null_value = null_value;
+        self
+    }
+
+    /// Draw across runs of missing samples no longer than `max_length` in
+    /// depth instead of breaking the line there.
+    pub fn gap_interpolation(&mut self, max_length: f64) -> &mut Self {
+        self.gap_interpolation = Some(max_length);
+        self
+    }
+
+    /// Draw the curve in `unit`, e.g. `%` for a porosity given in `v/v`,
+    /// whatever the unit system of the plot.
+    pub fn display_unit<I>(&mut self, unit: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.display_unit = Some(unit.into());
+        self
+    }
+
+    /// This curve with its range and unit converted to the unit it is
+    /// displayed in, and the conversion to apply to its values. The curve is
+    /// left as is when its unit is unknown or cannot be converted.
+    pub fn to_display(
+        &self,
+        units: &UnitRegistry,
+        system: Option<UnitSystem>,
+    ) -> (PlotCurve, Conversion) {
+        let target = match (&self.display_unit, system) {
+            (Some(unit), _) => unit.as_str(),
+            (None, Some(system)) => units.unit_for(system, &self.unit),
+            (None, None) => return (self.clone(), Conversion::IDENTITY),
+        };
+
+        match units.conversion(&self.unit, target) {
+            Some(conversion) if !conversion.is_identity() => (
+                PlotCurve {
+                    min: round_significant(conversion.apply(self.min as f64), 4) as f32,
+                    max: round_significant(conversion.apply(self.max as f64), 4) as f32,
+                    unit: target.to_string(),
+                    ..self.clone()
+                },
+                conversion,
+            ),
+            _ => (self.clone(), Conversion::IDENTITY),
+        }
+    }
+
+    /// Whether `value` is a missing sample of this curve
+    pub fn is_null(&self, value: f64) -> bool {
+        is_null(value, self.null_value)
+    }
 }
 
+/// Round `value` to `digits` significant digits, for legend values
+fn round_significant(value: f64, digits: i32) -> f64 {
+    if value == 0.0 || !value.is_finite() {
+        return value;
+    }
+
+    let scale = 10f64.powi(digits - 1 - value.abs().log10().floor() as i32);
+    (value * scale).round() / scale
+}
+
+/// Height of the fill swatch drawn under the line of a filled curve
+const SWATCH_HEIGHT: i32 = 8;
+
 pub struct PlotLegend<'a, Coord> {
     points: [Coord; 2],
     legends: Vec<PlotCurve>,
@@ -110,28 +301,41 @@ where
     where
         I: Iterator<Item = <BackendCoordOnly as CoordMapper>::Output>,
     {
-        let count = self.legends.len() as i32;
-
-        let stroke_height: f32 = self.legends.iter().map(|f| f.stroke.width).sum();
-
         let text_height = (self.line_up.size() + self.line_down.size() + 7.) as f32;
         let spacing = text_height as i32;
 
-        let text_height = text_height * count as f32;
-        let total_height = (stroke_height + text_height + (spacing * (count - 1)) as f32) as i32;
+        let heights: Vec<f32> = self
+            .legends
+            .iter()
+            .map(|legend| {
+                let stroke_height = match legend.marker {
+                    Some(marker) => (marker.size * 2) as f32,
+                    None => legend.stroke.width,
+                };
+                let swatch_height = if legend.fill.is_some() {
+                    SWATCH_HEIGHT as f32
+                } else {
+                    0.
+                };
+                let backup_height = match legend.backup {
+                    Some(backup) => backup.width + self.line_down.size() as f32 + 7.,
+                    None => 0.,
+                };
+
+                stroke_height + swatch_height + backup_height + text_height
+            })
+            .collect();
 
         let (real_width, real_height) = (parent_dim.0 as i32, parent_dim.1 as i32);
 
+        // the legends that don't fit in the area are left out
+        let (count, total_height) = fitting_rows(&heights, spacing as f32, real_height);
+
         match (points.next(), points.next()) {
             (Some(top_left), Some(bottom_right)) => {
-                if real_height < total_height {
-                    // todo
-                    panic!()
-                }
-
                 let mut start_y = top_left.y + (real_height - total_height) / 2;
 
-                for legend in self.legends.iter() {
+                for legend in self.legends.iter().take(count) {
                     let (_, (max_x, max_y)) = self
                         .line_up
                         .layout_box(&legend.name)
@@ -145,14 +349,56 @@ where
 
                     start_y += max_y + 5;
 
-                    backend.draw_line(
-                        BackendCoord::new(top_left.x, start_y),
-                        BackendCoord::new(bottom_right.x, start_y),
-                        &legend.stroke,
-                    )?;
+                    // a row of markers for point data, the line otherwise
+                    match &legend.marker {
+                        Some(marker) => {
+                            start_y += marker.size as i32;
+                            for quarter in 1..4 {
+                                let x = top_left.x + real_width * quarter / 4;
+                                draw_marker(backend, BackendCoord::new(x, start_y), marker)?;
+                            }
+                            start_y += marker.size as i32;
+                        }
+                        None => backend.draw_line(
+                            BackendCoord::new(top_left.x, start_y),
+                            BackendCoord::new(bottom_right.x, start_y),
+                            &legend.stroke,
+                        )?,
+                    }
+
+                    // decade ticks of a logarithmic range
+                    if legend.scale == CurveScale::Logarithmic {
+                        let scale = XScale::Logarithmic {
+                            min: legend.min as f64,
+                            max: legend.max as f64,
+                        };
+                        for (position, _) in scale.log_grid().iter().filter(|(_, decade)| *decade) {
+                            let x = top_left.x + (real_width as f64 * position).round() as i32;
+                            backend.draw_line(
+                                BackendCoord::new(x, start_y - 4),
+                                BackendCoord::new(x, start_y),
+                                &legend.stroke,
+                            )?;
+                        }
+                    }
 
                     start_y += 2;
 
+                    // fill swatch
+                    if let Some(fill) = &legend.fill {
+                        for x in top_left.x..bottom_right.x {
+                            let position = (x - top_left.x) as f64 / real_width.max(1) as f64;
+                            let color = fill.color_at(position, legend.denormalize(position));
+
+                            backend.draw_line(
+                                BackendCoord::new(x, start_y),
+                                BackendCoord::new(x, start_y + SWATCH_HEIGHT - 2),
+                                &Stroke::new(1., color),
+                            )?;
+                        }
+                        start_y += SWATCH_HEIGHT;
+                    }
+
                     backend.draw_text(
                         &legend.min.to_string(),
                         &self.line_down,
@@ -181,6 +427,35 @@ where
                         BackendCoord::new(bottom_right.x - max_x - 5, start_y),
                     )?;
 
+                    // backup scale, as a second line under the primary one
+                    if let Some(backup) = legend.backup_curve() {
+                        start_y += self.line_down.size() as i32 + 5;
+
+                        backend.draw_line(
+                            BackendCoord::new(top_left.x, start_y),
+                            BackendCoord::new(bottom_right.x, start_y),
+                            &backup.stroke,
+                        )?;
+
+                        start_y += 2;
+
+                        backend.draw_text(
+                            &backup.min.to_string(),
+                            &self.line_down,
+                            BackendCoord::new(top_left.x + 5, start_y),
+                        )?;
+
+                        let (_, (max_x, _max_y)) = self
+                            .line_down
+                            .layout_box(&backup.max.to_string())
+                            .map_err(|e| DrawingErrorKind::FontError(Box::new(e)))?;
+                        backend.draw_text(
+                            &backup.max.to_string(),
+                            &self.line_down,
+                            BackendCoord::new(bottom_right.x - max_x - 5, start_y),
+                        )?;
+                    }
+
                     start_y += spacing;
                 }
 
@@ -190,3 +465,105 @@ where
         }
     }
 }
+
+/// How many legend rows of `heights`, `spacing` apart, fit in `available`
+/// pixels, and the height those take together.
+fn fitting_rows(heights: &[f32], spacing: f32, available: i32) -> (usize, i32) {
+    let mut total = 0.;
+
+    for (count, height) in heights.iter().enumerate() {
+        let next = if count == 0 {
+            *height
+        } else {
+            total + spacing + height
+        };
+        if next as i32 > available {
+            return (count, total as i32);
+        }
+        total = next;
+    }
+
+    (heights.len(), total as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(min: f32, max: f32, scale: CurveScale) -> PlotCurve {
+        let mut curve = PlotCurve::default();
+        curve.range(min, max, "").scale(scale);
+        curve
+    }
+
+    fn assert_round_trips(curve: &PlotCurve) {
+        for position in [0.0, 0.25, 0.5, 1.0, 1.5] {
+            let value = curve.denormalize(position);
+            let back = curve.normalize(value).unwrap();
+            assert!(
+                (back - position).abs() < 1e-9,
+                "{position} -> {value} -> {back}"
+            );
+        }
+    }
+
+    #[test]
+    fn reversed_linear_range_runs_from_right_to_left() {
+        let curve = curve(0.5, -0.25, CurveScale::Linear);
+
+        assert_eq!(curve.normalize(0.5), Some(0.0));
+        assert_eq!(curve.normalize(-0.25), Some(1.0));
+        assert_eq!(curve.normalize(0.125), Some(0.5));
+        assert_round_trips(&curve);
+    }
+
+    #[test]
+    fn reversed_logarithmic_range_runs_from_right_to_left() {
+        let curve = curve(1000., 1., CurveScale::Logarithmic);
+
+        assert!(curve.normalize(1000.).unwrap().abs() < 1e-9);
+        assert!((curve.normalize(1.).unwrap() - 1.0).abs() < 1e-9);
+        assert!((curve.normalize(10.).unwrap() - 2. / 3.).abs() < 1e-9);
+        assert_eq!(curve.normalize(0.), None);
+        assert_round_trips(&curve);
+    }
+
+    #[test]
+    fn reversed_range_wraps_onto_a_backup_scale_past_its_max() {
+        let mut linear = curve(0.5, -0.25, CurveScale::Linear);
+        linear.backup_scale(Stroke::default());
+        let backup = linear.backup_curve().unwrap();
+
+        assert_eq!((backup.min, backup.max), (-0.25, -1.0));
+        assert_eq!(linear.normalize(-0.625), Some(1.5));
+        assert_eq!(backup.normalize(-0.625), Some(0.5));
+        assert_round_trips(&backup);
+
+        let mut logarithmic = curve(16., 4., CurveScale::Logarithmic);
+        logarithmic.backup_scale(Stroke::default());
+        let backup = logarithmic.backup_curve().unwrap();
+
+        assert_eq!((backup.min, backup.max), (4., 1.));
+        assert!((logarithmic.normalize(2.).unwrap() - 1.5).abs() < 1e-9);
+        assert!((backup.normalize(2.).unwrap() - 0.5).abs() < 1e-9);
+        assert_round_trips(&backup);
+    }
+
+    #[test]
+    fn all_rows_fit_a_tall_enough_area() {
+        assert_eq!(fitting_rows(&[30., 40.], 20., 90), (2, 90));
+        assert_eq!(fitting_rows(&[30., 40.], 20., 200), (2, 90));
+    }
+
+    #[test]
+    fn rows_past_the_area_are_left_out() {
+        assert_eq!(fitting_rows(&[30., 40., 30.], 20., 100), (2, 90));
+        assert_eq!(fitting_rows(&[30., 40.], 20., 89), (1, 30));
+    }
+
+    #[test]
+    fn no_row_fits_a_too_short_area() {
+        assert_eq!(fitting_rows(&[30., 40.], 20., 29), (0, 0));
+        assert_eq!(fitting_rows(&[], 20., 0), (0, 0));
+    }
+}
@@ -20,3 +20,214 @@ impl<'a, DB: DrawingBackend> LogGraphSeries<'a, DB> {
         }
     }
 }
+
+/// Split the `(value, depth)` points of a curve into the runs between missing
+/// samples, so that each run is drawn as its own polyline.
+///
+/// A run of missing samples spanning no more than `max_gap` in depth, measured
+/// between the valid samples on either side, does not break the line.
+pub fn split_at_gaps<I, F>(points: I, is_null: F, max_gap: Option<f64>) -> Vec<Vec<(f64, f64)>>
+where
+    I: IntoIterator<Item = (f64, f64)>,
+    F: Fn(f64) -> bool,
+{
+    let mut segments = vec![];
+    let mut segment: Vec<(f64, f64)> = vec![];
+    let mut in_gap = false;
+
+    for (value, depth) in points {
+        if depth.is_nan() || is_null(value) {
+            in_gap = true;
+            continue;
+        }
+
+        if in_gap {
+            let bridged = match (segment.last(), max_gap) {
+                (Some((_, last_depth)), Some(max_gap)) => (depth - last_depth).abs() <= max_gap,
+                _ => false,
+            };
+            if !bridged && !segment.is_empty() {
+                segments.push(std::mem::take(&mut segment));
+            }
+            in_gap = false;
+        }
+
+        segment.push((value, depth));
+    }
+
+    if !segment.is_empty() {
+        segments.push(segment);
+    }
+
+    segments
+}
+
+/// Cut the polyline of `(value, depth)` points to the parts with values within
+/// `min..=max`. A line leaving or entering the range ends or starts on its
+/// edge, at the depth where it crosses it.
+pub fn clip_to_range(segment: &[(f64, f64)], min: f64, max: f64) -> Vec<Vec<(f64, f64)>> {
+    let mut parts = vec![];
+    let mut part: Vec<(f64, f64)> = vec![];
+    let mut last: Option<(f64, f64)> = None;
+
+    for &(value, depth) in segment {
+        if let Some((last_value, last_depth)) = last {
+            // edges crossed since the last point, in the order they are crossed
+            let mut edges: Vec<f64> = [min, max]
+                .into_iter()
+                .filter(|edge| (last_value - edge) * (value - edge) < 0.0)
+                .collect();
+            if value < last_value {
+                edges.reverse();
+            }
+
+            for edge in edges {
+                let t = (edge - last_value) / (value - last_value);
+                part.push((edge, last_depth + (depth - last_depth) * t));
+
+                // leaving the range
+                if part.len() > 1 {
+                    parts.push(std::mem::take(&mut part));
+                }
+            }
+        }
+
+        if (min..=max).contains(&value) {
+            part.push((value, depth));
+        } else if part.len() > 1 {
+            parts.push(std::mem::take(&mut part));
+        } else {
+            part.clear();
+        }
+
+        last = Some((value, depth));
+    }
+
+    if part.len() > 1 {
+        parts.push(part);
+    }
+
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::is_null;
+
+    const NULL: Option<f64> = Some(-999.25);
+
+    fn points(values: &[f64]) -> Vec<(f64, f64)> {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (*value, 100.0 + i as f64 * 0.5))
+            .collect()
+    }
+
+    #[test]
+    fn splits_at_missing_samples() {
+        let points = points(&[1.0, 2.0, -999.25, 4.0, f64::NAN, f64::NAN, 7.0]);
+        let segments = split_at_gaps(points, |value| is_null(value, NULL), None);
+
+        assert_eq!(
+            segments,
+            vec![
+                vec![(1.0, 100.0), (2.0, 100.5)],
+                vec![(4.0, 101.5)],
+                vec![(7.0, 103.0)],
+            ]
+        );
+    }
+
+    #[test]
+    fn bridges_gaps_up_to_the_interpolation_length() {
+        // a one sample gap spans 1.0 between its neighbours, a two sample one 1.5
+        let points = points(&[1.0, 2.0, -999.25, 4.0, f64::NAN, f64::NAN, 7.0]);
+        let segments = split_at_gaps(points.clone(), |value| is_null(value, NULL), Some(1.0));
+
+        assert_eq!(
+            segments,
+            vec![
+                vec![(1.0, 100.0), (2.0, 100.5), (4.0, 101.5)],
+                vec![(7.0, 103.0)],
+            ]
+        );
+
+        let segments = split_at_gaps(points, |value| is_null(value, NULL), Some(1.5));
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].len(), 4);
+    }
+
+    #[test]
+    fn leading_and_trailing_gaps_leave_no_empty_segments() {
+        let points = points(&[f64::NAN, 1.0, 2.0, -999.25]);
+        let segments = split_at_gaps(points, |value| is_null(value, NULL), Some(10.0));
+
+        assert_eq!(segments, vec![vec![(1.0, 100.5), (2.0, 101.0)]]);
+        assert!(split_at_gaps(vec![(f64::NAN, 1.0)], f64::is_nan, None).is_empty());
+    }
+
+    fn assert_parts(parts: &[Vec<(f64, f64)>], expected: &[&[(f64, f64)]]) {
+        assert_eq!(parts.len(), expected.len(), "{parts:?}");
+        for (part, expected) in parts.iter().zip(expected) {
+            assert_eq!(part.len(), expected.len(), "{parts:?}");
+            for (point, expected) in part.iter().zip(expected.iter()) {
+                assert!(
+                    (point.0 - expected.0).abs() < 1e-9 && (point.1 - expected.1).abs() < 1e-9,
+                    "{parts:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_lines_within_the_range() {
+        let line = [(0.0, 0.0), (0.5, 1.0), (1.0, 2.0)];
+        assert_parts(&clip_to_range(&line, 0.0, 1.0), &[&line]);
+        assert!(clip_to_range(&[(2.0, 0.0), (3.0, 1.0)], 0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn cuts_lines_at_the_edges() {
+        let line = [(0.5, 0.0), (1.5, 1.0), (0.5, 2.0), (-0.5, 3.0)];
+        assert_parts(
+            &clip_to_range(&line, 0.0, 1.0),
+            &[
+                &[(0.5, 0.0), (1.0, 0.5)],
+                &[(1.0, 1.5), (0.5, 2.0), (0.0, 2.5)],
+            ],
+        );
+
+        // only the right edge, as for a curve with a backup scale
+        assert_parts(
+            &clip_to_range(&line, f64::NEG_INFINITY, 1.0),
+            &[
+                &[(0.5, 0.0), (1.0, 0.5)],
+                &[(1.0, 1.5), (0.5, 2.0), (-0.5, 3.0)],
+            ],
+        );
+    }
+
+    #[test]
+    fn crosses_both_edges_within_one_step() {
+        let rising = [(-1.0, 0.0), (2.0, 3.0)];
+        assert_parts(
+            &clip_to_range(&rising, 0.0, 1.0),
+            &[&[(0.0, 1.0), (1.0, 2.0)]],
+        );
+
+        let falling = [(2.0, 0.0), (-1.0, 3.0)];
+        assert_parts(
+            &clip_to_range(&falling, 0.0, 1.0),
+            &[&[(1.0, 1.0), (0.0, 2.0)]],
+        );
+
+        // across and back, each crossing its own part
+        let zigzag = [(-1.0, 0.0), (2.0, 3.0), (-1.0, 6.0)];
+        assert_parts(
+            &clip_to_range(&zigzag, 0.0, 1.0),
+            &[&[(0.0, 1.0), (1.0, 2.0)], &[(1.0, 4.0), (0.0, 5.0)]],
+        );
+    }
+}
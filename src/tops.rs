@@ -0,0 +1,97 @@
+//! Formation tops and markers drawn across the depth area and every track by
+//! [`crate::plot::log_plot_context::LogPlotContext::draw_tops`].
+//!
+//! ```ignore
+//! plot_context.draw_tops(
+//!     &[
+//!         FormationTop::new("TOP SAND", 1580.0, Stroke::new(2., RED.to_backend_color())),
+//!         FormationTop::new("BASE SAND", 1640.0, Stroke::new(2., BLUE.to_backend_color())),
+//!     ],
+//!     TopsLabelTrack::Depth,
+//! )?;
+//! ```
+
+use chartrs::prelude::Stroke;
+
+use crate::annotation::stack_labels;
+
+#[derive(Debug, Clone)]
+pub struct FormationTop {
+    pub name: String,
+    pub depth: f64,
+    pub stroke: Stroke,
+}
+
+impl FormationTop {
+    pub fn new<N>(name: N, depth: f64, stroke: Stroke) -> Self
+    where
+        N: Into<String>,
+    {
+        Self {
+            name: name.into(),
+            depth,
+            stroke,
+        }
+    }
+}
+
+/// The track the names of the tops are written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TopsLabelTrack {
+    #[default]
+    Depth,
+    /// The track added in this position, `0` being the first one after the depth area
+    Track(usize),
+}
+
+/// Top of the name of each top drawn at the pixel rows `lines`, names being
+/// `text_height` high: just above its line, moved apart where names would
+/// overlap and kept within `min..=max`. Also returns whether the name was
+/// moved from its line, to be joined to it by a leader.
+pub(crate) fn label_tops(lines: &[i32], text_height: i32, min: i32, max: i32) -> Vec<(i32, bool)> {
+    let wanted: Vec<(i32, i32)> = lines
+        .iter()
+        .map(|y| (y - text_height - 1, text_height))
+        .collect();
+
+    stack_labels(&wanted, 1, min, max)
+        .into_iter()
+        .zip(&wanted)
+        .map(|(top, (wanted, _))| (top, top != *wanted))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_sit_just_above_their_lines() {
+        assert_eq!(
+            label_tops(&[100, 200], 12, 0, 500),
+            vec![(87, false), (187, false)]
+        );
+    }
+
+    #[test]
+    fn names_of_close_tops_are_moved_apart() {
+        // five pixels apart, less than the height of a name
+        assert_eq!(
+            label_tops(&[105, 100], 12, 0, 500),
+            vec![(100, true), (87, false)]
+        );
+        assert_eq!(
+            label_tops(&[100, 100, 100], 12, 0, 500),
+            vec![(87, false), (100, true), (113, true)]
+        );
+    }
+
+    #[test]
+    fn names_stay_within_the_area() {
+        assert_eq!(label_tops(&[5], 12, 0, 500), vec![(0, true)]);
+        assert_eq!(
+            label_tops(&[495, 498], 12, 0, 500),
+            vec![(475, true), (488, true)]
+        );
+    }
+}
@@ -0,0 +1,364 @@
+//! Units of measure and conversion between them.
+//!
+//! ```ignore
+//! let units = UnitRegistry::default();
+//! let dt = units.convert(100.0, "US/F", "us/m"); // Some(328.08...)
+//! let unit = units.unit_for(UnitSystem::Imperial, "M"); // "ft"
+//!
+//! LogPlotBuilder::on(&root)
+//!     .depth_unit("m")
+//!     .unit_system(UnitSystem::Imperial);
+//! ```
+
+use crate::curve::Curve;
+
+/// The physical quantity a unit measures. Only units of the same dimension
+/// convert into each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dimension {
+    Length,
+    Time,
+    Slowness,
+    Velocity,
+    Density,
+    Resistivity,
+    Conductivity,
+    /// Fractions and percentages, e.g. porosity `v/v` and `pu`
+    Ratio,
+    Temperature,
+    Pressure,
+    Volume,
+    /// `gAPI`
+    Radioactivity,
+}
+
+/// A unit defined against the base unit of its dimension:
+/// `base = value * factor + offset`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Unit {
+    pub symbol: String,
+    pub dimension: Dimension,
+    pub factor: f64,
+    pub offset: f64,
+}
+
+/// Display unit systems a plot can be rendered in.
+///
+/// `Imperial` is the oilfield convention: feet, inches, `us/ft`, `g/cm3`,
+/// `degF` and `psi`. `Metric` uses meters, millimeters, `us/m`, `kg/m3`,
+/// `degC` and `kPa`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitSystem {
+    Metric,
+    Imperial,
+}
+
+/// A linear conversion between two units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Conversion {
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl Conversion {
+    pub const IDENTITY: Conversion = Conversion {
+        scale: 1.0,
+        offset: 0.0,
+    };
+
+    pub fn apply(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// The conversion back from the target unit
+    pub fn inverse(&self) -> Conversion {
+        Conversion {
+            scale: 1.0 / self.scale,
+            offset: -self.offset / self.scale,
+        }
+    }
+}
+
+/// Known units, their aliases (`F`, `US/F`, `G/C3` ... as found in LAS files)
+/// and the metric/imperial counterparts used by [`UnitSystem`].
+#[derive(Debug, Clone)]
+pub struct UnitRegistry {
+    units: Vec<Unit>,
+    aliases: Vec<(String, String)>,
+    /// `(metric, imperial)` pairs
+    counterparts: Vec<(String, String)>,
+}
+
+impl Default for UnitRegistry {
+    fn default() -> Self {
+        use Dimension::*;
+
+        let mut registry = Self::empty();
+
+        let units: &[(&str, Dimension, f64, f64)] = &[
+            ("m", Length, 1.0, 0.0),
+            ("km", Length, 1000.0, 0.0),
+            ("cm", Length, 0.01, 0.0),
+            ("mm", Length, 0.001, 0.0),
+            ("ft", Length, 0.3048, 0.0),
+            ("in", Length, 0.0254, 0.0),
+            ("s", Time, 1.0, 0.0),
+            ("ms", Time, 1e-3, 0.0),
+            ("us", Time, 1e-6, 0.0),
+            ("min", Time, 60.0, 0.0),
+            ("h", Time, 3600.0, 0.0),
+            ("us/m", Slowness, 1e-6, 0.0),
+            ("us/ft", Slowness, 1e-6 / 0.3048, 0.0),
+            ("m/s", Velocity, 1.0, 0.0),
+            ("km/s", Velocity, 1000.0, 0.0),
+            ("ft/s", Velocity, 0.3048, 0.0),
+            ("kg/m3", Density, 1.0, 0.0),
+            ("g/cm3", Density, 1000.0, 0.0),
+            ("ohm.m", Resistivity, 1.0, 0.0),
+            ("S/m", Conductivity, 1.0, 0.0),
+            ("mS/m", Conductivity, 1e-3, 0.0),
+            ("v/v", Ratio, 1.0, 0.0),
+            ("%", Ratio, 0.01, 0.0),
+            ("pu", Ratio, 0.01, 0.0),
+            ("K", Temperature, 1.0, 0.0),
+            ("degC", Temperature, 1.0, 273.15),
+            ("degF", Temperature, 5.0 / 9.0, 273.15 - 32.0 * 5.0 / 9.0),
+            ("Pa", Pressure, 1.0, 0.0),
+            ("kPa", Pressure, 1e3, 0.0),
+            ("MPa", Pressure, 1e6, 0.0),
+            ("bar", Pressure, 1e5, 0.0),
+            ("psi", Pressure, 6894.757293168, 0.0),
+            ("m3", Volume, 1.0, 0.0),
+            ("L", Volume, 1e-3, 0.0),
+            ("ft3", Volume, 0.3048 * 0.3048 * 0.3048, 0.0),
+            ("bbl", Volume, 0.158987294928, 0.0),
+            ("gAPI", Radioactivity, 1.0, 0.0),
+        ];
+        for (symbol, dimension, factor, offset) in units {
+            registry.add(*symbol, *dimension, *factor, *offset);
+        }
+
+        let aliases: &[(&str, &str)] = &[
+            ("meter", "m"),
+            ("meters", "m"),
+            ("metre", "m"),
+            ("metres", "m"),
+            ("F", "ft"),
+            ("feet", "ft"),
+            ("foot", "ft"),
+            ("inch", "in"),
+            ("inches", "in"),
+            ("sec", "s"),
+            ("msec", "ms"),
+            ("usec", "us"),
+            ("US/F", "us/ft"),
+            ("usec/ft", "us/ft"),
+            ("uspf", "us/ft"),
+            ("us/f", "us/ft"),
+            ("usec/m", "us/m"),
+            ("g/cc", "g/cm3"),
+            ("G/C3", "g/cm3"),
+            ("gm/cc", "g/cm3"),
+            ("K/M3", "kg/m3"),
+            ("ohmm", "ohm.m"),
+            ("ohm-m", "ohm.m"),
+            ("mmho/m", "mS/m"),
+            ("frac", "v/v"),
+            ("dec", "v/v"),
+            ("fraction", "v/v"),
+            ("m3/m3", "v/v"),
+            ("p.u.", "pu"),
+            ("DEGC", "degC"),
+            ("DEGF", "degF"),
+            ("API", "gAPI"),
+        ];
+        for (alias, symbol) in aliases {
+            registry.alias(*alias, *symbol);
+        }
+
+        let counterparts: &[(&str, &str)] = &[
+            ("m", "ft"),
+            ("mm", "in"),
+            ("us/m", "us/ft"),
+            ("m/s", "ft/s"),
+            ("kg/m3", "g/cm3"),
+            ("degC", "degF"),
+            ("kPa", "psi"),
+            ("m3", "bbl"),
+        ];
+        for (metric, imperial) in counterparts {
+            registry.counterpart(*metric, *imperial);
+        }
+
+        registry
+    }
+}
+
+impl UnitRegistry {
+    /// A registry without any unit
+    pub fn empty() -> Self {
+        Self {
+            units: vec![],
+            aliases: vec![],
+            counterparts: vec![],
+        }
+    }
+
+    /// Define a unit as `base = value * factor + offset` in the base unit of `dimension`.
+    pub fn add<S>(&mut self, symbol: S, dimension: Dimension, factor: f64, offset: f64) -> &mut Self
+    where
+        S: Into<String>,
+    {
+        self.units.push(Unit {
+            symbol: symbol.into(),
+            dimension,
+            factor,
+            offset,
+        });
+        self
+    }
+
+    /// Make `alias` another spelling of the known unit `symbol`.
+    pub fn alias<A, S>(&mut self, alias: A, symbol: S) -> &mut Self
+    where
+        A: Into<String>,
+        S: Into<String>,
+    {
+        self.aliases.push((alias.into(), symbol.into()));
+        self
+    }
+
+    /// Declare `metric` and `imperial` as the units of the same quantity in the two systems.
+    pub fn counterpart<M, I>(&mut self, metric: M, imperial: I) -> &mut Self
+    where
+        M: Into<String>,
+        I: Into<String>,
+    {
+        self.counterparts.push((metric.into(), imperial.into()));
+        self
+    }
+
+    /// Find a unit by symbol or alias. An exact match wins over a case
+    /// insensitive one, so `mS/m` and `MS/m` stay apart when both are defined.
+    pub fn lookup(&self, symbol: &str) -> Option<&Unit> {
+        let symbol = symbol.trim();
+
+        let find = |symbol: &str| {
+            self.units
+                .iter()
+                .find(|unit| unit.symbol == symbol)
+                .or_else(|| {
+                    self.units
+                        .iter()
+                        .find(|unit| unit.symbol.eq_ignore_ascii_case(symbol))
+                })
+        };
+
+        find(symbol).or_else(|| {
+            self.aliases
+                .iter()
+                .find(|(alias, _)| alias == symbol)
+                .or_else(|| {
+                    self.aliases
+                        .iter()
+                        .find(|(alias, _)| alias.eq_ignore_ascii_case(symbol))
+                })
+                .and_then(|(_, target)| find(target))
+        })
+    }
+
+    /// The conversion from `from` to `to`, `None` when either unit is unknown
+    /// or they measure different quantities.
+    pub fn conversion(&self, from: &str, to: &str) -> Option<Conversion> {
+        let (from, to) = (self.lookup(from)?, self.lookup(to)?);
+        if from.dimension != to.dimension {
+            return None;
+        }
+        if from.symbol == to.symbol {
+            return Some(Conversion::IDENTITY);
+        }
+
+        Some(Conversion {
+            scale: from.factor / to.factor,
+            offset: (from.offset - to.offset) / to.factor,
+        })
+    }
+
+    pub fn convert(&self, value: f64, from: &str, to: &str) -> Option<f64> {
+        self.conversion(from, to)
+            .map(|conversion| conversion.apply(value))
+    }
+
+    /// Convert the values of `curve` to `to`, leaving null samples untouched.
+    pub fn convert_curve(&self, curve: &Curve, to: &str) -> Option<Curve> {
+        let conversion = self.conversion(&curve.unit, to)?;
+
+        Some(Curve {
+            unit: to.to_string(),
+            values: curve
+                .values
+                .iter()
+                .map(|value| {
+                    if curve.is_null(*value) {
+                        *value
+                    } else {
+                        conversion.apply(*value)
+                    }
+                })
+                .collect(),
+            ..curve.clone()
+        })
+    }
+
+    /// The unit `unit` is displayed in under `system`: its counterpart when it
+    /// has one, the canonical symbol when it already belongs to the system, or
+    /// `unit` itself when it is unknown.
+    pub fn unit_for<'s>(&'s self, system: UnitSystem, unit: &'s str) -> &'s str {
+        let symbol = match self.lookup(unit) {
+            Some(found) => found.symbol.as_str(),
+            None => return unit,
+        };
+
+        let counterpart = self
+            .counterparts
+            .iter()
+            .find_map(|(metric, imperial)| match system {
+                UnitSystem::Metric if imperial == symbol => Some(metric.as_str()),
+                UnitSystem::Imperial if metric == symbol => Some(imperial.as_str()),
+                _ => None,
+            });
+
+        counterpart.unwrap_or(symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unit_for_maps_to_the_counterpart_or_the_canonical_symbol() {
+        let units = UnitRegistry::default();
+
+        assert_eq!(units.unit_for(UnitSystem::Imperial, "M"), "ft");
+        assert_eq!(units.unit_for(UnitSystem::Metric, "F"), "m");
+        // already in the system, spelled as an alias
+        assert_eq!(units.unit_for(UnitSystem::Metric, "M"), "m");
+        assert_eq!(units.unit_for(UnitSystem::Imperial, "feet"), "ft");
+        assert_eq!(units.unit_for(UnitSystem::Metric, "furlong"), "furlong");
+    }
+
+    #[test]
+    fn inverse_undoes_a_conversion() {
+        let units = UnitRegistry::default();
+        let conversion = units.conversion("m", "ft").unwrap();
+
+        let feet = conversion.apply(1524.0);
+        assert!((feet - 5000.0).abs() < 1e-9);
+        assert!((conversion.inverse().apply(feet) - 1524.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,89 @@
+//! Sonic waveforms drawn in a waveform track, see
+//! [`crate::plot::log_plot_context::LogPlotContext::add_waveform_track`].
+//!
+//! ```ignore
+//! let mut waveform_context = plot_context.add_waveform_track(1524.0..1850.0, 200);
+//! waveform_context
+//!     .time_range(200.0, 1200.0)
+//!     .display(WaveformDisplay::VariableDensity);
+//! waveform_context.draw("VDL", &Waveforms::new(depths, times, traces))?;
+//! ```
+
+use chartrs::prelude::Stroke;
+use chartrs_backend::BackendColor;
+
+/// One trace of amplitudes per depth, all sampled at the same `times` in
+/// microseconds.
+#[derive(Debug, Clone, Default)]
+pub struct Waveforms {
+    pub depths: Vec<f64>,
+    /// Ascending
+    pub times: Vec<f64>,
+    pub traces: Vec<Vec<f64>>,
+}
+
+impl Waveforms {
+    pub fn new(depths: Vec<f64>, times: Vec<f64>, traces: Vec<Vec<f64>>) -> Self {
+        Self {
+            depths,
+            times,
+            traces,
+        }
+    }
+
+    /// Largest absolute amplitude of all traces, the one drawn at full scale
+    pub fn max_amplitude(&self) -> f64 {
+        self.traces
+            .iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .fold(0.0, |max: f64, value| max.max(value.abs()))
+    }
+
+    /// Amplitude of `trace` at `time`, linearly interpolated between samples
+    pub fn amplitude_at(&self, trace: usize, time: f64) -> Option<f64> {
+        let values = self.traces.get(trace)?;
+        let samples = values.len().min(self.times.len());
+
+        let next = self.times[..samples].partition_point(|at| *at < time);
+        if next == samples {
+            return None;
+        }
+        if next == 0 {
+            return (self.times[0] == time).then_some(values[0]);
+        }
+
+        let (t0, t1) = (self.times[next - 1], self.times[next]);
+        let (v0, v1) = (values[next - 1], values[next]);
+
+        let value = v0 + (v1 - v0) * (time - t0) / (t1 - t0);
+        value.is_finite().then_some(value)
+    }
+}
+
+/// How the traces of a waveform track are drawn.
+#[derive(Debug, Clone, Copy)]
+pub enum WaveformDisplay {
+    /// A line per trace around its depth, deflected upwards by positive
+    /// amplitudes, with the positive lobes filled with `fill`
+    Wiggle {
+        stroke: Stroke,
+        fill: Option<BackendColor>,
+    },
+    /// Amplitudes as gray levels, positive ones dark and negative ones light
+    VariableDensity,
+}
+
+impl Default for WaveformDisplay {
+    fn default() -> Self {
+        let black = BackendColor {
+            alpha: 1.0,
+            rgb: (0, 0, 0),
+        };
+
+        WaveformDisplay::Wiggle {
+            stroke: Stroke::new(1., black),
+            fill: Some(black),
+        }
+    }
+}
@@ -0,0 +1,210 @@
+use crate::io::las::{HeaderItem, LasFile};
+
+/// `(label, value)` rows of one half of the heading table
+pub(crate) type HeadingRows = Vec<(&'static str, String)>;
+
+/// Well metadata shown in the log heading.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WellInfo {
+    pub well: String,
+    pub uwi: String,
+    pub api: String,
+    pub company: String,
+    pub field: String,
+    pub location: String,
+    pub state: String,
+    pub country: String,
+
+    /// Kelly bushing, derrick floor and ground level elevations
+    pub kb: Option<f64>,
+    pub df: Option<f64>,
+    pub gl: Option<f64>,
+    pub elevation_unit: String,
+
+    pub run_date: String,
+    pub logging_company: String,
+}
+
+impl WellInfo {
+    /// Fill the metadata from the `~WELL` section of `las`. Elevations are
+    /// looked up in `~WELL` and `~PARAMETER` (`EKB`/`KB`, `EDF`/`DF`,
+    /// `EGL`/`GL`).
+    pub fn from_las(las: &LasFile) -> Self {
+        // LAS 1.2 puts the value after the colon
+        let legacy = las.las_version().map(|v| v < 2.0).unwrap_or(false);
+        let text = |mnemonic: &str| {
+            las.well_item(mnemonic)
+                .map(|item| {
+                    if legacy {
+                        item.description.trim().to_string()
+                    } else {
+                        item.value.trim().to_string()
+                    }
+                })
+                .unwrap_or_default()
+        };
+
+        let elevation = |mnemonics: &[&str]| -> Option<&HeaderItem> {
+            mnemonics.iter().find_map(|mnemonic| {
+                las.well_item(mnemonic)
+                    .or_else(|| las.parameter(mnemonic))
+                    .filter(|item| item.number().is_some())
+            })
+        };
+        let kb = elevation(&["EKB", "KB"]);
+        let df = elevation(&["EDF", "DF"]);
+        let gl = elevation(&["EGL", "GL"]);
+
+        Self {
+            well: text("WELL"),
+            uwi: text("UWI"),
+            api: text("API"),
+            company: text("COMP"),
+            field: text("FLD"),
+            location: text("LOC"),
+            state: text("STAT"),
+            country: text("CTRY"),
+            kb: kb.and_then(HeaderItem::number),
+            df: df.and_then(HeaderItem::number),
+            gl: gl.and_then(HeaderItem::number),
+            elevation_unit: [kb, df, gl]
+                .into_iter()
+                .flatten()
+                .map(|item| item.unit.clone())
+                .next()
+                .unwrap_or_default(),
+            run_date: text("DATE"),
+            logging_company: text("SRVC"),
+        }
+    }
+
+    pub fn well<I>(&mut self, well: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.well = well.into();
+        self
+    }
+
+    pub fn uwi<I>(&mut self, uwi: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.uwi = uwi.into();
+        self
+    }
+
+    pub fn api<I>(&mut self, api: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.api = api.into();
+        self
+    }
+
+    pub fn company<I>(&mut self, company: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.company = company.into();
+        self
+    }
+
+    pub fn field<I>(&mut self, field: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.field = field.into();
+        self
+    }
+
+    pub fn location<I>(&mut self, location: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.location = location.into();
+        self
+    }
+
+    pub fn state<I>(&mut self, state: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.state = state.into();
+        self
+    }
+
+    pub fn country<I>(&mut self, country: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.country = country.into();
+        self
+    }
+
+    pub fn elevations<I>(
+        &mut self,
+        kb: Option<f64>,
+        df: Option<f64>,
+        gl: Option<f64>,
+        unit: I,
+    ) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.kb = kb;
+        self.df = df;
+        self.gl = gl;
+        self.elevation_unit = unit.into();
+        self
+    }
+
+    pub fn run_date<I>(&mut self, run_date: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.run_date = run_date.into();
+        self
+    }
+
+    pub fn logging_company<I>(&mut self, logging_company: I) -> &mut Self
+    where
+        I: Into<String>,
+    {
+        self.logging_company = logging_company.into();
+        self
+    }
+
+    /// Rows of the left and right halves of the heading table
+    pub(crate) fn heading_rows(&self) -> (HeadingRows, HeadingRows) {
+        let elevation = |value: Option<f64>| {
+            value
+                .map(|value| {
+                    format!("{} {}", value, self.elevation_unit)
+                        .trim_end()
+                        .to_string()
+                })
+                .unwrap_or_default()
+        };
+
+        (
+            vec![
+                ("COMPANY", self.company.clone()),
+                ("WELL", self.well.clone()),
+                ("FIELD", self.field.clone()),
+                ("LOCATION", self.location.clone()),
+                ("STATE", self.state.clone()),
+                ("COUNTRY", self.country.clone()),
+            ],
+            vec![
+                ("UWI", self.uwi.clone()),
+                ("API", self.api.clone()),
+                ("ELEV. KB", elevation(self.kb)),
+                ("ELEV. DF", elevation(self.df)),
+                ("ELEV. GL", elevation(self.gl)),
+                ("RUN DATE", self.run_date.clone()),
+                ("LOGGING CO.", self.logging_company.clone()),
+            ],
+        )
+    }
+}
@@ -0,0 +1,159 @@
+use chartrs::{
+    coord::Shift,
+    element::{BackendCoordOnly, CoordMapper, Drawable, PointCollection},
+    prelude::{DrawingArea, Stroke},
+};
+use chartrs_backend::{
+    text_anchor::{HPos, Pos, VPos},
+    BackendCoord, DrawingBackend, DrawingErrorKind, TextStyle,
+};
+
+use crate::well::WellInfo;
+
+/// The log heading: the well name as a title row above a two column table of
+/// the well metadata.
+pub struct WellHeader<'a, Coord> {
+    points: [Coord; 2],
+    info: WellInfo,
+    title_style: TextStyle<'a>,
+    text_style: TextStyle<'a>,
+}
+
+impl<'a> WellHeader<'a, BackendCoord> {
+    pub fn with_area<DB>(
+        area: &DrawingArea<DB, Shift>,
+        info: WellInfo,
+        title_style: TextStyle<'a>,
+        text_style: TextStyle<'a>,
+    ) -> Self
+    where
+        DB: DrawingBackend,
+    {
+        let (w, h) = area.dim_in_pixel();
+
+        Self {
+            points: [
+                BackendCoord::new(0_i32, 0_i32),
+                BackendCoord::new(w as i32, h as i32),
+            ],
+            info,
+            title_style,
+            text_style,
+        }
+    }
+}
+
+impl<'a, Coord> WellHeader<'a, Coord> {
+    pub fn new(
+        points: [Coord; 2],
+        info: WellInfo,
+        title_style: TextStyle<'a>,
+        text_style: TextStyle<'a>,
+    ) -> Self {
+        Self {
+            points,
+            info,
+            title_style,
+            text_style,
+        }
+    }
+}
+
+impl<'a, Coord> PointCollection<'a, Coord> for &'a WellHeader<'a, Coord> {
+    type Point = &'a Coord;
+    type IntoIter = &'a [Coord];
+
+    fn point_iter(self) -> &'a [Coord] {
+        &self.points
+    }
+}
+
+impl<'a, Coord, DB> Drawable<DB> for WellHeader<'a, Coord>
+where
+    DB: DrawingBackend,
+{
+    fn draw<I>(
+        &self,
+        mut points: I,
+        backend: &mut DB,
+        _parent_dim: (u32, u32),
+    ) -> Result<(), DrawingErrorKind<<DB as DrawingBackend>::ErrorType>>
+    where
+        I: Iterator<Item = <BackendCoordOnly as CoordMapper>::Output>,
+    {
+        let (top_left, bottom_right) = match (points.next(), points.next()) {
+            (Some(top_left), Some(bottom_right)) => (top_left, bottom_right),
+            _ => return Ok(()),
+        };
+
+        let (left_rows, right_rows) = self.info.heading_rows();
+        let row_count = left_rows.len().max(right_rows.len()) as i32;
+
+        let (width, height) = (bottom_right.x - top_left.x, bottom_right.y - top_left.y);
+        let title_height = height / 4;
+        let row_height = (height - title_height) / row_count.max(1);
+
+        let line = &Stroke::WIDTH2_BLACK_LINE;
+        let light_line = &Stroke::LIGHT_MESH_LINE;
+
+        // title
+        backend.draw_text(
+            &self.info.well,
+            &self.title_style.pos(Pos::new(HPos::Center, VPos::Center)),
+            BackendCoord::new(top_left.x + width / 2, top_left.y + title_height / 2),
+        )?;
+
+        let table_top = top_left.y + title_height;
+        backend.draw_line(
+            BackendCoord::new(top_left.x, table_top),
+            BackendCoord::new(bottom_right.x, table_top),
+            line,
+        )?;
+
+        // two halves, each a label and a value column
+        let half = width / 2;
+        let label_width = half * 3 / 10;
+        let label_style = self.text_style.pos(Pos::new(HPos::Left, VPos::Center));
+
+        for (column, rows) in [left_rows, right_rows].iter().enumerate() {
+            let x = top_left.x + half * column as i32;
+
+            for (i, (label, value)) in rows.iter().enumerate() {
+                let y = table_top + row_height * i as i32;
+
+                if i > 0 {
+                    backend.draw_line(
+                        BackendCoord::new(x, y),
+                        BackendCoord::new(x + half, y),
+                        light_line,
+                    )?;
+                }
+
+                backend.draw_text(
+                    label,
+                    &label_style,
+                    BackendCoord::new(x + 5, y + row_height / 2),
+                )?;
+                backend.draw_text(
+                    value,
+                    &label_style,
+                    BackendCoord::new(x + label_width + 5, y + row_height / 2),
+                )?;
+            }
+
+            backend.draw_line(
+                BackendCoord::new(x + label_width, table_top),
+                BackendCoord::new(x + label_width, bottom_right.y),
+                light_line,
+            )?;
+        }
+
+        backend.draw_line(
+            BackendCoord::new(top_left.x + half, table_top),
+            BackendCoord::new(top_left.x + half, bottom_right.y),
+            line,
+        )?;
+
+        Ok(())
+    }
+}